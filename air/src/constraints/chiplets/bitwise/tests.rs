@@ -1,16 +1,13 @@
 use super::{
+    super::test_utils::{build_frame, empty_row, set_selectors, Chiplet},
     enforce_constraints, get_periodic_values, EvaluationFrame, BITWISE_A_COL_IDX,
     BITWISE_A_COL_RANGE, BITWISE_B_COL_IDX, BITWISE_B_COL_RANGE, BITWISE_OUTPUT_COL_IDX,
-    BITWISE_PREV_OUTPUT_COL_IDX, BITWISE_SELECTOR_COL_IDX, NUM_CONSTRAINTS, NUM_DECOMP_BITS, ONE,
-    OP_CYCLE_LEN, ZERO,
+    BITWISE_PREV_OUTPUT_COL_IDX, NUM_CONSTRAINTS, NUM_DECOMP_BITS, ONE, OP_CYCLE_LEN, ZERO,
 };
 use crate::{
-    trace::{
-        chiplets::{
-            bitwise::{BITWISE_AND, BITWISE_XOR},
-            BITWISE_TRACE_RANGE,
-        },
-        TRACE_WIDTH,
+    trace::chiplets::{
+        bitwise::{BITWISE_AND, BITWISE_XOR},
+        BITWISE_TRACE_RANGE,
     },
     Felt,
 };
@@ -48,8 +45,8 @@ fn output_aggregation_and() {
     let cycle_row = 0;
 
     // create a valid test frame manually
-    let mut current = vec![ZERO; TRACE_WIDTH];
-    let mut next = vec![ZERO; TRACE_WIDTH];
+    let mut current = empty_row();
+    let mut next = empty_row();
 
     let current_bitwise = [
         // selector
@@ -100,13 +97,37 @@ fn output_aggregation_and() {
     current[BITWISE_TRACE_RANGE].copy_from_slice(&current_bitwise);
     next[BITWISE_TRACE_RANGE].copy_from_slice(&next_bitwise);
 
-    let frame = EvaluationFrame::<Felt>::from_rows(current, next);
+    let frame = build_frame(current, next);
     let result = get_constraint_evaluation(frame, cycle_row);
 
     // expect a failure for the output aggregation constraint (the last one)
     assert_ne!(ZERO, result[NUM_CONSTRAINTS - 1]);
 }
 
+/// Tests that the bitwise constraints do not all evaluate to zero if the output of an otherwise
+/// valid AND or XOR frame is perturbed.
+#[test]
+fn test_bitwise_wrong_output_fails() {
+    let expected = [ZERO; NUM_CONSTRAINTS];
+
+    for operation in [BITWISE_AND, BITWISE_XOR] {
+        let a = rand_value::<u32>();
+        let b = rand_value::<u32>();
+        let cycle_row: usize = rand_value::<u8>() as usize % (OP_CYCLE_LEN - 1);
+
+        let mut frame = get_test_frame(operation, a, b, cycle_row);
+        frame.current_mut()[BITWISE_OUTPUT_COL_IDX] += ONE;
+        let result = get_constraint_evaluation(frame, cycle_row);
+
+        // The output is no longer aggregated correctly from the previous output, and it is not
+        // copied into the previous output of the next row, so the last two constraints fail.
+        assert_ne!(result[NUM_CONSTRAINTS - 1], expected[NUM_CONSTRAINTS - 1]);
+        assert_ne!(result[NUM_CONSTRAINTS - 2], expected[NUM_CONSTRAINTS - 2]);
+        // All other constraints should evaluate to zero.
+        assert_eq!(result[..NUM_CONSTRAINTS - 2], expected[..NUM_CONSTRAINTS - 2]);
+    }
+}
+
 // RANDOMIZED TESTS
 // ================================================================================================
 
@@ -165,12 +186,12 @@ pub fn get_test_frame(
     );
 
     // Initialize the rows.
-    let mut current = vec![ZERO; TRACE_WIDTH];
-    let mut next = vec![ZERO; TRACE_WIDTH];
+    let mut current = empty_row();
+    let mut next = empty_row();
 
     // Set the operation selectors.
-    current[BITWISE_SELECTOR_COL_IDX] = operation;
-    next[BITWISE_SELECTOR_COL_IDX] = operation;
+    set_selectors(&mut current, Chiplet::Bitwise, &[operation]);
+    set_selectors(&mut next, Chiplet::Bitwise, &[operation]);
 
     // Set the input aggregation and decomposition values.
     set_frame_inputs(&mut current, &mut next, a, b, cycle_row_num);
@@ -194,7 +215,7 @@ pub fn get_test_frame(
     current[BITWISE_OUTPUT_COL_IDX] = Felt::new(output_current as u64);
     next[BITWISE_OUTPUT_COL_IDX] = Felt::new((output_next) as u64);
 
-    EvaluationFrame::<Felt>::from_rows(current, next)
+    build_frame(current, next)
 }
 
 /// Generates the current and next rows for the provided inputs, current cycle row number, and the
@@ -217,12 +238,12 @@ pub fn get_test_frame_with_two_ops(
     );
 
     // Initialize the rows.
-    let mut current = vec![ZERO; TRACE_WIDTH];
-    let mut next = vec![ZERO; TRACE_WIDTH];
+    let mut current = empty_row();
+    let mut next = empty_row();
 
     // Set the operation selector.
-    current[BITWISE_SELECTOR_COL_IDX] = op_current;
-    next[BITWISE_SELECTOR_COL_IDX] = op_next;
+    set_selectors(&mut current, Chiplet::Bitwise, &[op_current]);
+    set_selectors(&mut next, Chiplet::Bitwise, &[op_next]);
 
     // Set the input aggregation and decomposition values.
     set_frame_inputs(&mut current, &mut next, a, b, cycle_row_num);
@@ -246,7 +267,7 @@ pub fn get_test_frame_with_two_ops(
     current[BITWISE_OUTPUT_COL_IDX] = Felt::new(output_current as u64);
     next[BITWISE_OUTPUT_COL_IDX] = Felt::new((output_next) as u64);
 
-    EvaluationFrame::<Felt>::from_rows(current, next)
+    build_frame(current, next)
 }
 
 /// Returns the shift amount for the previous, current, and next rows, based on the `cycle_row_num`,
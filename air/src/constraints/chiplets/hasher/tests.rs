@@ -1,10 +1,11 @@
 use super::{
-    enforce_constraints, Hasher, HASHER_NODE_INDEX_COL_IDX, HASHER_SELECTOR_COL_RANGE,
-    HASHER_STATE_COL_RANGE, NUM_CONSTRAINTS, ONE, ZERO,
+    super::test_utils::{build_frame, empty_row, set_selectors, Chiplet},
+    enforce_constraints, Hasher, HASHER_NODE_INDEX_COL_IDX, HASHER_STATE_COL_RANGE,
+    NUM_CONSTRAINTS, ONE, ZERO,
 };
 use crate::{
     trace::chiplets::hasher::{Selectors, LINEAR_HASH, STATE_WIDTH},
-    Felt, TRACE_WIDTH,
+    Felt,
 };
 use alloc::vec::Vec;
 use rand_utils::rand_array;
@@ -26,12 +27,78 @@ fn hash_round() {
 
     let frame = get_test_hashing_frame(current_selectors, next_selectors, cycle_row_num);
     let result = get_constraint_evaluation(frame, cycle_row_num);
-    assert_eq!(expected, result);
+    assert_eq!(
+        expected,
+        result,
+        "failed constraints: {:?}",
+        describe_hasher_constraints(&result)
+    );
+}
+
+/// Tests that the failed constraints of an invalid hash round transition are reported by name.
+#[test]
+fn describe_failed_hash_round() {
+    let cycle_row_num: usize = 3;
+    let selectors = [ZERO, LINEAR_HASH[1], LINEAR_HASH[2]];
+
+    // corrupt the first element of the next hasher state and the next node index
+    let mut frame = get_test_hashing_frame(selectors, selectors, cycle_row_num);
+    frame.next_mut()[HASHER_STATE_COL_RANGE.start] += ONE;
+    frame.next_mut()[HASHER_NODE_INDEX_COL_IDX] = ONE;
+
+    let result = get_constraint_evaluation(frame, cycle_row_num);
+    assert_eq!(describe_hasher_constraints(&result), ["node index copied", "RPO round h0"]);
 }
 
 // TEST HELPER FUNCTIONS
 // ================================================================================================
 
+/// Human-readable names of the hasher chiplet constraints, in the order in which they are
+/// enforced by [enforce_constraints].
+const CONSTRAINT_NAMES: [&str; NUM_CONSTRAINTS] = [
+    "selector s0 binary",
+    "selector s1 binary",
+    "selector s2 binary",
+    "selector s1 copied",
+    "selector s2 copied",
+    "selector s0 zero after absorption",
+    "valid selector combination",
+    "node index zero on output",
+    "node index shifted on absorption",
+    "node index copied",
+    "RPO round h0",
+    "RPO round h1",
+    "RPO round h2",
+    "RPO round h3",
+    "RPO round h4",
+    "RPO round h5",
+    "RPO round h6",
+    "RPO round h7",
+    "RPO round h8",
+    "RPO round h9",
+    "RPO round h10",
+    "RPO round h11",
+    "capacity h0 unchanged on absorption",
+    "capacity h1 unchanged on absorption",
+    "capacity h2 unchanged on absorption",
+    "capacity h3 unchanged on absorption",
+    "digest h4 copied on node absorption",
+    "digest h5 copied on node absorption",
+    "digest h6 copied on node absorption",
+    "digest h7 copied on node absorption",
+];
+
+/// Returns the names of all constraints which evaluate to a non-zero value in the provided result
+/// of hasher chiplet's constraint evaluations.
+fn describe_hasher_constraints(result: &[Felt; NUM_CONSTRAINTS]) -> Vec<&'static str> {
+    result
+        .iter()
+        .zip(CONSTRAINT_NAMES)
+        .filter(|(value, _)| **value != ZERO)
+        .map(|(_, name)| name)
+        .collect()
+}
+
 /// Returns the result of hasher chiplet's constraint evaluations on the provided frame starting at
 /// the specified row.
 fn get_constraint_evaluation(
@@ -72,12 +139,12 @@ fn get_test_hashing_frame(
     next_selectors: Selectors,
     cycle_row_num: usize,
 ) -> EvaluationFrame<Felt> {
-    let mut current = vec![ZERO; TRACE_WIDTH];
-    let mut next = vec![ZERO; TRACE_WIDTH];
+    let mut current = empty_row();
+    let mut next = empty_row();
 
     // Set the selectors for the hash operation.
-    current[HASHER_SELECTOR_COL_RANGE].copy_from_slice(&current_selectors);
-    next[HASHER_SELECTOR_COL_RANGE].copy_from_slice(&next_selectors);
+    set_selectors(&mut current, Chiplet::Hasher, &current_selectors);
+    set_selectors(&mut next, Chiplet::Hasher, &next_selectors);
 
     // Set the starting hasher state.
     let mut state = rand_array();
@@ -91,5 +158,5 @@ fn get_test_hashing_frame(
     current[HASHER_NODE_INDEX_COL_IDX] = ZERO;
     next[HASHER_NODE_INDEX_COL_IDX] = ZERO;
 
-    EvaluationFrame::from_rows(current, next)
+    build_frame(current, next)
 }
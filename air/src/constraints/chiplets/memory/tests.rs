@@ -1,14 +1,9 @@
 use super::{
+    super::test_utils::{build_frame, empty_row, set_selectors, Chiplet},
     EvaluationFrame, MEMORY_ADDR_COL_IDX, MEMORY_CLK_COL_IDX, MEMORY_CTX_COL_IDX,
     MEMORY_D0_COL_IDX, MEMORY_D1_COL_IDX, MEMORY_D_INV_COL_IDX, MEMORY_V_COL_RANGE, NUM_ELEMENTS,
 };
-use crate::trace::{
-    chiplets::{
-        memory::{Selectors, MEMORY_COPY_READ, MEMORY_INIT_READ, MEMORY_WRITE},
-        MEMORY_TRACE_OFFSET,
-    },
-    TRACE_WIDTH,
-};
+use crate::trace::chiplets::memory::{Selectors, MEMORY_COPY_READ, MEMORY_INIT_READ, MEMORY_WRITE};
 use crate::{chiplets::memory, Felt, FieldElement, ONE, ZERO};
 use alloc::vec::Vec;
 use rand_utils::rand_value;
@@ -142,12 +137,11 @@ fn get_test_frame(
     old_values: &[u32],
     new_values: &[u32],
 ) -> EvaluationFrame<Felt> {
-    let mut current = vec![ZERO; TRACE_WIDTH];
-    let mut next = vec![ZERO; TRACE_WIDTH];
+    let mut current = empty_row();
+    let mut next = empty_row();
 
     // Set the operation in the next row.
-    next[MEMORY_TRACE_OFFSET] = selectors[0];
-    next[MEMORY_TRACE_OFFSET + 1] = selectors[1];
+    set_selectors(&mut next, Chiplet::Memory, &selectors);
 
     // Set the context, addr, and clock columns in the next row to the values in the delta row.
     next[MEMORY_CTX_COL_IDX] = Felt::new(delta_row[0]);
@@ -178,7 +172,7 @@ fn get_test_frame(
     next[MEMORY_D1_COL_IDX] = Felt::new(delta >> 16);
     next[MEMORY_D_INV_COL_IDX] = (Felt::new(delta)).inv();
 
-    EvaluationFrame::<Felt>::from_rows(current, next)
+    build_frame(current, next)
 }
 
 /// Generates a row of valid test values for the context, address, and clock columns according to
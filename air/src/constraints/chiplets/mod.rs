@@ -8,6 +8,12 @@ mod bitwise;
 mod hasher;
 mod memory;
 
+#[cfg(test)]
+pub mod test_utils;
+
+#[cfg(test)]
+mod tests;
+
 // CONSTANTS
 // ================================================================================================
 /// The number of constraints on the management of the Chiplets module. This does not include
@@ -0,0 +1,88 @@
+use super::{EvaluationFrame, Felt, CHIPLETS_OFFSET};
+use crate::{
+    trace::{
+        chiplets::{
+            memory, BITWISE_SELECTOR_COL_IDX, HASHER_SELECTOR_COL_RANGE, MEMORY_SELECTORS_COL_IDX,
+            NUM_KERNEL_ROM_SELECTORS,
+        },
+        TRACE_WIDTH,
+    },
+    ONE, ZERO,
+};
+use alloc::vec::Vec;
+use core::ops::Range;
+
+// TEST FRAMES
+// ================================================================================================
+
+/// Returns an evaluation frame with the provided current and next rows.
+///
+/// # Panics
+/// Panics if either of the rows does not contain exactly [TRACE_WIDTH] values.
+pub fn build_frame(current: Vec<Felt>, next: Vec<Felt>) -> EvaluationFrame<Felt> {
+    assert_eq!(current.len(), TRACE_WIDTH, "invalid width of the current row");
+    assert_eq!(next.len(), TRACE_WIDTH, "invalid width of the next row");
+    EvaluationFrame::from_rows(current, next)
+}
+
+/// Returns a row of the main trace with all columns set to ZERO.
+pub fn empty_row() -> Vec<Felt> {
+    vec![ZERO; TRACE_WIDTH]
+}
+
+/// Sets the chiplet selector columns of the provided row such that the row belongs to the
+/// specified chiplet.
+pub fn set_chiplet_selectors(row: &mut [Felt], chiplet: Chiplet) {
+    let selectors = chiplet.chiplet_selectors();
+    row[CHIPLETS_OFFSET..CHIPLETS_OFFSET + selectors.len()].copy_from_slice(selectors);
+}
+
+/// Sets the operation selector columns of the specified chiplet in the provided row to the
+/// provided values.
+///
+/// # Panics
+/// Panics if the number of values is different from the number of operation selectors of the
+/// chiplet.
+pub fn set_selectors(row: &mut [Felt], chiplet: Chiplet, selectors: &[Felt]) {
+    row[chiplet.selector_col_range()].copy_from_slice(selectors);
+}
+
+// CHIPLETS
+// ================================================================================================
+
+/// The chiplets of the chiplets module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chiplet {
+    Hasher,
+    Bitwise,
+    Memory,
+    KernelRom,
+}
+
+impl Chiplet {
+    /// Returns the values of the chiplet selector columns in the rows of this chiplet.
+    pub fn chiplet_selectors(&self) -> &'static [Felt] {
+        match self {
+            Self::Hasher => &[ZERO],
+            Self::Bitwise => &[ONE, ZERO],
+            Self::Memory => &[ONE, ONE, ZERO],
+            Self::KernelRom => &[ONE, ONE, ONE, ZERO],
+        }
+    }
+
+    /// Returns the range of the main trace columns which contain the operation selectors of this
+    /// chiplet. The range is empty for the kernel ROM chiplet, as it has no operation selectors.
+    pub fn selector_col_range(&self) -> Range<usize> {
+        match self {
+            Self::Hasher => HASHER_SELECTOR_COL_RANGE,
+            Self::Bitwise => BITWISE_SELECTOR_COL_IDX..BITWISE_SELECTOR_COL_IDX + 1,
+            Self::Memory => {
+                MEMORY_SELECTORS_COL_IDX..MEMORY_SELECTORS_COL_IDX + memory::NUM_SELECTORS
+            }
+            Self::KernelRom => {
+                let offset = CHIPLETS_OFFSET + NUM_KERNEL_ROM_SELECTORS;
+                offset..offset
+            }
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use super::{
+    enforce_selectors,
+    test_utils::{build_frame, empty_row, set_chiplet_selectors, Chiplet},
+    Felt, NUM_CONSTRAINTS,
+};
+use crate::ZERO;
+
+// UNIT TESTS
+// ================================================================================================
+
+/// Tests that the chiplet selectors can change from one chiplet to the next one in the order in
+/// which the chiplets are placed in the trace.
+#[test]
+fn chiplet_selectors_valid_transitions() {
+    let expected = [ZERO; NUM_CONSTRAINTS];
+    let chiplets = [Chiplet::Hasher, Chiplet::Bitwise, Chiplet::Memory, Chiplet::KernelRom];
+
+    for (idx, &current) in chiplets.iter().enumerate() {
+        for &next in chiplets[idx..].iter().take(2) {
+            let result = get_constraint_evaluation(current, next);
+            assert_eq!(expected, result, "{current:?} -> {next:?}");
+        }
+    }
+}
+
+/// Tests that the chiplet selectors cannot change back to a chiplet which precedes the current one.
+#[test]
+fn chiplet_selectors_invalid_transitions() {
+    let result = get_constraint_evaluation(Chiplet::Bitwise, Chiplet::Hasher);
+    assert_ne!(ZERO, result[3]);
+
+    let result = get_constraint_evaluation(Chiplet::Memory, Chiplet::Bitwise);
+    assert_ne!(ZERO, result[4]);
+
+    let result = get_constraint_evaluation(Chiplet::KernelRom, Chiplet::Memory);
+    assert_ne!(ZERO, result[5]);
+}
+
+// TEST HELPERS
+// ================================================================================================
+
+/// Returns the result of the chiplet selector constraint evaluations on a frame transitioning
+/// from a row of the `current` chiplet to a row of the `next` chiplet.
+fn get_constraint_evaluation(current: Chiplet, next: Chiplet) -> [Felt; NUM_CONSTRAINTS] {
+    let mut current_row = empty_row();
+    let mut next_row = empty_row();
+    set_chiplet_selectors(&mut current_row, current);
+    set_chiplet_selectors(&mut next_row, next);
+    let frame = build_frame(current_row, next_row);
+
+    let mut result = [ZERO; NUM_CONSTRAINTS];
+    enforce_selectors(&frame, &mut result);
+    result
+}
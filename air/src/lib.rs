@@ -37,7 +37,7 @@ use utils::TransitionConstraintRange;
 
 pub use errors::ExecutionOptionsError;
 pub use options::{ExecutionOptions, ProvingOptions};
-pub use proof::{ExecutionProof, HashFunction};
+pub use proof::{ExecutionProof, HashFunction, ProofSizeBreakdown};
 pub use vm_core::{
     utils::{DeserializationError, ToElements},
     Felt, FieldElement, StarkField,
@@ -255,7 +255,12 @@ impl Air for ProcessorAir {
 // PUBLIC INPUTS
 // ================================================================================================
 
-#[derive(Debug)]
+/// Public inputs of a Miden VM execution proof.
+///
+/// The public inputs consist of the info of the executed program, the initial state of the stack,
+/// and the final state of the stack. When lowered into field elements via
+/// [ToElements::to_elements()](vm_core::ToElements::to_elements), they are laid out in this order.
+#[derive(Clone, Debug)]
 pub struct PublicInputs {
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
@@ -274,6 +279,21 @@ impl PublicInputs {
             stack_outputs,
         }
     }
+
+    /// Returns the info of the program the proof was generated for.
+    pub fn program_info(&self) -> &ProgramInfo {
+        &self.program_info
+    }
+
+    /// Returns the initial state of the stack.
+    pub fn stack_inputs(&self) -> &StackInputs {
+        &self.stack_inputs
+    }
+
+    /// Returns the final state of the stack.
+    pub fn stack_outputs(&self) -> &StackOutputs {
+        &self.stack_outputs
+    }
 }
 
 impl vm_core::ToElements<Felt> for PublicInputs {
@@ -146,12 +146,32 @@ impl From<ProvingOptions> for WinterProofOptions {
 ///
 /// - `max_cycles` specifies the maximum number of cycles a program is allowed to execute.
 /// - `expected_cycles` specifies the number of cycles a program is expected to execute.
+/// - `max_stack_depth` specifies the maximum number of items the stack is allowed to hold.
+/// - `fixed_stack_depth` specifies whether the stack is required to never overflow its top 16
+///   items.
+/// - `enable_instruction_log` specifies whether the VM should record the executed operations
+///   together with the top of the stack before and after each of them.
+/// - `enable_memory_write_events` specifies whether the VM should notify the host about every
+///   write to memory performed via `MSTORE` and `MSTOREW` operations.
+/// - `enable_merkle_root_updates` specifies whether the VM should record the roots of Merkle trees
+///   updated via `MRUPDATE` operations.
+/// - `enable_advice_state` specifies whether the VM should record the state of the advice provider
+///   once the program has been executed.
+/// - `default_hdword_domain` specifies the domain used by `adv.insert_hdword` instructions which do
+///   not specify a domain explicitly.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ExecutionOptions {
     max_cycles: u32,
     expected_cycles: u32,
+    max_stack_depth: usize,
+    fixed_stack_depth: bool,
     enable_tracing: bool,
     enable_debugging: bool,
+    enable_instruction_log: bool,
+    enable_memory_write_events: bool,
+    enable_merkle_root_updates: bool,
+    enable_advice_state: bool,
+    default_hdword_domain: u8,
 }
 
 impl Default for ExecutionOptions {
@@ -159,8 +179,15 @@ impl Default for ExecutionOptions {
         ExecutionOptions {
             max_cycles: u32::MAX,
             expected_cycles: MIN_TRACE_LEN as u32,
+            max_stack_depth: usize::MAX,
+            fixed_stack_depth: false,
             enable_tracing: false,
             enable_debugging: false,
+            enable_instruction_log: false,
+            enable_memory_write_events: false,
+            enable_merkle_root_updates: false,
+            enable_advice_state: false,
+            default_hdword_domain: 0,
         }
     }
 }
@@ -179,7 +206,7 @@ impl ExecutionOptions {
     ) -> Result<Self, ExecutionOptionsError> {
         let max_cycles = max_cycles.unwrap_or(u32::MAX);
         if max_cycles < MIN_TRACE_LEN as u32 {
-            return Err(ExecutionOptionsError::MaxCycleNumTooSmall(expected_cycles));
+            return Err(ExecutionOptionsError::MaxCycleNumTooSmall(max_cycles));
         }
         if max_cycles < expected_cycles {
             return Err(ExecutionOptionsError::ExpectedCyclesTooBig(max_cycles, expected_cycles));
@@ -192,11 +219,39 @@ impl ExecutionOptions {
         Ok(ExecutionOptions {
             max_cycles,
             expected_cycles,
+            max_stack_depth: usize::MAX,
+            fixed_stack_depth: false,
             enable_tracing,
             enable_debugging: false,
+            enable_instruction_log: false,
+            enable_memory_write_events: false,
+            enable_merkle_root_updates: false,
+            enable_advice_state: false,
+            default_hdword_domain: 0,
         })
     }
 
+    /// Sets the maximum number of items the stack is allowed to hold during execution.
+    ///
+    /// The depth of the stack includes the top 16 items as well as all items in the overflow
+    /// table (across all execution contexts). Execution fails as soon as the depth of the stack
+    /// exceeds this limit. By default, the depth of the stack is not limited.
+    pub fn with_max_stack_depth(mut self, max_stack_depth: usize) -> Self {
+        self.max_stack_depth = max_stack_depth;
+        self
+    }
+
+    /// Requires the program to be executed with a fixed-depth stack.
+    ///
+    /// With this option, execution fails as soon as an operation moves an item of the stack into
+    /// the overflow table (i.e., as soon as the depth of the stack exceeds 16), rather than
+    /// recording the item in the table. This also applies to programs whose stack inputs do not
+    /// fit into the top 16 items of the stack.
+    pub fn with_fixed_stack_depth(mut self) -> Self {
+        self.fixed_stack_depth = true;
+        self
+    }
+
     /// Enables execution of the `trace` instructions.
     pub fn with_tracing(mut self) -> Self {
         self.enable_tracing = true;
@@ -214,6 +269,58 @@ impl ExecutionOptions {
         self
     }
 
+    /// Enables recording of an instruction log during execution.
+    ///
+    /// With this option, the VM records an entry for every executed operation, which contains the
+    /// operation together with the top 16 items of the stack before and after it was executed.
+    /// Unless the entries are consumed by the host, they are returned in the execution trace.
+    /// Recording the log is expensive, and thus it is disabled by default.
+    pub fn with_instruction_log(mut self) -> Self {
+        self.enable_instruction_log = true;
+        self
+    }
+
+    /// Enables notifying the host about memory writes during execution.
+    ///
+    /// With this option, the host is notified about every memory write performed via `MSTORE`
+    /// and `MSTOREW` operations (e.g., by `mem_store`, `mem_storew`, `loc_store`, and
+    /// `loc_storew` instructions), together with the value overwritten by the write.
+    pub fn with_memory_write_events(mut self) -> Self {
+        self.enable_memory_write_events = true;
+        self
+    }
+
+    /// Enables recording of the Merkle tree roots updated during execution.
+    ///
+    /// With this option, the VM records the old and the new root of every Merkle tree updated via
+    /// an `MRUPDATE` operation (e.g., by `mtree_set` instructions), and returns them in the
+    /// execution trace.
+    pub fn with_merkle_root_updates(mut self) -> Self {
+        self.enable_merkle_root_updates = true;
+        self
+    }
+
+    /// Enables recording of the state of the advice provider once the program has been executed.
+    ///
+    /// When enabled, the values remaining on the advice stack, the advice map, and the Merkle
+    /// store of the advice provider are returned together with the execution trace. This is
+    /// useful for checking the effects of advice injectors (e.g., `adv.insert_hperm`) in tests.
+    pub fn with_advice_state(mut self) -> Self {
+        self.enable_advice_state = true;
+        self
+    }
+
+    /// Sets the domain used by `adv.insert_hdword` instructions which do not specify a domain
+    /// explicitly.
+    ///
+    /// This allows a program to use a single application-specific domain without providing it to
+    /// every instruction. An explicitly specified domain (including `adv.insert_hdword.0`) always
+    /// takes precedence over the default one, which is ZERO unless set otherwise.
+    pub fn with_default_hdword_domain(mut self, domain: u8) -> Self {
+        self.default_hdword_domain = domain;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -231,6 +338,16 @@ impl ExecutionOptions {
         self.expected_cycles
     }
 
+    /// Returns the maximum number of items the stack is allowed to hold during execution.
+    pub fn max_stack_depth(&self) -> usize {
+        self.max_stack_depth
+    }
+
+    /// Returns a flag indicating whether the stack is required to never overflow its top 16 items.
+    pub fn fixed_stack_depth(&self) -> bool {
+        self.fixed_stack_depth
+    }
+
     /// Returns a flag indicating whether the VM should execute `trace` instructions.
     pub fn enable_tracing(&self) -> bool {
         self.enable_tracing
@@ -240,4 +357,31 @@ impl ExecutionOptions {
     pub fn enable_debugging(&self) -> bool {
         self.enable_debugging
     }
+
+    /// Returns a flag indicating whether the VM should record an instruction log.
+    pub fn enable_instruction_log(&self) -> bool {
+        self.enable_instruction_log
+    }
+
+    /// Returns a flag indicating whether the VM should notify the host about memory writes.
+    pub fn enable_memory_write_events(&self) -> bool {
+        self.enable_memory_write_events
+    }
+
+    /// Returns a flag indicating whether the VM should record the roots of updated Merkle trees.
+    pub fn enable_merkle_root_updates(&self) -> bool {
+        self.enable_merkle_root_updates
+    }
+
+    /// Returns a flag indicating whether the VM should record the final state of the advice
+    /// provider.
+    pub fn enable_advice_state(&self) -> bool {
+        self.enable_advice_state
+    }
+
+    /// Returns the domain used by `adv.insert_hdword` instructions which do not specify a domain
+    /// explicitly.
+    pub fn default_hdword_domain(&self) -> u8 {
+        self.default_hdword_domain
+    }
 }
@@ -50,6 +50,24 @@ impl ExecutionProof {
         }
     }
 
+    /// Returns the number of bytes taken by each component of this proof when serialized via
+    /// [ExecutionProof::to_bytes()].
+    pub fn size_breakdown(&self) -> ProofSizeBreakdown {
+        let proof = &self.proof;
+        let trace_queries = proof.trace_queries.iter().map(|queries| queries.to_bytes().len());
+        ProofSizeBreakdown {
+            // the hash function and the number of unique queries take one byte each
+            header: proof.context.to_bytes().len() + 2,
+            commitments: proof.commitments.to_bytes().len(),
+            trace_queries: trace_queries.collect(),
+            constraint_queries: proof.constraint_queries.to_bytes().len(),
+            ood_frame: proof.ood_frame.to_bytes().len(),
+            fri_proof: proof.fri_proof.to_bytes().len(),
+            num_fri_layers: proof.fri_proof.num_layers(),
+            pow_nonce: proof.pow_nonce.to_bytes().len(),
+        }
+    }
+
     // SERIALIZATION / DESERIALIZATION
     // --------------------------------------------------------------------------------------------
 
@@ -81,6 +99,44 @@ impl ExecutionProof {
     }
 }
 
+// PROOF SIZE BREAKDOWN
+// ================================================================================================
+
+/// The number of bytes taken by each component of a serialized [ExecutionProof].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProofSizeBreakdown {
+    /// Size of the proof context, the hash function, and the number of unique queries.
+    pub header: usize,
+    /// Size of the commitments to the trace segments, the constraint evaluations, and the FRI
+    /// layers.
+    pub commitments: usize,
+    /// Size of the trace queries, one entry per trace segment.
+    pub trace_queries: Vec<usize>,
+    /// Size of the constraint evaluation queries.
+    pub constraint_queries: usize,
+    /// Size of the out-of-domain evaluation frame.
+    pub ood_frame: usize,
+    /// Size of the FRI proof, including the remainder polynomial.
+    pub fri_proof: usize,
+    /// Number of FRI layers in the FRI proof.
+    pub num_fri_layers: usize,
+    /// Size of the proof-of-work nonce.
+    pub pow_nonce: usize,
+}
+
+impl ProofSizeBreakdown {
+    /// Returns the total size of the proof in bytes.
+    pub fn total(&self) -> usize {
+        self.header
+            + self.commitments
+            + self.trace_queries.iter().sum::<usize>()
+            + self.constraint_queries
+            + self.ood_frame
+            + self.fri_proof
+            + self.pow_nonce
+    }
+}
+
 // HASH FUNCTION
 // ================================================================================================
 
@@ -188,6 +188,28 @@ pub(super) fn mtree_verify(span: &mut SpanBuilder) -> Result<Option<CodeBlock>,
     span.add_op(MpVerify)
 }
 
+/// Checks that a Merkle tree with root `R` is present in the advice provider and removes `R` from
+/// the stack. The stack is expected to be arranged as follows (from the top):
+/// - root of the tree `R`, 4 elements
+///
+/// The check is performed by the advice provider; thus, the fact that the tree is present in the
+/// advice provider is not enforced by the proof of the execution.
+///
+/// This operation takes 4 VM cycles.
+pub(super) fn mtree_assert_root(
+    span: &mut SpanBuilder,
+) -> Result<Option<CodeBlock>, AssemblyError> {
+    // stack input:  [R, ...]
+    // stack output: [...]
+
+    // invoke the advice provider function to check that the Merkle tree with root R is present in
+    // the advice provider
+    span.push_advice_injector(AdviceInjector::MerkleRootCheck);
+
+    // drop the root from the stack
+    span.add_ops([Drop, Drop, Drop, Drop])
+}
+
 // MERKLE TREES - HELPERS
 // ================================================================================================
 
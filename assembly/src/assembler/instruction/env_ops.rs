@@ -2,6 +2,7 @@ use super::{
     mem_ops::local_to_absolute_addr, push_felt, AssemblyContext, AssemblyError, CodeBlock, Felt,
     Operation::*, SpanBuilder,
 };
+use vm_core::stack::STACK_TOP_SIZE;
 
 // CONSTANT INPUTS
 // ================================================================================================
@@ -50,6 +51,16 @@ pub fn locaddr(
     Ok(None)
 }
 
+/// Appends a sequence of operations to the span needed for executing overflow_depth instruction.
+/// This consists of putting the stack depth onto the stack via SDEPTH operation and subtracting
+/// the number of the top stack items from it, i.e.: SDEPTH PUSH(-16) ADD.
+///
+/// Since the stack depth is never smaller than the number of the top stack items, the result is
+/// the number of items in the stack overflow table.
+pub fn overflow_depth(span: &mut SpanBuilder) -> Result<Option<CodeBlock>, AssemblyError> {
+    span.add_ops([SDepth, Push(-Felt::from(STACK_TOP_SIZE as u32)), Add])
+}
+
 /// Appends CALLER operation to the span which puts the hash of the function which initiated the
 /// current SYSCALL onto the stack.
 ///
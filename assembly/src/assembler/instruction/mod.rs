@@ -1,9 +1,11 @@
 use super::{
-    Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, Instruction, Operation,
-    ProcedureId, RpoDigest, SpanBuilder, ONE, ZERO,
+    Assembler, AssemblyContext, AssemblyError, CodeBlock, Felt, Instruction, LibraryPath,
+    ModuleAst, Operation, ProcedureId, ProcedureName, RpoDigest, SourceLocation, SpanBuilder, ONE,
+    ZERO,
 };
 use crate::utils::bound_into_included_u64;
-use core::ops::RangeBounds;
+use alloc::vec::Vec;
+use core::{fmt, ops::RangeBounds};
 use vm_core::{Decorator, FieldElement};
 
 mod adv_ops;
@@ -24,6 +26,7 @@ impl Assembler {
     pub(super) fn compile_instruction(
         &self,
         instruction: &Instruction,
+        location: Option<&SourceLocation>,
         span: &mut SpanBuilder,
         ctx: &mut AssemblyContext,
     ) -> Result<Option<CodeBlock>, AssemblyError> {
@@ -33,7 +36,7 @@ impl Assembler {
         // this will allow us to map the instruction to the sequence of operations which were
         // executed as a part of this instruction.
         if self.in_debug_mode() {
-            span.track_instruction(instruction, ctx);
+            span.track_instruction(instruction, location, ctx);
         }
 
         let result = match instruction {
@@ -250,6 +253,7 @@ impl Assembler {
             Instruction::PushU32List(imms) => env_ops::push_many(imms, span),
             Instruction::PushFeltList(imms) => env_ops::push_many(imms, span),
             Instruction::Sdepth => span.add_op(SDepth),
+            Instruction::OverflowDepth => env_ops::overflow_depth(span),
             Instruction::Caller => env_ops::caller(span, ctx),
             Instruction::Clk => span.add_op(Clk),
             Instruction::AdvPipe => span.add_op(Pipe),
@@ -282,6 +286,7 @@ impl Assembler {
             Instruction::MTreeSet => crypto_ops::mtree_set(span),
             Instruction::MTreeMerge => crypto_ops::mtree_merge(span),
             Instruction::MTreeVerify => crypto_ops::mtree_verify(span),
+            Instruction::MTreeAssertRoot => crypto_ops::mtree_assert_root(span),
 
             // ----- STARK proof verification -----------------------------------------------------
             Instruction::FriExt2Fold4 => span.add_op(FriE2F4),
@@ -294,6 +299,9 @@ impl Assembler {
             Instruction::CallMastRoot(root) => self.call_mast_root(root, ctx),
             Instruction::CallImported(id) => self.call_imported(id, ctx),
             Instruction::SysCall(id) => self.syscall(id, ctx),
+            Instruction::DynExec | Instruction::DynCall if !self.dynamic_exec_enabled() => {
+                Err(AssemblyError::dynamic_exec_not_allowed(instruction, location))
+            }
             Instruction::DynExec => self.dynexec(),
             Instruction::DynCall => self.dyncall(),
             Instruction::ProcRefLocal(idx) => self.procref_local(*idx, ctx, span),
@@ -303,7 +311,7 @@ impl Assembler {
             Instruction::Breakpoint => {
                 if self.in_debug_mode() {
                     span.add_op(Noop)?;
-                    span.track_instruction(instruction, ctx);
+                    span.track_instruction(instruction, location, ctx);
                 }
                 Ok(None)
             }
@@ -337,6 +345,92 @@ impl Assembler {
     }
 }
 
+// INSTRUCTION COSTS
+// ================================================================================================
+
+/// Returns the number of VM cycles it takes to execute the specified instruction.
+///
+/// This is the number of operations the instruction compiles into; the cost of an instruction does
+/// not include the NOOPs which may be inserted to align operations in a span, nor the rows the
+/// instruction may occupy in the chiplets (e.g., `hperm` takes a single VM cycle but requires 8
+/// rows in the hasher chiplet).
+///
+/// Instructions which invoke procedures (`exec`, `call`, `syscall`, `dynexec`, and `dyncall`) are
+/// assigned zero cost as the number of cycles they take depends on the invoked procedures and the
+/// control flow blocks they compile into. Instructions with invalid immediate values are assigned
+/// zero cost as well. For instructions which access procedure locals, the cost is computed
+/// assuming the maximum number of locals is allocated for the procedure.
+pub fn instruction_cost(instruction: &Instruction) -> u32 {
+    InstructionCostCalculator::default().cost(instruction)
+}
+
+/// Computes the number of VM cycles it takes to execute instructions as described in
+/// [instruction_cost()].
+///
+/// The instructions are compiled within a kernel procedure which has the maximum number of
+/// locals, such that instructions which are valid only in some procedures (e.g., `caller` or
+/// `loc_load`) are compiled into the same operations as in a valid context. The same assembler
+/// and assembly context are reused for all instructions.
+pub(crate) struct InstructionCostCalculator {
+    assembler: Assembler,
+    context: AssemblyContext,
+}
+
+impl InstructionCostCalculator {
+    /// Returns the number of VM cycles it takes to execute the specified instruction.
+    pub fn cost(&mut self, instruction: &Instruction) -> u32 {
+        match instruction {
+            Instruction::ExecLocal(_)
+            | Instruction::ExecImported(_)
+            | Instruction::CallLocal(_)
+            | Instruction::CallMastRoot(_)
+            | Instruction::CallImported(_)
+            | Instruction::SysCall(_)
+            | Instruction::DynExec
+            | Instruction::DynCall => 0,
+            // procedure references push the MAST root of the procedure onto the stack
+            Instruction::ProcRefLocal(_) | Instruction::ProcRefImported(_) => 4,
+            _ => {
+                let mut span = SpanBuilder::default();
+                match self.assembler.compile_instruction(
+                    instruction,
+                    None,
+                    &mut span,
+                    &mut self.context,
+                ) {
+                    Ok(_) => span.num_ops() as u32,
+                    Err(_) => 0,
+                }
+            }
+        }
+    }
+}
+
+impl Default for InstructionCostCalculator {
+    fn default() -> Self {
+        let mut context = AssemblyContext::for_module(true);
+        let module = ModuleAst::new(Vec::new(), Vec::new(), None).expect("invalid module");
+        context
+            .begin_module(&LibraryPath::kernel_path(), &module)
+            .expect("failed to begin module");
+        let proc_name = ProcedureName::try_from("instruction_cost").expect("invalid name");
+        context
+            .begin_proc(&proc_name, false, u16::MAX)
+            .expect("failed to begin procedure");
+
+        Self {
+            assembler: Assembler::default(),
+            context,
+        }
+    }
+}
+
+impl fmt::Debug for InstructionCostCalculator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InstructionCostCalculator").finish_non_exhaustive()
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1,16 +1,21 @@
 use super::{
-    ast::{instrument, Instruction, ModuleAst, Node, ProcedureAst, ProgramAst},
+    ast::{
+        instrument, validate_kernel_procedures, validate_syscalls, CodeBody, Formatter,
+        Instruction, ModuleAst, Node, ProcedureAst, ProgramAst, SourceLocation, Visit,
+    },
     crypto::hash::RpoDigest,
     AssemblyError, CallSet, CodeBlock, CodeBlockTable, Felt, Kernel, Library, LibraryError,
     LibraryPath, Module, NamedProcedure, Operation, Procedure, ProcedureId, ProcedureName, Program,
-    ONE, ZERO,
+    ValidationError, ONE, ZERO,
 };
-use alloc::collections::BTreeMap;
-use alloc::vec::Vec;
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::{boxed::Box, string::String, vec::Vec};
 use core::{borrow::Borrow, cell::RefCell};
 use vm_core::{Decorator, DecoratorList};
 
 mod instruction;
+pub use instruction::instruction_cost;
+pub(crate) use instruction::InstructionCostCalculator;
 
 mod module_provider;
 use module_provider::ModuleProvider;
@@ -24,6 +29,9 @@ pub use context::AssemblyContext;
 mod procedure_cache;
 use procedure_cache::ProcedureCache;
 
+mod root_cache;
+pub use root_cache::RootCache;
+
 #[cfg(test)]
 mod tests;
 
@@ -32,15 +40,22 @@ mod tests;
 /// Miden Assembler which can be used to convert Miden assembly source code into program MAST.
 ///
 /// The assembler can be instantiated in several ways using a "builder" pattern. Specifically:
-/// - If `with_kernel()` or `with_kernel_module()` methods are not used, the assembler will be
-///   instantiated with a default empty kernel. Programs compiled using such assembler
-///   cannot make calls to kernel procedures via `syscall` instruction.
+/// - If `with_kernel()`, `with_kernel_module()`, or `with_kernel_modules()` methods are not used,
+///   the assembler will be instantiated with a default empty kernel. Programs compiled using such
+///   assembler cannot make calls to kernel procedures via `syscall` instruction.
+/// - If `with_dynamic_exec(false)` is used, the assembler will refuse to compile code containing
+///   `dynexec` or `dyncall` instructions.
+/// - If `with_root_cache()` is used, the assembler will reuse modules compiled previously by all
+///   assemblers sharing the same [RootCache].
 #[derive(Default)]
 pub struct Assembler {
     kernel: Kernel,
     module_provider: ModuleProvider,
     proc_cache: RefCell<ProcedureCache>,
     in_debug_mode: bool,
+    dynamic_exec_disabled: bool,
+    root_cache: Option<RootCache>,
+    stack_effect_comments: bool,
 }
 
 impl Assembler {
@@ -53,6 +68,40 @@ impl Assembler {
         self
     }
 
+    /// Specifies whether the assembler is allowed to compile `dynexec` and `dyncall` instructions.
+    ///
+    /// Dynamic execution is enabled by default. When it is disabled, compiling a program or a
+    /// module which contains either of these instructions results in an error; this guarantees
+    /// that all control flow of the compiled code can be determined statically.
+    pub fn with_dynamic_exec(mut self, enabled: bool) -> Self {
+        self.dynamic_exec_disabled = !enabled;
+        self
+    }
+
+    /// Specifies whether modules formatted by the assembler are annotated with stack effects.
+    ///
+    /// When enabled, every instruction rendered by [Assembler::format_module()] is followed by a
+    /// comment with the change of the stack depth caused by the instruction and the resulting
+    /// depth relative to the entry into the procedure, where these can be determined statically.
+    /// The annotations are comments, and thus the formatted source compiles into the same code.
+    pub fn with_stack_effect_comments(mut self, enabled: bool) -> Self {
+        self.stack_effect_comments = enabled;
+        self
+    }
+
+    /// Sets the cache of compiled modules shared by this assembler with other assemblers.
+    ///
+    /// Before compiling a module, the assembler looks it up in the cache, and reuses the compiled
+    /// procedures if the same module has already been compiled by an assembler with the same
+    /// options. Newly compiled modules are added to the cache.
+    ///
+    /// Modules compiled by assemblers with different kernels or different libraries are cached
+    /// separately. Kernel modules are never cached.
+    pub fn with_root_cache(mut self, cache: RootCache) -> Self {
+        self.root_cache = Some(cache);
+        self
+    }
+
     /// Adds the library to provide modules for the compilation.
     pub fn with_library<L>(mut self, library: &L) -> Result<Self, AssemblyError>
     where
@@ -86,8 +135,13 @@ impl Assembler {
     /// Sets the kernel for the assembler to the kernel defined by the provided module.
     ///
     /// # Errors
-    /// Returns an error if compiling kernel source results in an error.
+    /// Returns an error if:
+    /// - Any procedure in the kernel uses an instruction which cannot be executed within a
+    ///   `syscall` (see [Assembler::validate_kernel()]).
+    /// - Compiling kernel source results in an error.
     pub fn with_kernel_module(mut self, module: ModuleAst) -> Result<Self, AssemblyError> {
+        validate_kernel_procedures(&module).map_err(AssemblyError::invalid_kernel_procedures)?;
+
         // compile the kernel; this adds all exported kernel procedures to the procedure cache
         let mut context = AssemblyContext::for_module(true);
         let kernel = Module::kernel(module);
@@ -100,6 +154,52 @@ impl Assembler {
         Ok(self)
     }
 
+    /// Sets the kernel for the assembler to the kernel defined by the provided modules.
+    ///
+    /// The modules are linked into a single kernel, and thus procedures exported from any of the
+    /// modules can be invoked via `syscall` instruction.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A procedure with the same name is exported from more than one of the modules.
+    /// - Any procedure in the kernel modules uses an instruction which cannot be executed within a
+    ///   `syscall` (see [Assembler::validate_kernel()]).
+    /// - Compiling any of the kernel modules results in an error.
+    pub fn with_kernel_modules(mut self, modules: &[ModuleAst]) -> Result<Self, AssemblyError> {
+        // all kernel modules share the kernel path, and thus exported procedure names must be
+        // unique across the modules
+        let kernel_path = LibraryPath::kernel_path();
+        let mut exported_names = BTreeSet::new();
+        for proc in modules.iter().flat_map(|module| module.procs()).filter(|p| p.is_export) {
+            if !exported_names.insert(&proc.name) {
+                return Err(AssemblyError::duplicate_proc_name(&proc.name, &kernel_path));
+            }
+        }
+
+        let errors = modules
+            .iter()
+            .filter_map(|module| validate_kernel_procedures(module).err())
+            .flatten()
+            .collect::<Vec<_>>();
+        if !errors.is_empty() {
+            return Err(AssemblyError::invalid_kernel_procedures(errors));
+        }
+
+        // compile the kernel modules; this adds all exported kernel procedures to the procedure
+        // cache. internal procedures of kernel modules cannot be invoked via `call` instruction,
+        // and thus they are never added to the cache and cannot collide across the modules.
+        let mut proc_roots = Vec::new();
+        for module in modules {
+            let mut context = AssemblyContext::for_module(true);
+            proc_roots.extend(self.compile_module(module, Some(&kernel_path), &mut context)?);
+        }
+
+        // build the kernel from hashes of procedures exported from all kernel modules
+        self.kernel = Kernel::new(&proc_roots).map_err(AssemblyError::KernelError)?;
+
+        Ok(self)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -108,6 +208,16 @@ impl Assembler {
         self.in_debug_mode
     }
 
+    /// Returns true if this assembler is allowed to compile `dynexec` and `dyncall` instructions.
+    pub fn dynamic_exec_enabled(&self) -> bool {
+        !self.dynamic_exec_disabled
+    }
+
+    /// Returns true if modules formatted by this assembler are annotated with stack effects.
+    pub fn stack_effect_comments_enabled(&self) -> bool {
+        self.stack_effect_comments
+    }
+
     /// Returns a reference to the kernel for this assembler.
     ///
     /// If the assembler was instantiated without a kernel, the internal kernel will be empty.
@@ -115,6 +225,59 @@ impl Assembler {
         &self.kernel
     }
 
+    // VALIDATIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that the targets of all `syscall` instructions in the provided program are
+    /// procedures exported from the provided kernel module.
+    ///
+    /// Unlike compilation, which fails on the first unresolved `syscall` target, this reports all
+    /// of them at once.
+    ///
+    /// # Errors
+    /// Returns an error for every `syscall` instruction in the program which invokes a procedure
+    /// not exported from the kernel.
+    pub fn validate_syscalls(
+        program: &ProgramAst,
+        kernel: &Module,
+    ) -> Result<(), Vec<ValidationError>> {
+        validate_syscalls(program, &kernel.ast)
+    }
+
+    /// Checks that no procedure in the provided kernel module uses `call`, `syscall`, `dyncall`,
+    /// `dynexec`, or `procref` instructions, as none of these can be executed within a `syscall`.
+    ///
+    /// This validation is also performed when a kernel is set via [Assembler::with_kernel()],
+    /// [Assembler::with_kernel_module()], or [Assembler::with_kernel_modules()].
+    ///
+    /// # Errors
+    /// Returns an error for every procedure in the kernel which uses any of the above
+    /// instructions.
+    pub fn validate_kernel(kernel: &Module) -> Result<(), Vec<ValidationError>> {
+        validate_kernel_procedures(&kernel.ast)
+    }
+
+    // FORMATTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Renders the provided module as normalized Miden assembly source code.
+    ///
+    /// If stack effect comments are enabled via [Assembler::with_stack_effect_comments()], every
+    /// instruction is annotated with its stack effect (see [Formatter::with_stack_effects()]).
+    ///
+    /// # Panics
+    /// Panics if the module invokes imported procedures but import info is not associated with it.
+    pub fn format_module(&self, module: &ModuleAst) -> String {
+        let formatter = Formatter::new(module);
+        let mut formatter = if self.stack_effect_comments {
+            formatter.with_stack_effects()
+        } else {
+            formatter
+        };
+        let _ = formatter.visit_module(module);
+        formatter.into_string()
+    }
+
     // PROGRAM COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -181,7 +344,7 @@ impl Assembler {
         }
 
         // compile the program body
-        let program_root = self.compile_body(program.body().nodes().iter(), context, None)?;
+        let program_root = self.compile_body(program.body(), context, None)?;
 
         Ok(program_root)
     }
@@ -214,7 +377,7 @@ impl Assembler {
             // make sure the re-exported procedure is loaded into the procedure cache
             let ref_proc_id = reexporteed_proc.proc_id();
             self.ensure_procedure_is_in_cache(&ref_proc_id, context).map_err(|_| {
-                AssemblyError::ReExportedProcModuleNotFound(reexporteed_proc.clone())
+                AssemblyError::ReExportedProcModuleNotFound(Box::new(reexporteed_proc.clone()))
             })?;
 
             // if the library path is provided, build procedure ID for the alias and add it to the
@@ -239,13 +402,11 @@ impl Assembler {
             proc_roots.push(proc_mast_root);
         }
 
-        // compile all local (internal end exported) procedures in the module; once the compilation
+        // compile all local (internal end exported) procedures in the module, unless the module
+        // has already been compiled by an assembler sharing the root cache; once the compilation
         // is complete, we get all compiled procedures (and their combined callset) from the
         // context
-        for proc_ast in module.procs().iter() {
-            self.compile_procedure(proc_ast, context)?;
-        }
-        let (module_procs, module_callset) = context.complete_module()?;
+        let (module_procs, module_callset) = self.compile_module_procs(module, path, context)?;
 
         // add the compiled procedures to the assembler's cache. the procedures are added to the
         // cache only if:
@@ -272,6 +433,56 @@ impl Assembler {
         Ok(proc_roots)
     }
 
+    /// Compiles all local procedures in the specified module, and returns the compiled procedures
+    /// together with their combined callset.
+    ///
+    /// If the assembler has a root cache, the procedures are taken from the cache when the module
+    /// is found in it. In this case, the procedures imported by the module are still loaded into
+    /// the procedure cache, as they may be invoked by the cached procedures.
+    fn compile_module_procs(
+        &self,
+        module: &ModuleAst,
+        path: Option<&LibraryPath>,
+        context: &mut AssemblyContext,
+    ) -> Result<(Vec<NamedProcedure>, CallSet), AssemblyError> {
+        let cached = match &self.root_cache {
+            Some(cache) if !context.is_kernel() => {
+                let anon_path = LibraryPath::anon_path();
+                let key = RootCache::module_key(
+                    module,
+                    path.unwrap_or(&anon_path),
+                    &self.kernel,
+                    self.module_provider.digest(),
+                    self.in_debug_mode,
+                    self.dynamic_exec_enabled(),
+                );
+                Some((cache, key))
+            }
+            _ => None,
+        };
+
+        if let Some((cache, key)) = &cached {
+            if let Some(compiled) = cache.get(key) {
+                for proc_id in module.import_info().get_imported_procedures().keys() {
+                    self.ensure_procedure_is_in_cache(proc_id, context)?;
+                }
+                context.complete_module()?;
+                return Ok(compiled);
+            }
+        }
+
+        for proc_ast in module.procs().iter() {
+            self.compile_procedure(proc_ast, context)?;
+        }
+        let (module_procs, module_callset) = context.complete_module()?;
+
+        if let Some((cache, key)) = cached {
+            cache.insert(key, &module_procs, &module_callset);
+        }
+
+        Ok((module_procs, module_callset))
+    }
+
     // PROCEDURE COMPILER
     // --------------------------------------------------------------------------------------------
 
@@ -292,9 +503,9 @@ impl Assembler {
                 prologue: vec![Operation::Push(num_locals), Operation::FmpUpdate],
                 epilogue: vec![Operation::Push(-num_locals), Operation::FmpUpdate],
             };
-            self.compile_body(proc.body.nodes().iter(), context, Some(wrapper))?
+            self.compile_body(&proc.body, context, Some(wrapper))?
         } else {
-            self.compile_body(proc.body.nodes().iter(), context, None)?
+            self.compile_body(&proc.body, context, None)?
         };
 
         context.complete_proc(code);
@@ -305,24 +516,27 @@ impl Assembler {
     // CODE BODY COMPILER
     // --------------------------------------------------------------------------------------------
 
-    /// TODO: add comments
-    fn compile_body<A, N>(
+    /// Compiles the provided code body into a [CodeBlock].
+    ///
+    /// If the body carries source locations, these are attached to the instructions tracked in
+    /// debug mode.
+    fn compile_body(
         &self,
-        body: A,
+        body: &CodeBody,
         context: &mut AssemblyContext,
         wrapper: Option<BodyWrapper>,
-    ) -> Result<CodeBlock, AssemblyError>
-    where
-        A: Iterator<Item = N>,
-        N: Borrow<Node>,
-    {
+    ) -> Result<CodeBlock, AssemblyError> {
         let mut blocks: Vec<CodeBlock> = Vec::new();
         let mut span = SpanBuilder::new(wrapper);
+        let locations = body.source_locations();
 
-        for node in body {
-            match node.borrow() {
+        for (idx, node) in body.nodes().iter().enumerate() {
+            match node {
                 Node::Instruction(inner) => {
-                    if let Some(block) = self.compile_instruction(inner, &mut span, context)? {
+                    let location = locations.get(idx);
+                    if let Some(block) =
+                        self.compile_instruction(inner, location, &mut span, context)?
+                    {
                         span.extract_span_into(&mut blocks);
                         blocks.push(block);
                     }
@@ -334,12 +548,12 @@ impl Assembler {
                 } => {
                     span.extract_span_into(&mut blocks);
 
-                    let true_case = self.compile_body(true_case.nodes().iter(), context, None)?;
+                    let true_case = self.compile_body(true_case, context, None)?;
 
                     // else is an exception because it is optional; hence, will have to be replaced
                     // by noop span
                     let false_case = if !false_case.nodes().is_empty() {
-                        self.compile_body(false_case.nodes().iter(), context, None)?
+                        self.compile_body(false_case, context, None)?
                     } else {
                         CodeBlock::new_span(vec![Operation::Noop])
                     };
@@ -352,7 +566,7 @@ impl Assembler {
                 Node::Repeat { times, body } => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
+                    let block = self.compile_body(body, context, None)?;
 
                     for _ in 0..*times {
                         blocks.push(block.clone());
@@ -362,7 +576,7 @@ impl Assembler {
                 Node::While { body } => {
                     span.extract_span_into(&mut blocks);
 
-                    let block = self.compile_body(body.nodes().iter(), context, None)?;
+                    let block = self.compile_body(body, context, None)?;
                     let block = CodeBlock::new_loop(block);
 
                     blocks.push(block);
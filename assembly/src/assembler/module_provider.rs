@@ -1,4 +1,9 @@
 use super::{Library, LibraryError, Module, ProcedureId};
+use crate::{
+    ast::AstSerdeOptions,
+    crypto::hash::{Rpo256, RpoDigest},
+    Serializable,
+};
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
 
@@ -12,6 +17,8 @@ pub struct ModuleProvider {
     modules: Vec<Module>,
     /// Map from procedure id to the index of a module in which the procedure is defined.
     procedures: BTreeMap<ProcedureId, usize>,
+    /// Commitment to the paths and the sources of all modules, in the order they were added.
+    digest: RpoDigest,
 }
 
 impl ModuleProvider {
@@ -24,6 +31,13 @@ impl ModuleProvider {
         self.procedures.get(id).map(|i| &self.modules[*i])
     }
 
+    /// Returns a commitment to all modules of this module provider.
+    ///
+    /// Module providers with the same modules, added in the same order, have the same digest.
+    pub fn digest(&self) -> RpoDigest {
+        self.digest
+    }
+
     // MODULE AND LIBRARY MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -47,6 +61,13 @@ impl ModuleProvider {
             let proc_id = ProcedureId::from(&proc_path);
             self.procedures.insert(proc_id, module_idx);
         }
+
+        let mut bytes = Vec::new();
+        module.path.write_into(&mut bytes);
+        module.ast.write_into(&mut bytes, AstSerdeOptions::new(true));
+        module.ast.write_source_locations(&mut bytes);
+        self.digest = Rpo256::merge(&[self.digest, Rpo256::hash(&bytes)]);
+
         self.modules.push(module);
         Ok(())
     }
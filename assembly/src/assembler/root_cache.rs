@@ -0,0 +1,111 @@
+use crate::{
+    ast::{AstSerdeOptions, ModuleAst},
+    crypto::hash::{Rpo256, RpoDigest},
+    ByteWriter, CallSet, Kernel, LibraryPath, NamedProcedure, Serializable,
+};
+use alloc::{collections::BTreeMap, rc::Rc, vec::Vec};
+use core::cell::RefCell;
+
+// ROOT CACHE
+// ================================================================================================
+
+/// A cache of compiled modules which can be shared between several assemblers.
+///
+/// When an assembler with a root cache compiles a module, it first looks up the module in the
+/// cache, and reuses the procedures (and their MAST roots) compiled previously by any assembler
+/// sharing the same cache. Modules are keyed by a hash of their source (i.e., their AST, including
+/// the imports and, in debug mode, the source locations), their library path, the kernel of the
+/// assembler (which determines the targets of `syscall` instructions), the modules of all
+/// libraries loaded into the assembler (which determine the imported procedures), and the options
+/// of the assembler which affect the compiled code. Kernel modules are never cached.
+///
+/// Cloning a cache returns a new handle to the same underlying cache.
+#[derive(Debug, Clone, Default)]
+pub struct RootCache {
+    modules: Rc<RefCell<BTreeMap<RpoDigest, CachedModule>>>,
+}
+
+impl RootCache {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of modules in this cache.
+    pub fn len(&self) -> usize {
+        self.modules.borrow().len()
+    }
+
+    /// Returns true if this cache does not contain any modules.
+    pub fn is_empty(&self) -> bool {
+        self.modules.borrow().is_empty()
+    }
+
+    // PUBLIC MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Removes all modules from this cache.
+    pub fn clear(&self) {
+        self.modules.borrow_mut().clear();
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the procedures (and their combined callset) of the module with the specified key,
+    /// if the module is in the cache.
+    pub(super) fn get(&self, key: &RpoDigest) -> Option<(Vec<NamedProcedure>, CallSet)> {
+        self.modules
+            .borrow()
+            .get(key)
+            .map(|module| (module.procs.clone(), module.callset.clone()))
+    }
+
+    /// Adds the procedures (and their combined callset) of the module with the specified key to
+    /// the cache.
+    pub(super) fn insert(&self, key: RpoDigest, procs: &[NamedProcedure], callset: &CallSet) {
+        let module = CachedModule {
+            procs: procs.to_vec(),
+            callset: callset.clone(),
+        };
+        self.modules.borrow_mut().insert(key, module);
+    }
+
+    /// Returns the key identifying the provided module compiled at the specified path by an
+    /// assembler with the specified kernel, libraries, and options.
+    ///
+    /// The libraries are identified by the digest of the module provider of the assembler.
+    pub(super) fn module_key(
+        module: &ModuleAst,
+        path: &LibraryPath,
+        kernel: &Kernel,
+        libraries: RpoDigest,
+        in_debug_mode: bool,
+        dynamic_exec_enabled: bool,
+    ) -> RpoDigest {
+        let mut bytes = Vec::new();
+        path.write_into(&mut bytes);
+        kernel.write_into(&mut bytes);
+        libraries.write_into(&mut bytes);
+        bytes.write_bool(in_debug_mode);
+        bytes.write_bool(dynamic_exec_enabled);
+        module.write_into(&mut bytes, AstSerdeOptions::new(true));
+        if in_debug_mode {
+            module.write_source_locations(&mut bytes);
+        }
+        Rpo256::hash(&bytes)
+    }
+}
+
+/// The procedures compiled from a single module.
+#[derive(Debug)]
+struct CachedModule {
+    procs: Vec<NamedProcedure>,
+    callset: CallSet,
+}
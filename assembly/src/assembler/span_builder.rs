@@ -1,6 +1,6 @@
 use super::{
     AssemblyContext, AssemblyError, BodyWrapper, Borrow, CodeBlock, Decorator, DecoratorList,
-    Instruction, Operation,
+    Instruction, Operation, SourceLocation,
 };
 use alloc::string::ToString;
 use alloc::vec::Vec;
@@ -83,6 +83,11 @@ impl SpanBuilder {
         self.ops.resize(new_len, op);
     }
 
+    /// Returns the number of operations currently in this builder, excluding the epilogue.
+    pub fn num_ops(&self) -> usize {
+        self.ops.len()
+    }
+
     // DECORATORS
     // --------------------------------------------------------------------------------------------
 
@@ -100,12 +105,21 @@ impl SpanBuilder {
     ///
     /// This indicates that the provided instruction should be tracked and the cycle count for
     /// this instruction will be computed when the call to set_instruction_cycle_count() is made.
-    pub fn track_instruction(&mut self, instruction: &Instruction, ctx: &AssemblyContext) {
+    /// The source location of the instruction, if known, is attached to the decorator.
+    pub fn track_instruction(
+        &mut self,
+        instruction: &Instruction,
+        location: Option<&SourceLocation>,
+        ctx: &AssemblyContext,
+    ) {
         let context_name = ctx.current_context_name().to_string();
         let num_cycles = 0;
         let op = instruction.to_string();
         let should_break = instruction.should_break();
-        let op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        let mut op = AssemblyOp::new(context_name, num_cycles, op, should_break);
+        if let Some(location) = location {
+            op = op.with_location(*location);
+        }
         self.push_decorator(Decorator::AsmOp(op));
         self.last_asmop_pos = self.decorators.len() - 1;
     }
@@ -1,12 +1,38 @@
-use super::{combine_blocks, Assembler, CodeBlock, Library, Module, Operation};
-use crate::{ast::ModuleAst, LibraryNamespace, LibraryPath, Version};
-use alloc::string::ToString;
+use super::{
+    combine_blocks, instruction_cost, Assembler, AssemblyContext, CodeBlock, Instruction, Library,
+    Module, Operation, RootCache,
+};
+use crate::{ast::ModuleAst, LibraryNamespace, LibraryPath, MaslLibrary, Version};
 use alloc::vec::Vec;
+use alloc::{format, string::ToString};
 use core::slice::Iter;
 
 // TESTS
 // ================================================================================================
 
+#[test]
+fn instruction_costs() {
+    assert_eq!(instruction_cost(&Instruction::Add), 1);
+    assert_eq!(instruction_cost(&Instruction::Sub), 2);
+    assert_eq!(instruction_cost(&Instruction::Xor), 7);
+    assert_eq!(instruction_cost(&Instruction::HPerm), 1);
+    assert_eq!(instruction_cost(&Instruction::U32AssertW), 6);
+
+    // procedure locals are converted into absolute addresses via FMPADD
+    assert_eq!(instruction_cost(&Instruction::LocLoad(0)), 3);
+
+    // `caller` is valid only in kernel procedures
+    assert_eq!(instruction_cost(&Instruction::Caller), 1);
+
+    // decorators do not take any cycles
+    assert_eq!(instruction_cost(&Instruction::Emit(1)), 0);
+
+    // invocations are assigned zero cost
+    assert_eq!(instruction_cost(&Instruction::ExecLocal(0)), 0);
+    assert_eq!(instruction_cost(&Instruction::DynCall), 0);
+    assert_eq!(instruction_cost(&Instruction::ProcRefLocal(0)), 4);
+}
+
 #[test]
 fn nested_blocks() {
     const NAMESPACE: &str = "foo";
@@ -141,3 +167,95 @@ fn nested_blocks() {
 
     assert_eq!(combined.hash(), program.hash());
 }
+
+#[test]
+fn root_cache_reuses_compiled_modules() {
+    let source = "\
+        proc.foo
+            push.1 add
+        end
+        export.bar
+            exec.foo
+            mul
+        end";
+    let module = ModuleAst::parse(source).unwrap();
+    let path = LibraryPath::new("foo::bar").unwrap();
+    let cache = RootCache::new();
+
+    let assembler = Assembler::default().with_root_cache(cache.clone());
+    let mut context = AssemblyContext::for_module(false);
+    let roots = assembler.compile_module(&module, Some(&path), &mut context).unwrap();
+    assert_eq!(cache.len(), 1);
+
+    // the second assembler takes the procedures from the cache, but still adds the exported
+    // procedure to its own procedure cache
+    let assembler = Assembler::default().with_root_cache(cache.clone());
+    let mut context = AssemblyContext::for_module(false);
+    let cached_roots = assembler.compile_module(&module, Some(&path), &mut context).unwrap();
+    assert_eq!(roots, cached_roots);
+    assert_eq!(cache.len(), 1);
+    assert_eq!(assembler.proc_cache.borrow().len(), 1);
+
+    // the compiled code in debug mode differs, and thus the module is cached separately
+    let assembler = Assembler::default().with_debug_mode(true).with_root_cache(cache.clone());
+    let mut context = AssemblyContext::for_module(false);
+    assembler.compile_module(&module, Some(&path), &mut context).unwrap();
+    assert_eq!(cache.len(), 2);
+
+    // the targets of syscalls depend on the kernel, and thus the module is cached separately for
+    // each kernel, and a syscall to a procedure missing from the kernel is still rejected
+    let module = ModuleAst::parse("export.baz syscall.foo end").unwrap();
+    let assembler = Assembler::default()
+        .with_kernel("export.foo add end")
+        .unwrap()
+        .with_root_cache(cache.clone());
+    let mut context = AssemblyContext::for_module(false);
+    assembler.compile_module(&module, Some(&path), &mut context).unwrap();
+    assert_eq!(cache.len(), 3);
+
+    let assembler = Assembler::default()
+        .with_kernel("export.bar mul end")
+        .unwrap()
+        .with_root_cache(cache.clone());
+    let mut context = AssemblyContext::for_module(false);
+    assert!(assembler.compile_module(&module, Some(&path), &mut context).is_err());
+
+    cache.clear();
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn root_cache_distinguishes_library_versions() {
+    let module = ModuleAst::parse("use.foo::bar export.baz exec.bar::qux end").unwrap();
+    let path = LibraryPath::new("app::main").unwrap();
+    let cache = RootCache::new();
+
+    // compiles the module through the shared cache against a library whose `qux` procedure
+    // pushes the specified value
+    let compile = |value: u32| {
+        let namespace = LibraryNamespace::new("foo").unwrap();
+        let source = format!("export.qux push.{value} end");
+        let library_module = Module {
+            path: LibraryPath::new("foo::bar").unwrap(),
+            ast: ModuleAst::parse(&source).unwrap(),
+        };
+        let library =
+            MaslLibrary::new(namespace, Version::MIN, false, vec![library_module], Vec::new())
+                .unwrap();
+        let assembler = Assembler::default()
+            .with_library(&library)
+            .unwrap()
+            .with_root_cache(cache.clone());
+        let mut context = AssemblyContext::for_module(false);
+        assembler.compile_module(&module, Some(&path), &mut context).unwrap()
+    };
+
+    // both the module and the imported library module are cached
+    let roots = compile(1);
+    assert_eq!(roots, compile(1));
+    assert_eq!(cache.len(), 2);
+
+    // a different version of the imported library results in a different MAST root
+    assert_ne!(roots, compile(2));
+    assert_eq!(cache.len(), 4);
+}
@@ -115,6 +115,12 @@ impl CodeBody {
         &self.nodes
     }
 
+    /// Returns mutable access to the [Node] sequence, together with the [SourceLocations] bound
+    /// to the nodes.
+    pub(super) fn nodes_mut(&mut self) -> (&mut [Node], &[SourceLocation]) {
+        (&mut self.nodes, &self.locations)
+    }
+
     /// Returns the [SourceLocations] bound to the nodes of this body structure.
     pub fn source_locations(&self) -> &[SourceLocation] {
         &self.locations
@@ -2,10 +2,9 @@ use super::{
     CodeBody, FormattableNode, InvokedProcsMap, LibraryPath, ProcedureAst, ProcedureId,
     ProcedureName,
 };
-use alloc::vec::Vec;
 use core::fmt;
 
-const INDENT_STRING: &str = "    ";
+pub(super) const INDENT_STRING: &str = "    ";
 
 /// Context for the Ast formatter
 ///
@@ -13,13 +12,13 @@ const INDENT_STRING: &str = "    ";
 /// procedures in the program/module being formatted.
 pub struct AstFormatterContext<'a> {
     indent_level: usize,
-    local_procs: &'a Vec<ProcedureAst>,
+    local_procs: &'a [ProcedureAst],
     imported_procs: &'a InvokedProcsMap,
 }
 
 impl<'a> AstFormatterContext<'a> {
     pub fn new(
-        local_procs: &'a Vec<ProcedureAst>,
+        local_procs: &'a [ProcedureAst],
         imported_procs: &'a InvokedProcsMap,
     ) -> AstFormatterContext<'a> {
         Self {
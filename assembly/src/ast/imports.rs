@@ -125,6 +125,12 @@ impl ModuleImports {
         self.invoked_procs.iter().map(|(id, (name, _))| (*id, name.clone())).collect()
     }
 
+    /// Returns a reference to the internal map of imported modules, which maps module names to
+    /// their paths.
+    pub(super) fn imported_modules(&self) -> &ImportedModulesMap {
+        &self.imports
+    }
+
     /// Returns a reference to the internal invoked procedure map which maps procedure IDs to their names and paths.
     pub(super) fn invoked_procs(&self) -> &InvokedProcsMap {
         &self.invoked_procs
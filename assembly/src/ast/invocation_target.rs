@@ -1,9 +1,11 @@
 use super::{
-    parsers::decode_hex_rpo_digest_label, LibraryPath, ParsingError, ProcedureName, RpoDigest,
-    Token,
+    nodes::display_hex_bytes, parsers::decode_hex_rpo_digest_label, LibraryPath, ParsingError,
+    ProcedureName, RpoDigest, Token,
 };
+use core::fmt;
 
 /// Describes targets of `exec`, `call`, and `syscall` instructions.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum InvocationTarget<'a> {
     MastRoot(RpoDigest),
     ProcedureName(ProcedureName),
@@ -65,3 +67,18 @@ impl<'a> InvocationTarget<'a> {
         }
     }
 }
+
+impl fmt::Display for InvocationTarget<'_> {
+    /// Renders the target in the same form as it is written in the source code, i.e., either as
+    /// a hex-encoded MAST root (`0x...`), or as a procedure name optionally qualified by the name
+    /// of the module it is imported from (`u64::wrapping_add`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MastRoot(root) => display_hex_bytes(f, &root.as_bytes()),
+            Self::ProcedureName(name) => write!(f, "{name}"),
+            Self::ProcedurePath { name, module } => {
+                write!(f, "{module}{}{name}", LibraryPath::PATH_DELIM)
+            }
+        }
+    }
+}
@@ -18,7 +18,7 @@ pub use super::tokens::SourceLocation;
 
 mod nodes;
 use nodes::FormattableNode;
-pub use nodes::{AdviceInjectorNode, Instruction, Node};
+pub use nodes::{AdviceInjectorNode, ImmediateArity, Instruction, Node};
 
 mod code_body;
 pub use code_body::CodeBody;
@@ -50,6 +50,23 @@ pub(crate) use parsers::{
 mod serde;
 pub use serde::AstSerdeOptions;
 
+pub mod visit;
+pub use visit::{Callee, Visit, VisitInst, VisitMut};
+
+mod passes;
+pub use passes::{
+    block_stack_effect, check_call_depths, estimate_depth_on_return, estimate_expanded_size,
+    find_first, find_redundant_ops, find_stack_underflows, fold_constants, format_module,
+    inline_procedures, instruction_category, max_nesting_depth, procedure_fan_in,
+    program_cost_report, stack_effect, stack_inputs, validate_immediates,
+    validate_kernel_procedures, validate_local_accesses, validate_local_references,
+    validate_syscalls, CallDepthChecker, CallGraph, CategoryCost, ConstantFolder, CostEstimator,
+    CostReport, ExpandedSizeEstimator, FanInCounter, FindFirstInstruction, Formatter,
+    ImmediateRangeValidator, Inliner, InstructionCategory, KernelProcedureValidator,
+    LocalAccessValidator, LocalReferenceValidator, NestingDepthCounter, RedundantOpDetector,
+    StackDepthEstimator, StackUnderflowDetector, SyscallValidator,
+};
+
 #[cfg(test)]
 pub mod tests;
 
@@ -28,10 +28,15 @@ pub enum AdviceInjectorNode {
     PushMapValImm { offset: u8 },
     PushMapValN,
     PushMapValNImm { offset: u8 },
+    PushMapValLen,
+    PushMapValLenImm { offset: u8 },
     PushMtNode,
+    PushHasMtree,
+    PushMem,
     InsertMem,
     InsertHdword,
     InsertHdwordImm { domain: u8 },
+    InsertHdwordDefault,
     InsertHperm,
     PushSignature { kind: SignatureKind },
 }
@@ -61,12 +66,19 @@ impl From<&AdviceInjectorNode> for AdviceInjector {
                 include_len: true,
                 key_offset: (*offset) as usize,
             },
+            PushMapValLen => Self::MapValueLenToStack { key_offset: 0 },
+            PushMapValLenImm { offset } => Self::MapValueLenToStack {
+                key_offset: (*offset) as usize,
+            },
             PushMtNode => Self::MerkleNodeToStack,
+            PushHasMtree => Self::HasMerkleRoot,
+            PushMem => Self::MemToStack,
             InsertMem => Self::MemToMap,
             InsertHdword => Self::HdwordToMap { domain: ZERO },
             InsertHdwordImm { domain } => Self::HdwordToMap {
                 domain: Felt::from(*domain),
             },
+            InsertHdwordDefault => Self::HdwordToMapDefault,
             InsertHperm => Self::HpermToMap,
             PushSignature { kind } => Self::SigToStack { kind: *kind },
         }
@@ -86,10 +98,15 @@ impl fmt::Display for AdviceInjectorNode {
             PushMapValImm { offset } => write!(f, "push_mapval.{offset}"),
             PushMapValN => write!(f, "push_mapvaln"),
             PushMapValNImm { offset } => write!(f, "push_mapvaln.{offset}"),
+            PushMapValLen => write!(f, "push_mapval_len"),
+            PushMapValLenImm { offset } => write!(f, "push_mapval_len.{offset}"),
             PushMtNode => write!(f, "push_mtnode"),
+            PushHasMtree => write!(f, "push_has_mtree"),
+            PushMem => write!(f, "push_mem"),
             InsertMem => write!(f, "insert_mem"),
-            InsertHdword => write!(f, "insert_hdword"),
+            InsertHdword => write!(f, "insert_hdword.0"),
             InsertHdwordImm { domain } => write!(f, "insert_hdword.{domain}"),
+            InsertHdwordDefault => write!(f, "insert_hdword"),
             InsertHperm => writeln!(f, "insert_hperm"),
             PushSignature { kind } => write!(f, "push_sig.{kind}"),
         }
@@ -114,6 +131,11 @@ const INSERT_HDWORD: u8 = 11;
 const INSERT_HDWORD_IMM: u8 = 12;
 const INSERT_HPERM: u8 = 13;
 const PUSH_SIG: u8 = 14;
+const PUSH_HAS_MTREE: u8 = 15;
+const PUSH_MEM: u8 = 16;
+const PUSH_MAPVAL_LEN: u8 = 17;
+const PUSH_MAPVAL_LEN_IMM: u8 = 18;
+const INSERT_HDWORD_DEFAULT: u8 = 19;
 
 impl Serializable for AdviceInjectorNode {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
@@ -134,13 +156,21 @@ impl Serializable for AdviceInjectorNode {
                 target.write_u8(PUSH_MAPVALN_IMM);
                 target.write_u8(*offset);
             }
+            PushMapValLen => target.write_u8(PUSH_MAPVAL_LEN),
+            PushMapValLenImm { offset } => {
+                target.write_u8(PUSH_MAPVAL_LEN_IMM);
+                target.write_u8(*offset);
+            }
             PushMtNode => target.write_u8(PUSH_MTNODE),
+            PushHasMtree => target.write_u8(PUSH_HAS_MTREE),
+            PushMem => target.write_u8(PUSH_MEM),
             InsertMem => target.write_u8(INSERT_MEM),
             InsertHdword => target.write_u8(INSERT_HDWORD),
             InsertHdwordImm { domain } => {
                 target.write_u8(INSERT_HDWORD_IMM);
                 target.write_u8(*domain);
             }
+            InsertHdwordDefault => target.write_u8(INSERT_HDWORD_DEFAULT),
             InsertHperm => target.write_u8(INSERT_HPERM),
             PushSignature { kind } => {
                 target.write_u8(PUSH_SIG);
@@ -174,13 +204,24 @@ impl Deserializable for AdviceInjectorNode {
                 }
                 Ok(AdviceInjectorNode::PushMapValNImm { offset })
             }
+            PUSH_MAPVAL_LEN => Ok(AdviceInjectorNode::PushMapValLen),
+            PUSH_MAPVAL_LEN_IMM => {
+                let offset = source.read_u8()?;
+                if offset > MAX_STACK_WORD_OFFSET {
+                    return Err(DeserializationError::InvalidValue("invalid offset".to_string()));
+                }
+                Ok(AdviceInjectorNode::PushMapValLenImm { offset })
+            }
             PUSH_MTNODE => Ok(AdviceInjectorNode::PushMtNode),
+            PUSH_HAS_MTREE => Ok(AdviceInjectorNode::PushHasMtree),
+            PUSH_MEM => Ok(AdviceInjectorNode::PushMem),
             INSERT_MEM => Ok(AdviceInjectorNode::InsertMem),
             INSERT_HDWORD => Ok(AdviceInjectorNode::InsertHdword),
             INSERT_HDWORD_IMM => {
                 let domain = source.read_u8()?;
                 Ok(AdviceInjectorNode::InsertHdwordImm { domain })
             }
+            INSERT_HDWORD_DEFAULT => Ok(AdviceInjectorNode::InsertHdwordDefault),
             INSERT_HPERM => Ok(AdviceInjectorNode::InsertHperm),
             PUSH_SIG => Ok(AdviceInjectorNode::PushSignature {
                 kind: signatures::read_options_from(source)?,
@@ -0,0 +1,168 @@
+use ImmediateArity::*;
+
+// IMMEDIATE ARITY
+// ================================================================================================
+
+/// The number of immediate values (i.e., the dot-separated parameters following the mnemonic)
+/// accepted by an instruction.
+///
+/// Immediate values which are not numbers are counted as well, e.g., the name of the procedure in
+/// `exec.foo` or the injector in `adv.push_mapval`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImmediateArity {
+    /// The instruction does not accept immediate values, e.g., `swapdw`.
+    Zero,
+    /// The instruction accepts an optional immediate value, e.g., `add` and `add.1`.
+    ZeroOrOne,
+    /// The instruction requires a single immediate value, e.g., `movup.2`.
+    One,
+    /// The instruction requires at least one immediate value, e.g., `push.1.2` and `debug.stack.4`.
+    OneOrMore,
+}
+
+// INSTRUCTION MNEMONICS
+// ================================================================================================
+
+/// Mnemonics of all instructions accepted by the parser, together with the number of immediate
+/// values they accept, grouped in the same way as the variants of
+/// [Instruction](super::Instruction).
+///
+/// Several variants of [Instruction](super::Instruction) may share a mnemonic (e.g., `Add` and
+/// `AddImm` are both written as `add`).
+pub(super) const INSTRUCTION_MNEMONICS: &[(&str, ImmediateArity)] = &[
+    // ----- field operations ----------------------------------------------------------------------
+    ("assert", ZeroOrOne),
+    ("assertz", ZeroOrOne),
+    ("assert_eq", ZeroOrOne),
+    ("assert_eqw", ZeroOrOne),
+    ("add", ZeroOrOne),
+    ("sub", ZeroOrOne),
+    ("mul", ZeroOrOne),
+    ("div", ZeroOrOne),
+    ("neg", Zero),
+    ("inv", Zero),
+    ("pow2", Zero),
+    ("exp", ZeroOrOne),
+    ("ilog2", Zero),
+    ("not", Zero),
+    ("and", Zero),
+    ("or", Zero),
+    ("xor", Zero),
+    ("eq", ZeroOrOne),
+    ("neq", ZeroOrOne),
+    ("lt", Zero),
+    ("lte", Zero),
+    ("gt", Zero),
+    ("gte", Zero),
+    ("is_odd", Zero),
+    ("eqw", Zero),
+    // ----- ext2 operations -----------------------------------------------------------------------
+    ("ext2add", Zero),
+    ("ext2sub", Zero),
+    ("ext2mul", Zero),
+    ("ext2div", Zero),
+    ("ext2neg", Zero),
+    ("ext2inv", Zero),
+    // ----- u32 operations ------------------------------------------------------------------------
+    ("u32test", Zero),
+    ("u32testw", Zero),
+    ("u32assert", ZeroOrOne),
+    ("u32assert2", ZeroOrOne),
+    ("u32assertw", ZeroOrOne),
+    ("u32cast", Zero),
+    ("u32split", Zero),
+    ("u32wrapping_add", ZeroOrOne),
+    ("u32overflowing_add", ZeroOrOne),
+    ("u32overflowing_add3", Zero),
+    ("u32wrapping_add3", Zero),
+    ("u32wrapping_sub", ZeroOrOne),
+    ("u32overflowing_sub", ZeroOrOne),
+    ("u32wrapping_mul", ZeroOrOne),
+    ("u32overflowing_mul", ZeroOrOne),
+    ("u32overflowing_madd", Zero),
+    ("u32wrapping_madd", Zero),
+    ("u32div", ZeroOrOne),
+    ("u32mod", ZeroOrOne),
+    ("u32divmod", ZeroOrOne),
+    ("u32and", Zero),
+    ("u32or", Zero),
+    ("u32xor", Zero),
+    ("u32not", Zero),
+    ("u32shr", ZeroOrOne),
+    ("u32shl", ZeroOrOne),
+    ("u32rotr", ZeroOrOne),
+    ("u32rotl", ZeroOrOne),
+    ("u32popcnt", Zero),
+    ("u32clz", Zero),
+    ("u32ctz", Zero),
+    ("u32clo", Zero),
+    ("u32cto", Zero),
+    ("u32lt", Zero),
+    ("u32lte", Zero),
+    ("u32gt", Zero),
+    ("u32gte", Zero),
+    ("u32min", Zero),
+    ("u32max", Zero),
+    // ----- stack manipulation --------------------------------------------------------------------
+    ("drop", Zero),
+    ("dropw", Zero),
+    ("padw", Zero),
+    ("dup", ZeroOrOne),
+    ("dupw", ZeroOrOne),
+    ("swap", ZeroOrOne),
+    ("swapw", ZeroOrOne),
+    ("swapdw", Zero),
+    ("movup", One),
+    ("movupw", One),
+    ("movdn", One),
+    ("movdnw", One),
+    ("cswap", Zero),
+    ("cswapw", Zero),
+    ("cdrop", Zero),
+    ("cdropw", Zero),
+    // ----- input / output operations -------------------------------------------------------------
+    ("push", OneOrMore),
+    ("sdepth", Zero),
+    ("overflow_depth", Zero),
+    ("locaddr", One),
+    ("caller", Zero),
+    ("clk", Zero),
+    ("mem_load", ZeroOrOne),
+    ("loc_load", One),
+    ("mem_loadw", ZeroOrOne),
+    ("loc_loadw", One),
+    ("mem_store", ZeroOrOne),
+    ("loc_store", One),
+    ("mem_storew", ZeroOrOne),
+    ("loc_storew", One),
+    ("mem_stream", Zero),
+    ("adv_pipe", Zero),
+    ("adv_push", One),
+    ("adv_loadw", Zero),
+    ("adv", OneOrMore),
+    // ----- cryptographic operations --------------------------------------------------------------
+    ("hash", Zero),
+    ("hmerge", Zero),
+    ("hperm", Zero),
+    ("mtree_get", Zero),
+    ("mtree_set", Zero),
+    ("mtree_merge", Zero),
+    ("mtree_verify", Zero),
+    ("mtree_assert_root", Zero),
+    // ----- STARK proof verification --------------------------------------------------------------
+    ("fri_ext2fold4", Zero),
+    ("rcomb_base", Zero),
+    // ----- procedure invocations -----------------------------------------------------------------
+    ("exec", One),
+    ("call", One),
+    ("syscall", One),
+    ("dynexec", Zero),
+    ("dyncall", Zero),
+    ("procref", One),
+    // ----- debug decorators ----------------------------------------------------------------------
+    ("breakpoint", Zero),
+    ("debug", OneOrMore),
+    // ----- event decorators ----------------------------------------------------------------------
+    ("emit", One),
+    ("trace", One),
+];
@@ -9,6 +9,10 @@ pub use advice::AdviceInjectorNode;
 mod format;
 pub use format::*;
 
+mod mnemonics;
+pub use mnemonics::ImmediateArity;
+use mnemonics::INSTRUCTION_MNEMONICS;
+
 mod serde;
 
 // TYPE ALIASES
@@ -236,6 +240,7 @@ pub enum Instruction {
     PushFeltList(Vec<Felt>),
     Locaddr(u16),
     Sdepth,
+    OverflowDepth,
     Caller,
     Clk,
 
@@ -269,6 +274,7 @@ pub enum Instruction {
     MTreeSet,
     MTreeMerge,
     MTreeVerify,
+    MTreeAssertRoot,
 
     // ----- STARK proof verification -------------------------------------------------------------
     FriExt2Fold4,
@@ -296,6 +302,15 @@ pub enum Instruction {
 }
 
 impl Instruction {
+    /// Returns the mnemonics of all instructions supported by the assembler, together with the
+    /// number of immediate values accepted by each of them.
+    ///
+    /// Every mnemonic is listed once, even if it is parsed into several instructions depending
+    /// on its immediate values (e.g., `add` and `add.1`).
+    pub const fn all_mnemonics() -> &'static [(&'static str, ImmediateArity)] {
+        INSTRUCTION_MNEMONICS
+    }
+
     /// Returns true if the instruction should yield a breakpoint.
     pub const fn should_break(&self) -> bool {
         matches!(self, Self::Breakpoint)
@@ -502,6 +517,7 @@ impl fmt::Display for Instruction {
 
             Self::Locaddr(value) => write!(f, "locaddr.{value}"),
             Self::Sdepth => write!(f, "sdepth"),
+            Self::OverflowDepth => write!(f, "overflow_depth"),
             Self::Caller => write!(f, "caller"),
             Self::Clk => write!(f, "clk"),
 
@@ -535,6 +551,7 @@ impl fmt::Display for Instruction {
             Self::MTreeSet => write!(f, "mtree_set"),
             Self::MTreeMerge => write!(f, "mtree_merge"),
             Self::MTreeVerify => write!(f, "mtree_verify"),
+            Self::MTreeAssertRoot => write!(f, "mtree_assert_root"),
 
             // ----- STARK proof verification -----------------------------------------------------
             Self::FriExt2Fold4 => write!(f, "fri_ext2fold4"),
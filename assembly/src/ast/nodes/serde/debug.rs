@@ -6,6 +6,8 @@ const STACK_TOP: u8 = 1;
 const MEM_ALL: u8 = 2;
 const MEM_INTERVAL: u8 = 3;
 const LOCAL_INTERVAL: u8 = 4;
+const MEM_INTERVAL_CTX: u8 = 5;
+const CHECKPOINT: u8 = 6;
 
 /// Writes the provided [DebugOptions] into the provided target.
 pub fn write_options_into<W: ByteWriter>(target: &mut W, options: &DebugOptions) {
@@ -21,12 +23,22 @@ pub fn write_options_into<W: ByteWriter>(target: &mut W, options: &DebugOptions)
             target.write_u32(*n);
             target.write_u32(*m);
         }
+        DebugOptions::MemIntervalCtx(ctx, n, m) => {
+            target.write_u8(MEM_INTERVAL_CTX);
+            target.write_u32(*ctx);
+            target.write_u32(*n);
+            target.write_u32(*m);
+        }
         DebugOptions::LocalInterval(start, end, num_locals) => {
             target.write_u8(LOCAL_INTERVAL);
             target.write_u16(*start);
             target.write_u16(*end);
             target.write_u16(*num_locals);
         }
+        DebugOptions::Checkpoint(label) => {
+            target.write_u8(CHECKPOINT);
+            target.write_u32(*label);
+        }
     }
 }
 
@@ -49,12 +61,19 @@ pub fn read_options_from<R: ByteReader>(
             let m = source.read_u32()?;
             Ok(DebugOptions::MemInterval(n, m))
         }
+        MEM_INTERVAL_CTX => {
+            let ctx = source.read_u32()?;
+            let n = source.read_u32()?;
+            let m = source.read_u32()?;
+            Ok(DebugOptions::MemIntervalCtx(ctx, n, m))
+        }
         LOCAL_INTERVAL => {
             let n = source.read_u16()?;
             let m = source.read_u16()?;
             let num_locals = source.read_u16()?;
             Ok(DebugOptions::LocalInterval(n, m, num_locals))
         }
+        CHECKPOINT => Ok(DebugOptions::Checkpoint(source.read_u32()?)),
         val => Err(DeserializationError::InvalidValue(val.to_string())),
     }
 }
@@ -291,6 +291,7 @@ impl Deserializable for Instruction {
 
             OpCode::Locaddr => Ok(Instruction::Locaddr(source.read_u16()?)),
             OpCode::Sdepth => Ok(Instruction::Sdepth),
+            OpCode::OverflowDepth => Ok(Instruction::OverflowDepth),
             OpCode::Caller => Ok(Instruction::Caller),
             OpCode::Clk => Ok(Instruction::Clk),
 
@@ -323,6 +324,7 @@ impl Deserializable for Instruction {
             OpCode::MTreeSet => Ok(Instruction::MTreeSet),
             OpCode::MTreeMerge => Ok(Instruction::MTreeMerge),
             OpCode::MTreeVerify => Ok(Instruction::MTreeVerify),
+            OpCode::MTreeAssertRoot => Ok(Instruction::MTreeAssertRoot),
 
             // ----- STARK proof verification -----------------------------------------------------
             OpCode::FriExt2Fold4 => Ok(Instruction::FriExt2Fold4),
@@ -212,6 +212,7 @@ pub enum OpCode {
 
     Locaddr = 186,
     Sdepth = 187,
+    OverflowDepth = 230,
     Caller = 188,
     Clk = 189,
 
@@ -244,6 +245,7 @@ pub enum OpCode {
     MTreeSet = 211,
     MTreeMerge = 212,
     MTreeVerify = 213,
+    MTreeAssertRoot = 229,
 
     // ----- STARK proof verification -------------------------------------------------------------
     FriExt2Fold4 = 214,
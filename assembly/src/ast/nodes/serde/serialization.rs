@@ -366,6 +366,7 @@ impl Serializable for Instruction {
                 target.write_u16(*v);
             }
             Self::Sdepth => OpCode::Sdepth.write_into(target),
+            Self::OverflowDepth => OpCode::OverflowDepth.write_into(target),
             Self::Caller => OpCode::Caller.write_into(target),
             Self::Clk => OpCode::Clk.write_into(target),
 
@@ -428,6 +429,7 @@ impl Serializable for Instruction {
             Self::MTreeSet => OpCode::MTreeSet.write_into(target),
             Self::MTreeMerge => OpCode::MTreeMerge.write_into(target),
             Self::MTreeVerify => OpCode::MTreeVerify.write_into(target),
+            Self::MTreeAssertRoot => OpCode::MTreeAssertRoot.write_into(target),
 
             // ----- STARK proof verification -----------------------------------------------------
             Self::FriExt2Fold4 => OpCode::FriExt2Fold4.write_into(target),
@@ -1,7 +1,8 @@
 use super::{
-    parse_checked_param,
+    parse_checked_param, parse_param_with_constant_lookup,
     AdviceInjectorNode::*,
     Instruction::AdvInject,
+    LocalConstMap,
     Node::{self, Instruction},
     ParsingError, Token, MAX_STACK_WORD_OFFSET,
 };
@@ -12,10 +13,14 @@ use vm_core::SignatureKind;
 
 /// Returns `AdvInject` instruction node with an appropriate internal advice injector variant.
 ///
+/// The domain of `adv.insert_hdword` can be provided as a constant label or as a u8 value. If the
+/// domain is omitted, the default domain of the executor is used.
+///
 /// # Errors
 /// Returns an error if parsing of the internal advice injector variant fails due to wrong number
-/// of parameters or invalid parameter values.
-pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
+/// of parameters or invalid parameter values, or if the constant used as the domain does not exist
+/// or is not a u8 value.
+pub fn parse_adv_inject(op: &Token, constants: &LocalConstMap) -> Result<Node, ParsingError> {
     debug_assert_eq!(op.parts()[0], "adv");
     if op.num_parts() < 2 {
         return Err(ParsingError::missing_param(op, "adv.<injector>.<injector_param?>"));
@@ -66,18 +71,38 @@ pub fn parse_adv_inject(op: &Token) -> Result<Node, ParsingError> {
             }
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_mapval_len" => match op.num_parts() {
+            2 => AdvInject(PushMapValLen),
+            3 => {
+                let offset = parse_checked_param::<u8, _>(op, 2, 0..=MAX_STACK_WORD_OFFSET)?;
+                if offset == 0 {
+                    AdvInject(PushMapValLen)
+                } else {
+                    AdvInject(PushMapValLenImm { offset })
+                }
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "push_mtnode" => match op.num_parts() {
             2 => AdvInject(PushMtNode),
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "push_has_mtree" => match op.num_parts() {
+            2 => AdvInject(PushHasMtree),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
+        "push_mem" => match op.num_parts() {
+            2 => AdvInject(PushMem),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "insert_mem" => match op.num_parts() {
             2 => AdvInject(InsertMem),
             _ => return Err(ParsingError::extra_param(op)),
         },
         "insert_hdword" => match op.num_parts() {
-            2 => AdvInject(InsertHdword),
+            2 => AdvInject(InsertHdwordDefault),
             3 => {
-                let domain = parse_checked_param::<u8, _>(op, 2, 0..=u8::MAX)?;
+                let domain = parse_param_with_constant_lookup::<u8>(op, 2, constants)?;
                 if domain == 0 {
                     AdvInject(InsertHdword)
                 } else {
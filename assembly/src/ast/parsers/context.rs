@@ -382,8 +382,7 @@ impl ParserContext<'_> {
             }
         }
 
-        let proc_id = ProcedureId::from_name(&ref_name, module_path);
-        Ok(ProcReExport::new(proc_id, proc_name, docs))
+        Ok(ProcReExport::new(module_path.clone(), ref_name, proc_name, docs))
     }
 
     // BODY PARSER
@@ -581,6 +580,7 @@ impl ParserContext<'_> {
             "push" => io_ops::parse_push(op, &self.local_constants),
 
             "sdepth" => simple_instruction(op, Sdepth),
+            "overflow_depth" => simple_instruction(op, OverflowDepth),
             "locaddr" => io_ops::parse_locaddr(op, &self.local_constants),
             "caller" => simple_instruction(op, Caller), // TODO: error if not in SYSCALL (issue #551)
             "clk" => simple_instruction(op, Clk),
@@ -603,7 +603,7 @@ impl ParserContext<'_> {
             "adv_push" => io_ops::parse_adv_push(op),
             "adv_loadw" => simple_instruction(op, AdvLoadW),
 
-            "adv" => adv_ops::parse_adv_inject(op),
+            "adv" => adv_ops::parse_adv_inject(op, &self.local_constants),
 
             // ----- cryptographic operations -----------------------------------------------------
             "hash" => simple_instruction(op, Hash),
@@ -614,6 +614,7 @@ impl ParserContext<'_> {
             "mtree_set" => simple_instruction(op, MTreeSet),
             "mtree_merge" => simple_instruction(op, MTreeMerge),
             "mtree_verify" => simple_instruction(op, MTreeVerify),
+            "mtree_assert_root" => simple_instruction(op, MTreeAssertRoot),
 
             // ----- STARK proof verification -----------------------------------------------------
             "fri_ext2fold4" => simple_instruction(op, FriExt2Fold4),
@@ -45,6 +45,26 @@ pub fn parse_debug(op: &Token, num_proc_locals: u16) -> Result<Node, ParsingErro
             }
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "mem_ctx" => match op.num_parts() {
+            4 => {
+                let ctx: u32 = parse_checked_param(op, 2, 0..=u32::MAX)?;
+                let n: u32 = parse_checked_param(op, 3, 0..=u32::MAX)?;
+                DebugOptions::MemIntervalCtx(ctx, n, n)
+            }
+            5 => {
+                let ctx: u32 = parse_checked_param(op, 2, 0..=u32::MAX)?;
+                let n: u32 = parse_checked_param(op, 3, 0..=u32::MAX)?;
+                let m: u32 = parse_checked_param(op, 4, 0..=u32::MAX)?;
+                if m < n {
+                    return Err(ParsingError::invalid_param_with_reason(op, 4, "the index of the end of the interval must be greater than the index of its beginning"));
+                }
+                DebugOptions::MemIntervalCtx(ctx, n, m)
+            }
+            num_parts if num_parts < 4 => {
+                return Err(ParsingError::missing_param(op, "debug.mem_ctx.<ctx>.<start>.<end?>"))
+            }
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         "local" => match op.num_parts() {
             2 => DebugOptions::LocalInterval(0, u16::MAX, num_proc_locals),
             3 => {
@@ -61,6 +81,14 @@ pub fn parse_debug(op: &Token, num_proc_locals: u16) -> Result<Node, ParsingErro
             }
             _ => return Err(ParsingError::extra_param(op)),
         },
+        "checkpoint" => match op.num_parts() {
+            3 => {
+                let label: u32 = parse_checked_param(op, 2, 0..=u32::MAX)?;
+                DebugOptions::Checkpoint(label)
+            }
+            2 => return Err(ParsingError::missing_param(op, "debug.checkpoint.<label>")),
+            _ => return Err(ParsingError::extra_param(op)),
+        },
         _ => return Err(ParsingError::invalid_op(op)),
     };
 
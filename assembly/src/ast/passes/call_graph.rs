@@ -0,0 +1,173 @@
+use super::{Callee, LibraryPath, ModuleAst, ProcedureId, Visit};
+use crate::Module;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::ops::ControlFlow;
+
+// CALL GRAPH
+// ================================================================================================
+
+/// A graph of procedure invocations across a set of modules.
+///
+/// The nodes of the graph are the procedures defined in the modules, identified by their
+/// [ProcedureId]s, and there is an edge from a procedure to every procedure it invokes via `exec`,
+/// `call`, or `syscall` instructions. Invocations of procedures re-exported from a module are
+/// resolved to the original procedure.
+///
+/// Invocations of procedures by their MAST root (i.e., `call.0x...`) and dynamic invocations (i.e.,
+/// `dynexec` and `dyncall`) cannot be resolved without compiling the modules, and thus are not
+/// part of the graph.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CallGraph {
+    callees: BTreeMap<ProcedureId, BTreeSet<ProcedureId>>,
+    callers: BTreeMap<ProcedureId, BTreeSet<ProcedureId>>,
+}
+
+impl CallGraph {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the call graph of all procedures defined in the provided modules.
+    pub fn new(modules: &[Module]) -> Self {
+        let aliases = collect_aliases(modules);
+
+        let mut graph = Self::default();
+        for module in modules {
+            for procedure in module.ast.procs() {
+                let caller = ProcedureId::from_name(procedure.name.as_ref(), &module.path);
+                let mut collector = CalleeCollector::new(&module.ast, &module.path);
+                let _ = collector.visit_procedure(procedure);
+
+                graph.callees.entry(caller).or_default();
+                for callee in collector.callees {
+                    let callee = resolve_alias(&aliases, callee);
+                    graph.callees.entry(caller).or_default().insert(callee);
+                    graph.callers.entry(callee).or_default().insert(caller);
+                }
+            }
+        }
+        graph
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an iterator over all procedures which directly invoke the specified procedure.
+    pub fn callers_of(&self, proc_id: &ProcedureId) -> impl Iterator<Item = &ProcedureId> {
+        self.callers.get(proc_id).into_iter().flatten()
+    }
+
+    /// Returns an iterator over all procedures directly invoked by the specified procedure.
+    pub fn callees_of(&self, proc_id: &ProcedureId) -> impl Iterator<Item = &ProcedureId> {
+        self.callees.get(proc_id).into_iter().flatten()
+    }
+
+    /// Returns true if the specified procedure can invoke itself, either directly or through
+    /// other procedures.
+    pub fn is_recursive(&self, proc_id: &ProcedureId) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut pending = self.callees_of(proc_id).collect::<Vec<_>>();
+        while let Some(next) = pending.pop() {
+            if next == proc_id {
+                return true;
+            }
+            if visited.insert(next) {
+                pending.extend(self.callees_of(next));
+            }
+        }
+        false
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Maps the IDs of procedures re-exported from the provided modules to the IDs of the procedures
+/// they refer to.
+pub(super) fn collect_aliases(modules: &[Module]) -> BTreeMap<ProcedureId, ProcedureId> {
+    modules
+        .iter()
+        .flat_map(|module| {
+            module
+                .ast
+                .reexported_procs()
+                .iter()
+                .map(|reexport| (reexport.get_alias_id(&module.path), reexport.proc_id()))
+        })
+        .collect()
+}
+
+/// Returns the ID of the procedure referred to by the provided ID, following re-exports.
+pub(super) fn resolve_alias(
+    aliases: &BTreeMap<ProcedureId, ProcedureId>,
+    proc_id: ProcedureId,
+) -> ProcedureId {
+    let mut resolved = proc_id;
+    // the number of steps is bounded to guard against cyclic re-exports
+    for _ in 0..aliases.len() {
+        match aliases.get(&resolved) {
+            Some(target) => resolved = *target,
+            None => break,
+        }
+    }
+    resolved
+}
+
+/// Returns the ID of the procedure invoked by an `exec` or a `call` instruction in the provided
+/// module, or `None` if the callee is a MAST root or an undefined local procedure.
+pub(super) fn resolve_callee(
+    module: &ModuleAst,
+    path: &LibraryPath,
+    callee: Callee,
+) -> Option<ProcedureId> {
+    match callee {
+        Callee::Local(index) => module
+            .procs()
+            .get(index as usize)
+            .map(|procedure| ProcedureId::from_name(procedure.name.as_ref(), path)),
+        Callee::Imported(proc_id) => Some(*proc_id),
+        Callee::MastRoot(_) => None,
+    }
+}
+
+/// A visitor which collects the IDs of all procedures invoked from a procedure.
+struct CalleeCollector<'a> {
+    module: &'a ModuleAst,
+    path: &'a LibraryPath,
+    callees: BTreeSet<ProcedureId>,
+}
+
+impl<'a> CalleeCollector<'a> {
+    fn new(module: &'a ModuleAst, path: &'a LibraryPath) -> Self {
+        Self {
+            module,
+            path,
+            callees: BTreeSet::new(),
+        }
+    }
+
+    fn add(&mut self, callee: Callee) {
+        if let Some(proc_id) = resolve_callee(self.module, self.path, callee) {
+            self.callees.insert(proc_id);
+        }
+    }
+}
+
+impl<'a> Visit for CalleeCollector<'a> {
+    fn visit_exec(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.add(callee);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_call(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.add(callee);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_syscall(&mut self, callee: &ProcedureId) -> ControlFlow<()> {
+        self.callees.insert(*callee);
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,102 @@
+use super::{visit, CodeBody, Felt, Instruction, ModuleAst, Node, VisitMut};
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+// CONSTANT FOLDING
+// ================================================================================================
+
+/// Folds arithmetic operations over constants in all procedures of the provided module, and
+/// returns the number of folded operations.
+///
+/// See [ConstantFolder] for the details of which instruction sequences are folded.
+pub fn fold_constants(module: &mut ModuleAst) -> usize {
+    let mut folder = ConstantFolder::default();
+    let _ = folder.visit_mut_module(module);
+    folder.folded()
+}
+
+/// A visitor which replaces sequences of the form `push.a push.b op`, where `op` is one of `add`,
+/// `sub`, or `mul`, with a single `push` of the result computed in the field.
+///
+/// Folding is applied repeatedly, so `push.1 push.2 add push.3 mul` is replaced with `push.9`.
+/// The folded `push` keeps the source location of the first instruction of the sequence.
+#[derive(Debug, Default)]
+pub struct ConstantFolder {
+    folded: usize,
+}
+
+impl ConstantFolder {
+    /// Returns the number of operations folded so far.
+    pub fn folded(&self) -> usize {
+        self.folded
+    }
+
+    /// Rebuilds the provided block with all foldable sequences of its nodes replaced.
+    fn fold_block(&mut self, block: &mut CodeBody) {
+        let (nodes, mut locations) = core::mem::take(block).into_parts();
+        let has_locations = !locations.is_empty();
+        // a location for the end of the block is present in addition to the node locations
+        let final_location = (locations.len() > nodes.len()).then(|| locations.pop()).flatten();
+
+        let mut folded_nodes = Vec::with_capacity(nodes.len());
+        let mut folded_locations = Vec::with_capacity(locations.len());
+        let mut locations = locations.into_iter();
+        for node in nodes {
+            let location = locations.next();
+            if let Some(result) = fold_with_previous(&folded_nodes, &node) {
+                folded_nodes.truncate(folded_nodes.len() - 2);
+                folded_locations.truncate(folded_locations.len().saturating_sub(1));
+                folded_nodes.push(Node::Instruction(Instruction::PushFelt(result)));
+                self.folded += 1;
+                continue;
+            }
+            folded_nodes.push(node);
+            folded_locations.extend(location);
+        }
+        folded_locations.extend(final_location);
+
+        let mut folded = CodeBody::new(folded_nodes);
+        if has_locations {
+            folded = folded.with_source_locations(folded_locations);
+        }
+        *block = folded;
+    }
+}
+
+impl VisitMut for ConstantFolder {
+    fn visit_mut_block(&mut self, block: &mut CodeBody) -> ControlFlow<()> {
+        visit::visit_mut_block(self, block)?;
+        self.fold_block(block);
+        ControlFlow::Continue(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the value pushed onto the stack by the provided node if it is a push of a single
+/// constant.
+fn pushed_constant(node: &Node) -> Option<Felt> {
+    match node {
+        Node::Instruction(Instruction::PushU8(value)) => Some(Felt::from(*value)),
+        Node::Instruction(Instruction::PushU16(value)) => Some(Felt::from(*value)),
+        Node::Instruction(Instruction::PushU32(value)) => Some(Felt::from(*value)),
+        Node::Instruction(Instruction::PushFelt(value)) => Some(*value),
+        _ => None,
+    }
+}
+
+/// Returns the result of applying the operation in `node` to the two constants pushed by the last
+/// two of the `previous` nodes, or `None` if the sequence cannot be folded.
+fn fold_with_previous(previous: &[Node], node: &Node) -> Option<Felt> {
+    let [.., a, b] = previous else {
+        return None;
+    };
+    let (a, b) = (pushed_constant(a)?, pushed_constant(b)?);
+    match node {
+        Node::Instruction(Instruction::Add) => Some(a + b),
+        Node::Instruction(Instruction::Sub) => Some(a - b),
+        Node::Instruction(Instruction::Mul) => Some(a * b),
+        _ => None,
+    }
+}
@@ -0,0 +1,284 @@
+use super::{visit, Instruction, ModuleAst, Node, SourceLocation, Visit};
+use crate::assembler::InstructionCostCalculator;
+use alloc::collections::BTreeMap;
+use core::ops::ControlFlow;
+
+// COST REPORT
+// ================================================================================================
+
+/// Returns an estimate of the number of VM cycles it takes to execute all procedures of the
+/// provided module, broken down by instruction category.
+///
+/// See [CostEstimator] for the details of how the instructions are counted.
+pub fn program_cost_report(module: &ModuleAst) -> CostReport {
+    let mut estimator = CostEstimator::default();
+    let _ = estimator.visit_module(module);
+    estimator.into_report()
+}
+
+/// Returns the category of the provided instruction.
+///
+/// The categories follow the grouping of instructions in the Miden assembly documentation.
+pub fn instruction_category(instruction: &Instruction) -> InstructionCategory {
+    use Instruction::*;
+
+    match instruction {
+        Assert
+        | AssertWithError(_)
+        | Assertz
+        | AssertzWithError(_)
+        | AssertEq
+        | AssertEqWithError(_)
+        | AssertEqw
+        | AssertEqwWithError(_)
+        | Add
+        | AddImm(_)
+        | Sub
+        | SubImm(_)
+        | Mul
+        | MulImm(_)
+        | Div
+        | DivImm(_)
+        | Neg
+        | Inv
+        | Incr
+        | Pow2
+        | Exp
+        | ExpImm(_)
+        | ExpBitLength(_)
+        | ILog2
+        | Not
+        | And
+        | Or
+        | Xor
+        | Eq
+        | EqImm(_)
+        | Neq
+        | NeqImm(_)
+        | Eqw
+        | Lt
+        | Lte
+        | Gt
+        | Gte
+        | IsOdd => InstructionCategory::Field,
+
+        Ext2Add | Ext2Sub | Ext2Mul | Ext2Div | Ext2Neg | Ext2Inv => InstructionCategory::Ext2,
+
+        U32Test
+        | U32TestW
+        | U32Assert
+        | U32AssertWithError(_)
+        | U32Assert2
+        | U32Assert2WithError(_)
+        | U32AssertW
+        | U32AssertWWithError(_)
+        | U32Split
+        | U32Cast
+        | U32WrappingAdd
+        | U32WrappingAddImm(_)
+        | U32OverflowingAdd
+        | U32OverflowingAddImm(_)
+        | U32OverflowingAdd3
+        | U32WrappingAdd3
+        | U32WrappingSub
+        | U32WrappingSubImm(_)
+        | U32OverflowingSub
+        | U32OverflowingSubImm(_)
+        | U32WrappingMul
+        | U32WrappingMulImm(_)
+        | U32OverflowingMul
+        | U32OverflowingMulImm(_)
+        | U32OverflowingMadd
+        | U32WrappingMadd
+        | U32Div
+        | U32DivImm(_)
+        | U32Mod
+        | U32ModImm(_)
+        | U32DivMod
+        | U32DivModImm(_)
+        | U32And
+        | U32Or
+        | U32Xor
+        | U32Not
+        | U32Shr
+        | U32ShrImm(_)
+        | U32Shl
+        | U32ShlImm(_)
+        | U32Rotr
+        | U32RotrImm(_)
+        | U32Rotl
+        | U32RotlImm(_)
+        | U32Popcnt
+        | U32Clz
+        | U32Ctz
+        | U32Clo
+        | U32Cto
+        | U32Lt
+        | U32Lte
+        | U32Gt
+        | U32Gte
+        | U32Min
+        | U32Max => InstructionCategory::U32,
+
+        Drop | DropW | PadW | Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7 | Dup8
+        | Dup9 | Dup10 | Dup11 | Dup12 | Dup13 | Dup14 | Dup15 | DupW0 | DupW1 | DupW2 | DupW3
+        | Swap1 | Swap2 | Swap3 | Swap4 | Swap5 | Swap6 | Swap7 | Swap8 | Swap9 | Swap10
+        | Swap11 | Swap12 | Swap13 | Swap14 | Swap15 | SwapW1 | SwapW2 | SwapW3 | SwapDw
+        | MovUp2 | MovUp3 | MovUp4 | MovUp5 | MovUp6 | MovUp7 | MovUp8 | MovUp9 | MovUp10
+        | MovUp11 | MovUp12 | MovUp13 | MovUp14 | MovUp15 | MovUpW2 | MovUpW3 | MovDn2 | MovDn3
+        | MovDn4 | MovDn5 | MovDn6 | MovDn7 | MovDn8 | MovDn9 | MovDn10 | MovDn11 | MovDn12
+        | MovDn13 | MovDn14 | MovDn15 | MovDnW2 | MovDnW3 | CSwap | CSwapW | CDrop | CDropW => {
+            InstructionCategory::Stack
+        }
+
+        PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) | PushWord(_) | PushU8List(_)
+        | PushU16List(_) | PushU32List(_) | PushFeltList(_) | Locaddr(_) | Sdepth | Caller
+        | Clk | MemLoad | MemLoadImm(_) | MemLoadW | MemLoadWImm(_) | LocLoad(_) | LocLoadW(_)
+        | MemStore | MemStoreImm(_) | LocStore(_) | MemStoreW | MemStoreWImm(_) | LocStoreW(_)
+        | MemStream | AdvPipe | AdvPush(_) | AdvLoadW | AdvInject(_) | OverflowDepth => {
+            InstructionCategory::Io
+        }
+
+        Hash | HPerm | HMerge | MTreeGet | MTreeSet | MTreeMerge | MTreeVerify
+        | MTreeAssertRoot | FriExt2Fold4 | RCombBase => InstructionCategory::Crypto,
+
+        ExecLocal(_) | ExecImported(_) | CallLocal(_) | CallMastRoot(_) | CallImported(_)
+        | SysCall(_) | DynExec | DynCall | ProcRefLocal(_) | ProcRefImported(_) => {
+            InstructionCategory::Invocation
+        }
+
+        Breakpoint | Debug(_) | Emit(_) | Trace(_) => InstructionCategory::Decorator,
+    }
+}
+
+/// A category of Miden assembly instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum InstructionCategory {
+    /// Field operations, e.g., `add` and `assert`.
+    Field,
+    /// Operations in the quadratic extension field, e.g., `ext2mul`.
+    Ext2,
+    /// Operations on u32 values, e.g., `u32wrapping_add`.
+    U32,
+    /// Stack manipulation, e.g., `swap` and `movup`.
+    Stack,
+    /// Input / output operations, e.g., `push`, `mem_load`, and `adv_push`.
+    Io,
+    /// Cryptographic operations, e.g., `hperm` and `mtree_get`.
+    Crypto,
+    /// Procedure invocations and references, e.g., `exec` and `procref`.
+    Invocation,
+    /// Debug and event decorators, e.g., `debug` and `emit`.
+    Decorator,
+}
+
+/// The number of executed instructions and the estimated number of VM cycles they take, in total
+/// and per instruction category.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CostReport {
+    categories: BTreeMap<InstructionCategory, CategoryCost>,
+}
+
+impl CostReport {
+    /// Returns the estimated number of VM cycles taken by all instructions.
+    pub fn total_cycles(&self) -> u64 {
+        self.categories
+            .values()
+            .fold(0, |total, cost| total.saturating_add(cost.cycles))
+    }
+
+    /// Returns the number of executed instructions.
+    pub fn num_instructions(&self) -> u64 {
+        self.categories.values().fold(0, |total, cost| total.saturating_add(cost.count))
+    }
+
+    /// Returns the cost of the instructions in the specified category.
+    pub fn category(&self, category: InstructionCategory) -> CategoryCost {
+        self.categories.get(&category).copied().unwrap_or_default()
+    }
+
+    /// Returns an iterator over the costs of all categories with at least one instruction,
+    /// ordered by category.
+    pub fn categories(&self) -> impl Iterator<Item = (InstructionCategory, CategoryCost)> + '_ {
+        self.categories.iter().map(|(category, cost)| (*category, *cost))
+    }
+}
+
+/// The number of executed instructions in a single category, and the estimated number of VM
+/// cycles they take.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CategoryCost {
+    pub count: u64,
+    pub cycles: u64,
+}
+
+// COST ESTIMATOR
+// ================================================================================================
+
+/// A visitor which counts the instructions of the visited code, and estimates the number of VM
+/// cycles they take via [instruction_cost()](crate::instruction_cost).
+///
+/// The estimate is made as follows:
+/// - The bodies of `repeat.n` blocks are counted `n` times, and nested `repeat` blocks multiply
+///   each other.
+/// - The bodies of `while.true` loops are counted once, as the number of iterations is not known
+///   statically.
+/// - Both branches of `if.true` blocks are counted.
+/// - Invoked procedures are not expanded, and the invocation instructions take no cycles.
+///
+/// The cycles taken by the control flow blocks themselves are not included. All counts saturate
+/// at `u64::MAX`.
+///
+/// Unlike [program_cost_report()], the estimator can be used with any AST element, e.g., by
+/// visiting a [ProgramAst](super::ProgramAst) or a single procedure.
+#[derive(Debug)]
+pub struct CostEstimator {
+    /// The number of times the code currently being visited is repeated.
+    multiplier: u64,
+    report: CostReport,
+    costs: InstructionCostCalculator,
+}
+
+impl CostEstimator {
+    /// Returns the report of the instructions counted by this estimator.
+    pub fn into_report(self) -> CostReport {
+        self.report
+    }
+}
+
+impl Default for CostEstimator {
+    fn default() -> Self {
+        Self {
+            multiplier: 1,
+            report: CostReport::default(),
+            costs: InstructionCostCalculator::default(),
+        }
+    }
+}
+
+impl Visit for CostEstimator {
+    fn visit_node(&mut self, node: &Node, location: Option<&SourceLocation>) -> ControlFlow<()> {
+        match node {
+            Node::Repeat { times, body } => {
+                let multiplier = self.multiplier;
+                self.multiplier = multiplier.saturating_mul(*times as u64);
+                self.visit_block(body)?;
+                self.multiplier = multiplier;
+                ControlFlow::Continue(())
+            }
+            _ => visit::visit_node(self, node, location),
+        }
+    }
+
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        _location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        let cycles = (self.costs.cost(inst) as u64).saturating_mul(self.multiplier);
+        let cost = self.report.categories.entry(instruction_category(inst)).or_default();
+        cost.count = cost.count.saturating_add(self.multiplier);
+        cost.cycles = cost.cycles.saturating_add(cycles);
+        ControlFlow::Continue(())
+    }
+}
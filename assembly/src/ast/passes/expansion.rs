@@ -0,0 +1,72 @@
+use super::{visit, Instruction, ModuleAst, Node, SourceLocation, Visit};
+use core::ops::ControlFlow;
+
+// REPEAT EXPANSION
+// ================================================================================================
+
+/// Returns the number of instructions in the provided module after all `repeat` blocks have been
+/// unrolled.
+///
+/// Since `repeat.n` blocks are unrolled by the assembler, their bodies contribute `n` times to the
+/// size of the compiled code, and nested `repeat` blocks multiply each other. The estimate counts
+/// every instruction once per unrolled copy, including invocation instructions (e.g., `exec`),
+/// whose targets are not expanded. The result saturates at `usize::MAX`.
+pub fn estimate_expanded_size(module: &ModuleAst) -> usize {
+    let mut estimator = ExpandedSizeEstimator::default();
+    let _ = estimator.visit_module(module);
+    estimator.size()
+}
+
+// EXPANDED SIZE ESTIMATOR
+// ================================================================================================
+
+/// A visitor which counts the instructions of the visited code with all `repeat` blocks unrolled.
+///
+/// Unlike [estimate_expanded_size()], the estimator can be used with any AST element, e.g., by
+/// visiting a [ProgramAst](super::ProgramAst) or a single procedure.
+#[derive(Debug)]
+pub struct ExpandedSizeEstimator {
+    /// The number of times the code currently being visited is repeated.
+    multiplier: usize,
+    size: usize,
+}
+
+impl ExpandedSizeEstimator {
+    /// Returns the number of instructions counted so far.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+}
+
+impl Default for ExpandedSizeEstimator {
+    fn default() -> Self {
+        Self {
+            multiplier: 1,
+            size: 0,
+        }
+    }
+}
+
+impl Visit for ExpandedSizeEstimator {
+    fn visit_node(&mut self, node: &Node, location: Option<&SourceLocation>) -> ControlFlow<()> {
+        match node {
+            Node::Repeat { times, body } => {
+                let multiplier = self.multiplier;
+                self.multiplier = multiplier.saturating_mul(*times as usize);
+                self.visit_block(body)?;
+                self.multiplier = multiplier;
+                ControlFlow::Continue(())
+            }
+            _ => visit::visit_node(self, node, location),
+        }
+    }
+
+    fn visit_inst(
+        &mut self,
+        _inst: &Instruction,
+        _location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.size = self.size.saturating_add(self.multiplier);
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,99 @@
+use super::{
+    call_graph::{collect_aliases, resolve_alias, resolve_callee},
+    Callee, LibraryPath, ModuleAst, ProcedureId, Visit, VisitInst,
+};
+use crate::Module;
+use alloc::collections::BTreeMap;
+use core::ops::ControlFlow;
+
+// PROCEDURE FAN-IN
+// ================================================================================================
+
+/// Returns the number of call sites which invoke each procedure via `exec` or `call` instructions
+/// across the provided modules.
+///
+/// Every procedure defined in the modules is part of the report, including procedures which are
+/// never invoked. Invocations via imports are counted against the procedure which defines the
+/// invoked code, following re-exports, and invocations of procedures not defined in the provided
+/// modules are reported under the IDs they were imported with.
+///
+/// A call site is counted once regardless of how many times it is executed, e.g., an `exec` in
+/// the body of a `repeat` block counts as a single call site. Invocations by MAST root are not
+/// counted.
+pub fn procedure_fan_in(modules: &[Module]) -> BTreeMap<ProcedureId, usize> {
+    let aliases = collect_aliases(modules);
+
+    let mut fan_in =
+        modules
+            .iter()
+            .flat_map(|module| {
+                module.ast.procs().iter().map(|procedure| {
+                    (ProcedureId::from_name(procedure.name.as_ref(), &module.path), 0)
+                })
+            })
+            .collect::<BTreeMap<_, _>>();
+
+    for module in modules {
+        let mut counter = FanInCounter::new(&module.ast, &module.path);
+        let _ = counter.visit_module(&module.ast);
+        for (callee, count) in counter.into_counts() {
+            *fan_in.entry(resolve_alias(&aliases, callee)).or_default() += count;
+        }
+    }
+    fan_in
+}
+
+// FAN-IN COUNTER
+// ================================================================================================
+
+/// A visitor which counts the `exec` and `call` instructions invoking each procedure from a
+/// module.
+///
+/// Invoked procedures are identified by the IDs they are referenced with in the module, i.e.,
+/// re-exported procedures are not resolved.
+#[derive(Debug)]
+pub struct FanInCounter<'a> {
+    module: &'a ModuleAst,
+    path: &'a LibraryPath,
+    counts: BTreeMap<ProcedureId, usize>,
+}
+
+impl<'a> FanInCounter<'a> {
+    /// Returns a new counter for the provided module located at the specified path.
+    pub fn new(module: &'a ModuleAst, path: &'a LibraryPath) -> Self {
+        Self {
+            module,
+            path,
+            counts: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the number of call sites counted so far for each invoked procedure.
+    pub fn counts(&self) -> &BTreeMap<ProcedureId, usize> {
+        &self.counts
+    }
+
+    /// Consumes this counter and returns the number of call sites of each invoked procedure.
+    pub fn into_counts(self) -> BTreeMap<ProcedureId, usize> {
+        self.counts
+    }
+
+    fn add(&mut self, callee: Callee) -> ControlFlow<()> {
+        if let Some(proc_id) = resolve_callee(self.module, self.path, callee) {
+            *self.counts.entry(proc_id).or_default() += 1;
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl<'a> Visit for FanInCounter<'a> {
+    fn visit_exec(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.add(callee)
+    }
+
+    fn visit_call(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.add(callee)
+    }
+}
+
+impl VisitInst for FanInCounter<'_> {}
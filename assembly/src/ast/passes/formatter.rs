@@ -0,0 +1,276 @@
+use super::{
+    block_stack_effect, stack_effect, AstFormatterContext, CodeBody, FormattableInstruction,
+    Instruction, LibraryPath, ModuleAst, Node, ProcedureAst, ProcedureId, SourceLocation, Visit,
+    INDENT_STRING,
+};
+use crate::tokens::Token;
+use alloc::{collections::BTreeMap, string::String};
+use core::{fmt::Write, ops::ControlFlow};
+
+// MODULE FORMATTING
+// ================================================================================================
+
+/// Renders the provided module as normalized Miden assembly source code.
+///
+/// The rendered code puts each instruction on a separate line, and indents the bodies of
+/// procedures and of `if.true`, `while.true`, and `repeat` blocks by one level per block.
+///
+/// # Panics
+/// Panics if the module invokes imported procedures but import info is not associated with it.
+pub fn format_module(module: &ModuleAst) -> String {
+    let mut formatter = Formatter::new(module);
+    let _ = formatter.visit_module(module);
+    formatter.into_string()
+}
+
+// FORMATTER
+// ================================================================================================
+
+/// A visitor which renders the visited module as Miden assembly source code.
+///
+/// Imports are rendered in the order in which they are stored in the module's import info, i.e.,
+/// sorted by module name, together with their aliases, if any. Instructions are rendered via their
+/// [Display](core::fmt::Display) implementation, which reproduces all immediate values exactly.
+pub struct Formatter<'a> {
+    /// Context used to resolve the names of invoked procedures.
+    context: AstFormatterContext<'a>,
+    /// Names under which the imported modules are referenced in the module, keyed by their paths.
+    module_names: BTreeMap<&'a LibraryPath, &'a str>,
+    indent_level: usize,
+    output: String,
+    /// Whether the rendered lines are annotated with stack effects.
+    annotate: bool,
+    /// Depth of the stack relative to the entry into the current procedure, if known.
+    depth: Option<i32>,
+}
+
+impl<'a> Formatter<'a> {
+    /// Returns a new formatter for the provided module.
+    pub fn new(module: &'a ModuleAst) -> Self {
+        let import_info = module.import_info();
+        let context = AstFormatterContext::new(module.procs(), import_info.invoked_procs());
+        let module_names = import_info
+            .imported_modules()
+            .iter()
+            .map(|(name, path)| (path, name.as_str()))
+            .collect();
+        Self {
+            context,
+            module_names,
+            indent_level: 0,
+            output: String::new(),
+            annotate: false,
+            depth: Some(0),
+        }
+    }
+
+    /// Enables annotating the rendered lines with stack effects.
+    ///
+    /// With this option, every instruction line ends with a comment of the form
+    /// `# effect: +1, depth: -2`, where `effect` is the change of the stack depth caused by the
+    /// instruction, and `depth` is the depth of the stack after the instruction relative to the
+    /// depth on entry into the procedure. The lines opening `if.true` and `while.true` blocks are
+    /// annotated with the popped condition, and the lines closing blocks are annotated with the
+    /// depth after the block. Values which cannot be determined statically (see
+    /// [block_stack_effect()]) are rendered as `?`.
+    pub fn with_stack_effects(mut self) -> Self {
+        self.annotate = true;
+        self
+    }
+
+    /// Returns the source code rendered so far.
+    pub fn into_string(self) -> String {
+        self.output
+    }
+
+    /// Writes the provided line at the current indentation level.
+    fn write_line(&mut self, line: &str) {
+        self.indent();
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+
+    /// Applies the provided stack effect to the tracked depth, and writes the provided line
+    /// annotated with the effect and the resulting depth if annotations are enabled.
+    fn write_with_effect(&mut self, line: &str, effect: Option<i32>) {
+        self.depth = self.depth.zip(effect).map(|(depth, effect)| depth + effect);
+        if self.annotate {
+            let annotation =
+                format!("{line} # effect: {}, depth: {}", signed(effect), signed(self.depth));
+            self.write_line(&annotation);
+        } else {
+            self.write_line(line);
+        }
+    }
+
+    /// Writes the provided line closing a block, annotated with the depth after the block if
+    /// annotations are enabled.
+    fn write_block_end(&mut self, line: &str) {
+        if self.annotate {
+            self.write_line(&format!("{line} # depth: {}", signed(self.depth)));
+        } else {
+            self.write_line(line);
+        }
+    }
+
+    /// Returns the invocation target of the imported procedure with the specified ID, i.e., the
+    /// name of the procedure qualified by the name of its module.
+    fn imported_proc(&self, proc_id: &ProcedureId) -> String {
+        let (name, path) = self.context.imported_proc(proc_id);
+        let module_name = self.module_names.get(path).copied().unwrap_or(path.last());
+        format!("{module_name}::{name}")
+    }
+
+    fn indent(&mut self) {
+        for _ in 0..self.indent_level {
+            self.output.push_str(INDENT_STRING);
+        }
+    }
+
+    /// Writes the body of a block one indentation level deeper than the current one.
+    fn write_block(&mut self, body: &CodeBody) -> ControlFlow<()> {
+        self.indent_level += 1;
+        let result = self.visit_block(body);
+        self.indent_level -= 1;
+        result
+    }
+}
+
+impl<'a> Visit for Formatter<'a> {
+    fn visit_module(&mut self, module: &ModuleAst) -> ControlFlow<()> {
+        if let Some(docs) = module.docs() {
+            docs.lines().for_each(|line| self.write_line(&format!("#! {line}")));
+            self.write_line("");
+        }
+
+        let imports = module.import_info().imported_modules();
+        for (name, path) in imports.iter() {
+            if name == path.last() {
+                self.write_line(&format!("use.{path}"));
+            } else {
+                self.write_line(&format!("use.{path}{}{name}", Token::ALIAS_DELIM));
+            }
+        }
+        if !imports.is_empty() {
+            self.write_line("");
+        }
+
+        for reexport in module.reexported_procs() {
+            if let Some(docs) = reexport.docs() {
+                docs.lines().for_each(|line| self.write_line(&format!("#! {line}")));
+            }
+            let module_path = reexport.module_path();
+            let module_name = self
+                .module_names
+                .get(module_path)
+                .copied()
+                .unwrap_or_else(|| module_path.last());
+            let mut line =
+                format!("export.{module_name}{}{}", LibraryPath::PATH_DELIM, reexport.ref_name());
+            if reexport.ref_name() != reexport.name() {
+                line.push_str(&format!("{}{}", Token::ALIAS_DELIM, reexport.name()));
+            }
+            self.write_line(&line);
+            self.write_line("");
+        }
+
+        for procedure in module.procs() {
+            self.visit_procedure(procedure)?;
+            self.write_line("");
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn visit_procedure(&mut self, procedure: &ProcedureAst) -> ControlFlow<()> {
+        if let Some(docs) = procedure.docs.as_ref() {
+            docs.lines().for_each(|line| self.write_line(&format!("#! {line}")));
+        }
+        let kind = if procedure.is_export { "export" } else { "proc" };
+        self.write_line(&format!("{kind}.{}.{}", procedure.name, procedure.num_locals));
+        self.depth = Some(0);
+        self.write_block(&procedure.body)?;
+        self.write_block_end("end");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_node(&mut self, node: &Node, location: Option<&SourceLocation>) -> ControlFlow<()> {
+        match node {
+            Node::Instruction(inst) => return self.visit_inst(inst, location),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                self.write_with_effect("if.true", Some(-1));
+                let depth = self.depth;
+                self.write_block(true_case)?;
+                let true_depth = self.depth;
+                self.depth = depth;
+                if !false_case.nodes().is_empty() {
+                    self.write_line("else");
+                    self.write_block(false_case)?;
+                }
+                // the depth after the block is known only if both branches agree on it
+                self.depth = self.depth.filter(|_| self.depth == true_depth);
+            }
+            Node::Repeat { times, body } => {
+                self.write_line(&format!("repeat.{times}"));
+                let depth = self.depth;
+                let effect = block_stack_effect(body);
+                // the depth within the body is the same for every iteration only if the body
+                // leaves the depth unchanged
+                self.depth = self.depth.filter(|_| effect == Some(0));
+                self.write_block(body)?;
+                let total_effect = effect
+                    .zip(i32::try_from(*times).ok())
+                    .and_then(|(effect, times)| effect.checked_mul(times));
+                self.depth = depth.zip(total_effect).map(|(depth, effect)| depth + effect);
+            }
+            Node::While { body } => {
+                self.write_with_effect("while.true", Some(-1));
+                // the body must push the next condition for the depth within the body to be the
+                // same for every iteration
+                let balanced = block_stack_effect(body) == Some(1);
+                self.depth = self.depth.filter(|_| balanced);
+                self.write_block(body)?;
+                self.depth = self.depth.map(|depth| depth - 1);
+            }
+        }
+        self.write_block_end("end");
+        ControlFlow::Continue(())
+    }
+
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        _location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        let line = match inst {
+            Instruction::ExecImported(proc_id) => format!("exec.{}", self.imported_proc(proc_id)),
+            Instruction::CallImported(proc_id) => format!("call.{}", self.imported_proc(proc_id)),
+            Instruction::ProcRefImported(proc_id) => {
+                format!("procref.{}", self.imported_proc(proc_id))
+            }
+            _ => {
+                // the formatting context is not indented, and the instruction is terminated by a
+                // newline by its own formatter, which is stripped here
+                let mut line = String::new();
+                let _ = write!(line, "{}", FormattableInstruction::new(inst, &self.context));
+                line.truncate(line.trim_end().len());
+                line
+            }
+        };
+        self.write_with_effect(&line, stack_effect(inst));
+        ControlFlow::Continue(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Renders the provided value with its sign, or `?` if the value is not known.
+fn signed(value: Option<i32>) -> String {
+    match value {
+        Some(value) => format!("{value:+}"),
+        None => "?".into(),
+    }
+}
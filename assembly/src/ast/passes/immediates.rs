@@ -0,0 +1,120 @@
+use super::{
+    visit, AdviceInjectorNode, Instruction, SourceLocation, Visit, VisitInst, MAX_STACK_WORD_OFFSET,
+};
+use crate::{
+    errors::{ImmediateError, ValidationError},
+    Module, ADVICE_READ_LIMIT, MAX_EXP_BITS, MAX_U32_ROTATE_VALUE, MAX_U32_SHIFT_VALUE,
+};
+use alloc::vec::Vec;
+use core::ops::{ControlFlow, RangeInclusive};
+
+// IMMEDIATE VALIDATION
+// ================================================================================================
+
+/// Checks that all immediate values of the instructions in the provided module are within the
+/// ranges accepted by these instructions.
+///
+/// The parser enforces these ranges when processing source code, but ASTs can also be built
+/// directly or deserialized from bytes, and in these cases an invalid immediate would only be
+/// detected during compilation.
+///
+/// # Errors
+/// Returns an error for every out-of-range immediate found in the module.
+pub fn validate_immediates(module: &Module) -> Result<(), Vec<ImmediateError>> {
+    let mut validator = ImmediateRangeValidator::default();
+    let _ = validator.visit_module(&module.ast);
+    validator.into_result()
+}
+
+// IMMEDIATE RANGE VALIDATOR
+// ================================================================================================
+
+/// A visitor which collects all immediates falling outside of the range of values accepted by
+/// their instruction.
+///
+/// Immediates are stored in the narrowest integer type able to hold them, but many instructions
+/// accept only a subset of that type (e.g., `u32shr` accepts shifts in the range [0, 31] even
+/// though its immediate is stored as a `u8`).
+#[derive(Debug, Default)]
+pub struct ImmediateRangeValidator {
+    /// The instruction currently being visited, together with its accepted range of immediates.
+    current: Option<(Instruction, RangeInclusive<u64>)>,
+    location: Option<SourceLocation>,
+    errors: Vec<ImmediateError>,
+}
+
+impl ImmediateRangeValidator {
+    /// Returns the errors collected by this validator, if any.
+    pub fn into_result(self) -> Result<(), Vec<ImmediateError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Returns the range of immediate values accepted by the provided instruction, or `None` if
+    /// the instruction accepts any value of the immediate's type.
+    fn accepted_range(inst: &Instruction) -> Option<RangeInclusive<u64>> {
+        use Instruction::*;
+
+        let max = match inst {
+            U32ShrImm(_) | U32ShlImm(_) => MAX_U32_SHIFT_VALUE,
+            U32RotrImm(_) | U32RotlImm(_) => MAX_U32_ROTATE_VALUE,
+            ExpBitLength(_) => MAX_EXP_BITS,
+            AdvPush(_) => return Some(1..=ADVICE_READ_LIMIT as u64),
+            AdvInject(
+                AdviceInjectorNode::PushMapValImm { .. }
+                | AdviceInjectorNode::PushMapValNImm { .. }
+                | AdviceInjectorNode::PushMapValLenImm { .. },
+            ) => MAX_STACK_WORD_OFFSET,
+            _ => return None,
+        };
+        Some(0..=max as u64)
+    }
+
+    fn check(&mut self, value: u64) {
+        if let Some((inst, range)) = self.current.as_ref() {
+            if !range.contains(&value) {
+                self.errors.push(ValidationError::immediate_out_of_range(
+                    inst,
+                    value,
+                    *range.start(),
+                    *range.end(),
+                    self.location,
+                ));
+            }
+        }
+    }
+}
+
+impl Visit for ImmediateRangeValidator {
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.current = Self::accepted_range(inst).map(|range| (inst.clone(), range));
+        self.location = location.copied();
+        visit::visit_inst(self, inst, location)?;
+        self.current = None;
+        ControlFlow::Continue(())
+    }
+
+    fn visit_immediate_u8(&mut self, imm: &u8) -> ControlFlow<()> {
+        self.check(*imm as u64);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_immediate_u16(&mut self, imm: &u16) -> ControlFlow<()> {
+        self.check(*imm as u64);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_immediate_u32(&mut self, imm: &u32) -> ControlFlow<()> {
+        self.check(*imm as u64);
+        ControlFlow::Continue(())
+    }
+}
+
+impl VisitInst for ImmediateRangeValidator {}
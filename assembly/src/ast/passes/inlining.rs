@@ -0,0 +1,173 @@
+use super::{
+    visit, Callee, CodeBody, Instruction, ModuleAst, Node, ProcedureAst, ProcedureName, Visit,
+    VisitMut,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
+use core::ops::ControlFlow;
+
+// PROCEDURE INLINING
+// ================================================================================================
+
+/// Inlines the specified local procedures of the provided module at all of their `exec` sites,
+/// and returns the number of inlined invocations.
+///
+/// See [Inliner] for the details of which procedures can be inlined.
+pub fn inline_procedures(module: &mut ModuleAst, names: &BTreeSet<ProcedureName>) -> usize {
+    let mut inliner = Inliner::new(module.procs(), names);
+    let _ = inliner.visit_mut_module(module);
+    inliner.inlined()
+}
+
+/// A visitor which replaces `exec` instructions invoking local procedures with the bodies of
+/// these procedures.
+///
+/// Only the procedures with the specified names are inlined, and out of these, procedures which
+/// use local memory or which can invoke themselves, either directly or through other local
+/// procedures, are left intact. Invocations of procedures via `call` are never inlined, as these
+/// start a new execution context.
+///
+/// The inlined procedures remain in the module, as other modules may still invoke them.
+#[derive(Debug)]
+pub struct Inliner {
+    bodies: BTreeMap<u16, CodeBody>,
+    inlined: usize,
+}
+
+impl Inliner {
+    /// Returns a new inliner for a module or a program with the provided local procedures which
+    /// inlines the procedures with the specified names.
+    pub fn new(procs: &[ProcedureAst], names: &BTreeSet<ProcedureName>) -> Self {
+        let callees = procs.iter().map(local_callees).collect::<Vec<_>>();
+        let bodies = procs
+            .iter()
+            .enumerate()
+            .filter(|(index, procedure)| {
+                names.contains(&procedure.name)
+                    && procedure.num_locals == 0
+                    && !is_recursive(&callees, *index as u16)
+            })
+            .map(|(index, procedure)| (index as u16, procedure.body.clone()))
+            .collect();
+
+        Self { bodies, inlined: 0 }
+    }
+
+    /// Returns the number of invocations inlined so far.
+    pub fn inlined(&self) -> usize {
+        self.inlined
+    }
+
+    /// Rebuilds the provided block with all inlinable invocations replaced by the bodies of
+    /// the invoked procedures.
+    fn inline_block(&mut self, block: &mut CodeBody) {
+        if !block.nodes().iter().any(|node| self.inlinable_body(node).is_some()) {
+            return;
+        }
+
+        let (nodes, mut locations) = core::mem::take(block).into_parts();
+        let has_locations = !locations.is_empty();
+        // a location for the end of the block is present in addition to the node locations
+        let final_location = (locations.len() > nodes.len()).then(|| locations.pop()).flatten();
+
+        let mut inlined_nodes = Vec::with_capacity(nodes.len());
+        let mut inlined_locations = Vec::with_capacity(locations.len());
+        let mut locations = locations.into_iter();
+        for node in nodes {
+            let location = locations.next();
+            let Some(body) = self.inlinable_body(&node) else {
+                inlined_nodes.push(node);
+                inlined_locations.extend(location);
+                continue;
+            };
+
+            // the inlined body may itself invoke inlinable procedures; this terminates as none of
+            // the inlinable procedures can invoke themselves
+            let mut body = body.clone();
+            let _ = self.visit_mut_block(&mut body);
+            let (body_nodes, mut body_locations) = body.into_parts();
+            body_locations.truncate(body_nodes.len());
+            inlined_nodes.extend(body_nodes);
+            inlined_locations.extend(body_locations);
+            self.inlined += 1;
+        }
+        inlined_locations.extend(final_location);
+
+        let mut inlined = CodeBody::new(inlined_nodes);
+        if has_locations {
+            inlined = inlined.with_source_locations(inlined_locations);
+        }
+        *block = inlined;
+    }
+
+    /// Returns the body of the procedure invoked by the provided node if the node is an `exec`
+    /// of an inlinable procedure.
+    fn inlinable_body(&self, node: &Node) -> Option<&CodeBody> {
+        match node {
+            Node::Instruction(Instruction::ExecLocal(index)) => self.bodies.get(index),
+            _ => None,
+        }
+    }
+}
+
+impl VisitMut for Inliner {
+    fn visit_mut_block(&mut self, block: &mut CodeBody) -> ControlFlow<()> {
+        visit::visit_mut_block(self, block)?;
+        self.inline_block(block);
+        ControlFlow::Continue(())
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the indexes of all local procedures invoked from the provided procedure.
+fn local_callees(procedure: &ProcedureAst) -> BTreeSet<u16> {
+    let mut collector = LocalCalleeCollector::default();
+    let _ = collector.visit_procedure(procedure);
+    collector.callees
+}
+
+/// Returns true if the procedure at the specified index can invoke itself, either directly or
+/// through other local procedures.
+fn is_recursive(callees: &[BTreeSet<u16>], index: u16) -> bool {
+    let mut visited = BTreeSet::new();
+    let mut pending = callees[index as usize].iter().copied().collect::<Vec<_>>();
+    while let Some(next) = pending.pop() {
+        if next == index {
+            return true;
+        }
+        if visited.insert(next) {
+            pending.extend(callees.get(next as usize).into_iter().flatten());
+        }
+    }
+    false
+}
+
+/// A visitor which collects the indexes of all local procedures invoked from a procedure.
+#[derive(Default)]
+struct LocalCalleeCollector {
+    callees: BTreeSet<u16>,
+}
+
+impl LocalCalleeCollector {
+    fn add(&mut self, callee: Callee) {
+        if let Callee::Local(index) = callee {
+            self.callees.insert(index);
+        }
+    }
+}
+
+impl Visit for LocalCalleeCollector {
+    fn visit_exec(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.add(callee);
+        ControlFlow::Continue(())
+    }
+
+    fn visit_call(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.add(callee);
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,110 @@
+use super::{
+    visit, Callee, Instruction, ModuleAst, ProcedureAst, ProcedureId, ProcedureName,
+    SourceLocation, Visit,
+};
+use crate::errors::ValidationError;
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+// KERNEL PROCEDURE VALIDATION
+// ================================================================================================
+
+/// Checks that no procedure in the provided kernel module uses `call`, `syscall`, `dyncall`,
+/// `dynexec`, or `procref` instructions.
+///
+/// Kernel procedures are executed via `syscall`, and the VM does not support starting a new
+/// execution context from within a syscall. Code executed dynamically cannot be checked
+/// statically, and thus `dynexec` is rejected as well; `procref` is rejected because a procedure
+/// reference is only useful for a subsequent `dyncall` or `dynexec`.
+///
+/// This validation reports all offending procedures at once. Local procedures are checked as well
+/// as exported ones, since they can be executed by the exported procedures.
+///
+/// # Errors
+/// Returns an error for every procedure in the kernel which uses any of the above instructions.
+pub fn validate_kernel_procedures(kernel: &ModuleAst) -> Result<(), Vec<ValidationError>> {
+    let mut validator = KernelProcedureValidator::default();
+    let _ = validator.visit_module(kernel);
+    validator.into_result()
+}
+
+// KERNEL PROCEDURE VALIDATOR
+// ================================================================================================
+
+/// A visitor which collects all procedures containing instructions which cannot be executed
+/// within a kernel procedure.
+///
+/// Only the first offending instruction of each procedure is reported.
+#[derive(Debug, Default)]
+pub struct KernelProcedureValidator {
+    /// The name of the procedure currently being visited, if any.
+    procedure: Option<ProcedureName>,
+    /// The instruction currently being visited, if any.
+    current: Option<(Instruction, Option<SourceLocation>)>,
+    /// True if an error has already been reported for the current procedure.
+    reported: bool,
+    errors: Vec<ValidationError>,
+}
+
+impl KernelProcedureValidator {
+    /// Returns the errors collected by this validator, if any.
+    pub fn into_result(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    /// Records an error for the instruction currently being visited, unless the current
+    /// procedure has already been reported.
+    fn report(&mut self) {
+        if self.reported {
+            return;
+        }
+        if let (Some(proc_name), Some((inst, location))) = (&self.procedure, &self.current) {
+            self.errors
+                .push(ValidationError::invalid_kernel_instruction(proc_name, inst, *location));
+            self.reported = true;
+        }
+    }
+}
+
+impl Visit for KernelProcedureValidator {
+    fn visit_procedure(&mut self, procedure: &ProcedureAst) -> ControlFlow<()> {
+        self.procedure = Some(procedure.name.clone());
+        self.reported = false;
+        visit::visit_procedure(self, procedure)?;
+        self.procedure = None;
+        ControlFlow::Continue(())
+    }
+
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.current = Some((inst.clone(), location.copied()));
+        if matches!(inst, Instruction::DynExec | Instruction::DynCall) {
+            self.report();
+        }
+        visit::visit_inst(self, inst, location)?;
+        self.current = None;
+        ControlFlow::Continue(())
+    }
+
+    fn visit_call(&mut self, _callee: Callee) -> ControlFlow<()> {
+        self.report();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_syscall(&mut self, _callee: &ProcedureId) -> ControlFlow<()> {
+        self.report();
+        ControlFlow::Continue(())
+    }
+
+    fn visit_procref(&mut self, _callee: Callee) -> ControlFlow<()> {
+        self.report();
+        ControlFlow::Continue(())
+    }
+}
@@ -0,0 +1,95 @@
+use super::{visit, Instruction, ModuleAst, ProcedureAst, ProgramAst, SourceLocation, Visit};
+use crate::errors::ValidationError;
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+// LOCAL ACCESS VALIDATION
+// ================================================================================================
+
+/// Checks that all `locaddr`, `loc_load`, `loc_loadw`, `loc_store`, and `loc_storew` instructions
+/// in the provided module access locals declared by their procedure.
+///
+/// The parser accepts any local index which fits into a `u16`, and thus an access beyond the
+/// number of locals declared by a procedure (e.g., `loc_load.4` in `proc.foo.4`) would otherwise
+/// only be detected during compilation.
+///
+/// # Errors
+/// Returns an error for every out-of-bounds local access found in the module.
+pub fn validate_local_accesses(module: &ModuleAst) -> Result<(), Vec<ValidationError>> {
+    let mut validator = LocalAccessValidator::default();
+    let _ = validator.visit_module(module);
+    validator.into_result()
+}
+
+// LOCAL ACCESS VALIDATOR
+// ================================================================================================
+
+/// A visitor which collects all accesses to procedure locals with indexes beyond the number of
+/// locals declared by the enclosing procedure.
+///
+/// The body of a program declares no locals, and thus every local access in it is reported.
+#[derive(Debug, Default)]
+pub struct LocalAccessValidator {
+    num_locals: u16,
+    /// The local access instruction currently being visited, if any.
+    current: Option<Instruction>,
+    location: Option<SourceLocation>,
+    errors: Vec<ValidationError>,
+}
+
+impl LocalAccessValidator {
+    /// Returns the errors collected by this validator, if any.
+    pub fn into_result(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    fn is_local_access(inst: &Instruction) -> bool {
+        use Instruction::*;
+        matches!(inst, Locaddr(_) | LocLoad(_) | LocLoadW(_) | LocStore(_) | LocStoreW(_))
+    }
+}
+
+impl Visit for LocalAccessValidator {
+    fn visit_program(&mut self, program: &ProgramAst) -> ControlFlow<()> {
+        for procedure in program.procedures() {
+            self.visit_procedure(procedure)?;
+        }
+        self.num_locals = 0;
+        self.visit_block(program.body())
+    }
+
+    fn visit_procedure(&mut self, procedure: &ProcedureAst) -> ControlFlow<()> {
+        self.num_locals = procedure.num_locals;
+        visit::visit_procedure(self, procedure)
+    }
+
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.current = Self::is_local_access(inst).then(|| inst.clone());
+        self.location = location.copied();
+        visit::visit_inst(self, inst, location)?;
+        self.current = None;
+        ControlFlow::Continue(())
+    }
+
+    fn visit_immediate_u16(&mut self, imm: &u16) -> ControlFlow<()> {
+        if let Some(inst) = self.current.as_ref() {
+            if *imm >= self.num_locals {
+                self.errors.push(ValidationError::local_out_of_bounds(
+                    inst,
+                    *imm,
+                    self.num_locals,
+                    self.location,
+                ));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
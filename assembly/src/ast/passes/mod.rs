@@ -0,0 +1,68 @@
+//! Analysis and transformation passes over Miden assembly ASTs built on top of the
+//! [Visit](super::Visit) and [VisitMut](super::VisitMut) frameworks.
+use super::{
+    nodes::FormattableInstruction, visit, AdviceInjectorNode, AstFormatterContext, Callee,
+    CodeBody, Felt, Instruction, LibraryPath, ModuleAst, Node, ProcedureAst, ProcedureId,
+    ProcedureName, ProgramAst, SourceLocation, Visit, VisitInst, VisitMut, INDENT_STRING,
+    MAX_STACK_WORD_OFFSET,
+};
+
+mod immediates;
+pub use immediates::{validate_immediates, ImmediateRangeValidator};
+
+mod syscalls;
+pub use syscalls::{validate_syscalls, SyscallValidator};
+
+mod kernel;
+pub use kernel::{validate_kernel_procedures, KernelProcedureValidator};
+
+mod resolution;
+pub use resolution::{validate_local_references, LocalReferenceValidator};
+
+mod locals;
+pub use locals::{validate_local_accesses, LocalAccessValidator};
+
+mod call_graph;
+pub use call_graph::CallGraph;
+
+mod fan_in;
+pub use fan_in::{procedure_fan_in, FanInCounter};
+
+mod expansion;
+pub use expansion::{estimate_expanded_size, ExpandedSizeEstimator};
+
+mod cost;
+pub use cost::{
+    instruction_category, program_cost_report, CategoryCost, CostEstimator, CostReport,
+    InstructionCategory,
+};
+
+mod nesting;
+pub use nesting::{max_nesting_depth, NestingDepthCounter};
+
+mod search;
+pub use search::{find_first, FindFirstInstruction};
+
+mod constant_folding;
+pub use constant_folding::{fold_constants, ConstantFolder};
+
+mod inlining;
+pub use inlining::{inline_procedures, Inliner};
+
+mod formatter;
+pub use formatter::{format_module, Formatter};
+
+mod redundancy;
+pub use redundancy::{find_redundant_ops, RedundantOpDetector};
+
+mod stack_depth;
+pub use stack_depth::{
+    block_stack_effect, check_call_depths, estimate_depth_on_return, stack_effect, stack_inputs,
+    CallDepthChecker, StackDepthEstimator,
+};
+
+mod underflow;
+pub use underflow::{find_stack_underflows, StackUnderflowDetector};
+
+#[cfg(test)]
+mod tests;
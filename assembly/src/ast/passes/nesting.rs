@@ -0,0 +1,55 @@
+use super::{visit, ModuleAst, Node, SourceLocation, Visit};
+use core::ops::ControlFlow;
+
+// NESTING DEPTH
+// ================================================================================================
+
+/// Returns the deepest nesting of control flow blocks (i.e., `if.true`, `while.true`, and
+/// `repeat` blocks) across all procedures of the provided module.
+///
+/// Instructions outside of any control flow block have the nesting depth of 0, and every block
+/// increases the depth of its body by one; both branches of an `if.true` block are at the same
+/// depth. Thus, a `while.true` loop containing an `if.true` block nested within another `if.true`
+/// block has the nesting depth of 3.
+pub fn max_nesting_depth(module: &ModuleAst) -> usize {
+    let mut counter = NestingDepthCounter::default();
+    let _ = counter.visit_module(module);
+    counter.max_depth()
+}
+
+// NESTING DEPTH COUNTER
+// ================================================================================================
+
+/// A visitor which tracks the nesting of control flow blocks in the visited code, and records
+/// the deepest nesting encountered.
+///
+/// Unlike [max_nesting_depth()], the counter can be used with any AST element, e.g., by visiting
+/// a [ProgramAst](super::ProgramAst) or a single procedure.
+#[derive(Debug, Default)]
+pub struct NestingDepthCounter {
+    /// The nesting depth of the code currently being visited.
+    depth: usize,
+    max_depth: usize,
+}
+
+impl NestingDepthCounter {
+    /// Returns the deepest nesting of control flow blocks visited so far.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+}
+
+impl Visit for NestingDepthCounter {
+    fn visit_node(&mut self, node: &Node, location: Option<&SourceLocation>) -> ControlFlow<()> {
+        match node {
+            Node::Instruction(_) => visit::visit_node(self, node, location),
+            Node::IfElse { .. } | Node::While { .. } | Node::Repeat { .. } => {
+                self.depth += 1;
+                self.max_depth = self.max_depth.max(self.depth);
+                visit::visit_node(self, node, location)?;
+                self.depth -= 1;
+                ControlFlow::Continue(())
+            }
+        }
+    }
+}
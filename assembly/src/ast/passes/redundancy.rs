@@ -0,0 +1,121 @@
+use super::{visit, CodeBody, Instruction, ModuleAst, Node, Visit};
+use crate::errors::RedundantOpWarning;
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+// REDUNDANT OPERATION DETECTION
+// ================================================================================================
+
+/// Returns a warning for every pair of adjacent instructions in the provided module whose effects
+/// on the stack cancel each other out.
+///
+/// See [RedundantOpDetector] for the details of which pairs are reported.
+pub fn find_redundant_ops(module: &ModuleAst) -> Vec<RedundantOpWarning> {
+    let mut detector = RedundantOpDetector::default();
+    let _ = detector.visit_module(module);
+    detector.into_warnings()
+}
+
+/// A visitor which detects pairs of adjacent instructions within a block that leave the stack
+/// unchanged, and thus can be removed to save cycles.
+///
+/// The following pairs are detected:
+/// - `swap` followed by `swap`.
+/// - `dup` (i.e., `dup.0`) followed by `drop`.
+/// - `movup.n` followed by `movdn.n`, and `movdn.n` followed by `movup.n`; the same applies to
+///   `movupw.n` and `movdnw.n`.
+///
+/// Only instructions which are adjacent within a single block are considered, and every
+/// instruction is reported as a part of at most one pair, so `swap swap swap` results in a single
+/// warning.
+#[derive(Debug, Default)]
+pub struct RedundantOpDetector {
+    warnings: Vec<RedundantOpWarning>,
+}
+
+impl RedundantOpDetector {
+    /// Returns the warnings collected by this detector.
+    pub fn into_warnings(self) -> Vec<RedundantOpWarning> {
+        self.warnings
+    }
+
+    /// Records a warning for every canceling pair of adjacent instructions in the provided block.
+    fn check_block(&mut self, block: &CodeBody) {
+        let nodes = block.nodes();
+        let locations = block.source_locations();
+        let mut idx = 0;
+        while idx + 1 < nodes.len() {
+            let (Node::Instruction(first), Node::Instruction(second)) =
+                (&nodes[idx], &nodes[idx + 1])
+            else {
+                idx += 1;
+                continue;
+            };
+            if !cancel_out(first, second) {
+                idx += 1;
+                continue;
+            }
+
+            self.warnings.push(RedundantOpWarning::new(
+                first.clone(),
+                second.clone(),
+                locations.get(idx).copied(),
+                locations.get(idx + 1).copied(),
+            ));
+            idx += 2;
+        }
+    }
+}
+
+impl Visit for RedundantOpDetector {
+    fn visit_block(&mut self, block: &CodeBody) -> ControlFlow<()> {
+        self.check_block(block);
+        visit::visit_block(self, block)
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns true if executing `first` immediately followed by `second` leaves the stack unchanged.
+fn cancel_out(first: &Instruction, second: &Instruction) -> bool {
+    use Instruction::*;
+
+    matches!(
+        (first, second),
+        (Swap1, Swap1)
+            | (Dup0, Drop)
+            | (MovUp2, MovDn2)
+            | (MovDn2, MovUp2)
+            | (MovUp3, MovDn3)
+            | (MovDn3, MovUp3)
+            | (MovUp4, MovDn4)
+            | (MovDn4, MovUp4)
+            | (MovUp5, MovDn5)
+            | (MovDn5, MovUp5)
+            | (MovUp6, MovDn6)
+            | (MovDn6, MovUp6)
+            | (MovUp7, MovDn7)
+            | (MovDn7, MovUp7)
+            | (MovUp8, MovDn8)
+            | (MovDn8, MovUp8)
+            | (MovUp9, MovDn9)
+            | (MovDn9, MovUp9)
+            | (MovUp10, MovDn10)
+            | (MovDn10, MovUp10)
+            | (MovUp11, MovDn11)
+            | (MovDn11, MovUp11)
+            | (MovUp12, MovDn12)
+            | (MovDn12, MovUp12)
+            | (MovUp13, MovDn13)
+            | (MovDn13, MovUp13)
+            | (MovUp14, MovDn14)
+            | (MovDn14, MovUp14)
+            | (MovUp15, MovDn15)
+            | (MovDn15, MovUp15)
+            | (MovUpW2, MovDnW2)
+            | (MovDnW2, MovUpW2)
+            | (MovUpW3, MovDnW3)
+            | (MovDnW3, MovUpW3)
+    )
+}
@@ -0,0 +1,91 @@
+use super::{visit, Callee, Instruction, ModuleAst, SourceLocation, Visit, VisitInst};
+use crate::errors::ValidationError;
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+
+// LOCAL REFERENCE VALIDATION
+// ================================================================================================
+
+/// Checks that all local procedures referenced by `exec`, `call`, and `procref` instructions in
+/// the provided module are defined in the module.
+///
+/// Local procedures are referenced by their index in the module. The parser resolves procedure
+/// names to valid indexes, but ASTs can also be built directly or deserialized from bytes, and in
+/// these cases a dangling reference would only be detected during compilation.
+///
+/// # Errors
+/// Returns an error for every reference to a local procedure not defined in the module.
+pub fn validate_local_references(module: &ModuleAst) -> Result<(), Vec<ValidationError>> {
+    let mut validator = LocalReferenceValidator::new(module.procs().len());
+    let _ = validator.visit_module(module);
+    validator.into_result()
+}
+
+// LOCAL REFERENCE VALIDATOR
+// ================================================================================================
+
+/// A visitor which collects all references to local procedures with indexes beyond the number of
+/// procedures defined locally.
+#[derive(Debug)]
+pub struct LocalReferenceValidator {
+    num_procs: usize,
+    /// The instruction currently being visited, if any.
+    current: Option<(Instruction, Option<SourceLocation>)>,
+    errors: Vec<ValidationError>,
+}
+
+impl LocalReferenceValidator {
+    /// Returns a new validator for a module or a program with the specified number of local
+    /// procedures.
+    pub fn new(num_procs: usize) -> Self {
+        Self {
+            num_procs,
+            current: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Returns the errors collected by this validator, if any.
+    pub fn into_result(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+
+    fn check(&mut self, callee: Callee) -> ControlFlow<()> {
+        if let (Callee::Local(index), Some((inst, location))) = (callee, self.current.as_ref()) {
+            if index as usize >= self.num_procs {
+                self.errors
+                    .push(ValidationError::unresolved_local_procedure(inst, index, *location));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl Visit for LocalReferenceValidator {
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.current = Some((inst.clone(), location.copied()));
+        visit::visit_inst(self, inst, location)
+    }
+
+    fn visit_exec(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.check(callee)
+    }
+
+    fn visit_call(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.check(callee)
+    }
+
+    fn visit_procref(&mut self, callee: Callee) -> ControlFlow<()> {
+        self.check(callee)
+    }
+}
+
+impl VisitInst for LocalReferenceValidator {}
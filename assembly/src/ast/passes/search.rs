@@ -0,0 +1,69 @@
+use super::{Instruction, ModuleAst, SourceLocation, Visit};
+use core::ops::ControlFlow;
+
+// INSTRUCTION SEARCH
+// ================================================================================================
+
+/// Returns the first instruction in the provided module for which `predicate` returns true,
+/// together with its source location, if known.
+///
+/// Procedures are searched in the order of their indexes, and the body of every procedure in the
+/// order in which its instructions appear in the source code (i.e., the `true` branch of an
+/// `if.true` block is searched before its `false` branch). The search stops at the first match.
+pub fn find_first<P>(
+    module: &ModuleAst,
+    predicate: P,
+) -> Option<(Instruction, Option<SourceLocation>)>
+where
+    P: FnMut(&Instruction) -> bool,
+{
+    FindFirstInstruction::new(predicate).find(module)
+}
+
+// FIND FIRST INSTRUCTION
+// ================================================================================================
+
+/// A visitor which stops the traversal at the first instruction matching a predicate.
+///
+/// The matching instruction and its source location are carried by the [ControlFlow::Break]
+/// returned from the visitor's hooks, and are propagated up through the default traversal
+/// without visiting any of the remaining nodes.
+pub struct FindFirstInstruction<P> {
+    predicate: P,
+}
+
+impl<P> FindFirstInstruction<P>
+where
+    P: FnMut(&Instruction) -> bool,
+{
+    /// Returns a new visitor matching instructions against the provided predicate.
+    pub fn new(predicate: P) -> Self {
+        Self { predicate }
+    }
+
+    /// Returns the first instruction in the provided module matching the predicate of this
+    /// visitor, together with its source location, if known.
+    pub fn find(&mut self, module: &ModuleAst) -> Option<(Instruction, Option<SourceLocation>)> {
+        match self.visit_module(module) {
+            ControlFlow::Break(found) => Some(found),
+            ControlFlow::Continue(()) => None,
+        }
+    }
+}
+
+impl<P> Visit<(Instruction, Option<SourceLocation>)> for FindFirstInstruction<P>
+where
+    P: FnMut(&Instruction) -> bool,
+{
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<(Instruction, Option<SourceLocation>)> {
+        if (self.predicate)(inst) {
+            ControlFlow::Break((inst.clone(), location.copied()))
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
@@ -0,0 +1,374 @@
+use super::{Callee, CodeBody, Instruction, ModuleAst, Node, ProcedureAst, SourceLocation, Visit};
+use crate::errors::StackDepthWarning;
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::ops::ControlFlow;
+use vm_core::stack::STACK_TOP_SIZE;
+
+// CALL DEPTH CHECK
+// ================================================================================================
+
+/// Checks that all local procedures invoked via `call` instructions in the provided module return
+/// with a stack depth of 16.
+///
+/// When a procedure is invoked via `call`, it starts executing in a new context with a stack of
+/// depth 16, and the VM fails when the depth of the stack is not 16 upon return. The depth on
+/// return is estimated via [estimate_depth_on_return()], and thus only procedures with a statically
+/// known stack effect are checked.
+///
+/// Returns a warning for every such procedure which provably returns with a deeper stack.
+pub fn check_call_depths(module: &ModuleAst) -> Vec<StackDepthWarning> {
+    let mut checker = CallDepthChecker::new(module.procs());
+    let _ = checker.visit_module(module);
+    checker.into_warnings()
+}
+
+/// Returns the depth of the stack after executing the provided procedure in a new execution
+/// context, or `None` if the depth cannot be determined statically.
+///
+/// The stack starts with the depth of 16, and the depth never goes below 16 as the VM pads the
+/// stack with zeros. The depth can be determined only for procedures consisting solely of
+/// instructions with a known stack effect (see [stack_effect()]); procedures which contain control
+/// flow blocks or invoke other procedures are skipped.
+pub fn estimate_depth_on_return(procedure: &ProcedureAst) -> Option<usize> {
+    let mut estimator = StackDepthEstimator::default();
+    match estimator.visit_procedure(procedure) {
+        ControlFlow::Continue(()) => Some(estimator.depth()),
+        ControlFlow::Break(()) => None,
+    }
+}
+
+/// Returns the net change of the stack depth caused by executing the provided instruction, or
+/// `None` if it cannot be determined statically (e.g., for procedure invocations).
+pub fn stack_effect(instruction: &Instruction) -> Option<i32> {
+    use Instruction::*;
+
+    let effect = match instruction {
+        // ----- field operations -----------------------------------------------------------------
+        Assert | AssertWithError(_) | Assertz | AssertzWithError(_) => -1,
+        AssertEq | AssertEqWithError(_) => -2,
+        AssertEqw | AssertEqwWithError(_) => -8,
+        Add | Sub | Mul | Div | Exp | ExpBitLength(_) | And | Or | Xor | Eq | Neq | Lt | Lte
+        | Gt | Gte => -1,
+        AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | ExpImm(_) | EqImm(_) | NeqImm(_) => 0,
+        Neg | Inv | Incr | Pow2 | ILog2 | Not | IsOdd => 0,
+        Eqw => 1,
+
+        // ----- ext2 operations ------------------------------------------------------------------
+        Ext2Add | Ext2Sub | Ext2Mul | Ext2Div => -2,
+        Ext2Neg | Ext2Inv => 0,
+
+        // ----- u32 operations -------------------------------------------------------------------
+        U32Test | U32TestW | U32Split => 1,
+        U32Assert
+        | U32AssertWithError(_)
+        | U32Assert2
+        | U32Assert2WithError(_)
+        | U32AssertW
+        | U32AssertWWithError(_)
+        | U32Cast => 0,
+        U32WrappingAdd | U32WrappingSub | U32WrappingMul | U32Div | U32Mod | U32And | U32Or
+        | U32Xor | U32Shr | U32Shl | U32Rotr | U32Rotl | U32Lt | U32Lte | U32Gt | U32Gte
+        | U32Min | U32Max => -1,
+        U32WrappingAddImm(_) | U32WrappingSubImm(_) | U32WrappingMulImm(_) | U32DivImm(_)
+        | U32ModImm(_) | U32ShrImm(_) | U32ShlImm(_) | U32RotrImm(_) | U32RotlImm(_) => 0,
+        U32OverflowingAdd | U32OverflowingSub | U32OverflowingMul | U32DivMod => 0,
+        U32OverflowingAddImm(_)
+        | U32OverflowingSubImm(_)
+        | U32OverflowingMulImm(_)
+        | U32DivModImm(_) => 1,
+        U32OverflowingAdd3 | U32OverflowingMadd => -1,
+        U32WrappingAdd3 | U32WrappingMadd => -2,
+        U32Not | U32Popcnt | U32Clz | U32Ctz | U32Clo | U32Cto => 0,
+
+        // ----- stack manipulation ---------------------------------------------------------------
+        Drop | CSwap | CSwapW => -1,
+        DropW => -4,
+        CDrop => -2,
+        CDropW => -5,
+        PadW | DupW0 | DupW1 | DupW2 | DupW3 => 4,
+        Dup0 | Dup1 | Dup2 | Dup3 | Dup4 | Dup5 | Dup6 | Dup7 | Dup8 | Dup9 | Dup10 | Dup11
+        | Dup12 | Dup13 | Dup14 | Dup15 => 1,
+        Swap1 | Swap2 | Swap3 | Swap4 | Swap5 | Swap6 | Swap7 | Swap8 | Swap9 | Swap10 | Swap11
+        | Swap12 | Swap13 | Swap14 | Swap15 | SwapW1 | SwapW2 | SwapW3 | SwapDw => 0,
+        MovUp2 | MovUp3 | MovUp4 | MovUp5 | MovUp6 | MovUp7 | MovUp8 | MovUp9 | MovUp10
+        | MovUp11 | MovUp12 | MovUp13 | MovUp14 | MovUp15 | MovUpW2 | MovUpW3 => 0,
+        MovDn2 | MovDn3 | MovDn4 | MovDn5 | MovDn6 | MovDn7 | MovDn8 | MovDn9 | MovDn10
+        | MovDn11 | MovDn12 | MovDn13 | MovDn14 | MovDn15 | MovDnW2 | MovDnW3 => 0,
+
+        // ----- input / output operations --------------------------------------------------------
+        PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) => 1,
+        PushWord(_) => 4,
+        PushU8List(values) => values.len() as i32,
+        PushU16List(values) => values.len() as i32,
+        PushU32List(values) => values.len() as i32,
+        PushFeltList(values) => values.len() as i32,
+        Locaddr(_) | Sdepth | OverflowDepth | Clk => 1,
+        Caller => 0,
+        MemLoad | LocLoadW(_) | MemLoadWImm(_) => 0,
+        MemLoadImm(_) | LocLoad(_) => 1,
+        MemLoadW => -1,
+        MemStore => -2,
+        MemStoreImm(_) | LocStore(_) | MemStoreW => -1,
+        MemStoreWImm(_) | LocStoreW(_) => 0,
+        MemStream | AdvPipe | AdvLoadW => 0,
+        AdvPush(n) => *n as i32,
+        AdvInject(_) => 0,
+
+        // ----- cryptographic operations ---------------------------------------------------------
+        Hash | HPerm | MTreeVerify => 0,
+        HMerge | MTreeMerge | MTreeAssertRoot => -4,
+        MTreeGet => 2,
+        MTreeSet => -2,
+        FriExt2Fold4 | RCombBase => 0,
+
+        // ----- exec / call ----------------------------------------------------------------------
+        ExecLocal(_) | ExecImported(_) | CallLocal(_) | CallMastRoot(_) | CallImported(_)
+        | SysCall(_) | DynExec | DynCall => return None,
+        ProcRefLocal(_) | ProcRefImported(_) => 4,
+
+        // ----- decorators -----------------------------------------------------------------------
+        Breakpoint | Debug(_) | Emit(_) | Trace(_) => 0,
+    };
+
+    Some(effect)
+}
+
+/// Returns the number of items at the top of the stack which are read by the provided instruction,
+/// or `None` if it cannot be determined statically (e.g., for procedure invocations).
+///
+/// Items which are overwritten in place (e.g., by `mem_loadw`) are counted as read, and advice
+/// injectors and other decorators are treated as reading no items, since they do not affect the
+/// stack.
+pub fn stack_inputs(instruction: &Instruction) -> Option<usize> {
+    use Instruction::*;
+
+    let inputs = match instruction {
+        // ----- field operations -----------------------------------------------------------------
+        Assert | AssertWithError(_) | Assertz | AssertzWithError(_) => 1,
+        AssertEq | AssertEqWithError(_) => 2,
+        AssertEqw | AssertEqwWithError(_) => 8,
+        Add | Sub | Mul | Div | Exp | ExpBitLength(_) | And | Or | Xor | Eq | Neq | Lt | Lte
+        | Gt | Gte => 2,
+        AddImm(_) | SubImm(_) | MulImm(_) | DivImm(_) | ExpImm(_) | EqImm(_) | NeqImm(_) => 1,
+        Neg | Inv | Incr | Pow2 | ILog2 | Not | IsOdd => 1,
+        Eqw => 8,
+
+        // ----- ext2 operations ------------------------------------------------------------------
+        Ext2Add | Ext2Sub | Ext2Mul | Ext2Div => 4,
+        Ext2Neg | Ext2Inv => 2,
+
+        // ----- u32 operations -------------------------------------------------------------------
+        U32Test | U32Assert | U32AssertWithError(_) | U32Split | U32Cast => 1,
+        U32Assert2 | U32Assert2WithError(_) => 2,
+        U32TestW | U32AssertW | U32AssertWWithError(_) => 4,
+        U32WrappingAdd | U32WrappingSub | U32WrappingMul | U32Div | U32Mod | U32And | U32Or
+        | U32Xor | U32Shr | U32Shl | U32Rotr | U32Rotl | U32Lt | U32Lte | U32Gt | U32Gte
+        | U32Min | U32Max => 2,
+        U32WrappingAddImm(_) | U32WrappingSubImm(_) | U32WrappingMulImm(_) | U32DivImm(_)
+        | U32ModImm(_) | U32ShrImm(_) | U32ShlImm(_) | U32RotrImm(_) | U32RotlImm(_) => 1,
+        U32OverflowingAdd | U32OverflowingSub | U32OverflowingMul | U32DivMod => 2,
+        U32OverflowingAddImm(_)
+        | U32OverflowingSubImm(_)
+        | U32OverflowingMulImm(_)
+        | U32DivModImm(_) => 1,
+        U32OverflowingAdd3 | U32OverflowingMadd | U32WrappingAdd3 | U32WrappingMadd => 3,
+        U32Not | U32Popcnt | U32Clz | U32Ctz | U32Clo | U32Cto => 1,
+
+        // ----- stack manipulation ---------------------------------------------------------------
+        Drop => 1,
+        DropW => 4,
+        CSwap | CDrop => 3,
+        CSwapW | CDropW => 9,
+        PadW => 0,
+        Dup0 => 1,
+        Dup1 | Swap1 => 2,
+        Dup2 | Swap2 | MovUp2 | MovDn2 => 3,
+        Dup3 | Swap3 | MovUp3 | MovDn3 => 4,
+        Dup4 | Swap4 | MovUp4 | MovDn4 => 5,
+        Dup5 | Swap5 | MovUp5 | MovDn5 => 6,
+        Dup6 | Swap6 | MovUp6 | MovDn6 => 7,
+        Dup7 | Swap7 | MovUp7 | MovDn7 => 8,
+        Dup8 | Swap8 | MovUp8 | MovDn8 => 9,
+        Dup9 | Swap9 | MovUp9 | MovDn9 => 10,
+        Dup10 | Swap10 | MovUp10 | MovDn10 => 11,
+        Dup11 | Swap11 | MovUp11 | MovDn11 => 12,
+        Dup12 | Swap12 | MovUp12 | MovDn12 => 13,
+        Dup13 | Swap13 | MovUp13 | MovDn13 => 14,
+        Dup14 | Swap14 | MovUp14 | MovDn14 => 15,
+        Dup15 | Swap15 | MovUp15 | MovDn15 => 16,
+        DupW0 => 4,
+        DupW1 | SwapW1 => 8,
+        DupW2 | SwapW2 | MovUpW2 | MovDnW2 => 12,
+        DupW3 | SwapW3 | MovUpW3 | MovDnW3 | SwapDw => 16,
+
+        // ----- input / output operations --------------------------------------------------------
+        PushU8(_) | PushU16(_) | PushU32(_) | PushFelt(_) | PushWord(_) | PushU8List(_)
+        | PushU16List(_) | PushU32List(_) | PushFeltList(_) => 0,
+        Locaddr(_) | Sdepth | OverflowDepth | Clk => 0,
+        Caller => 4,
+        MemLoad => 1,
+        MemLoadImm(_) | LocLoad(_) => 0,
+        MemLoadW => 5,
+        MemLoadWImm(_) | LocLoadW(_) => 4,
+        MemStore => 2,
+        MemStoreImm(_) | LocStore(_) => 1,
+        MemStoreW => 5,
+        MemStoreWImm(_) | LocStoreW(_) => 4,
+        MemStream | AdvPipe => 13,
+        AdvPush(_) => 0,
+        AdvLoadW => 4,
+        AdvInject(_) => 0,
+
+        // ----- cryptographic operations ---------------------------------------------------------
+        Hash | MTreeAssertRoot => 4,
+        HMerge | MTreeMerge => 8,
+        HPerm => 12,
+        MTreeGet => 6,
+        MTreeSet | MTreeVerify => 10,
+        FriExt2Fold4 | RCombBase => 16,
+
+        // ----- exec / call ----------------------------------------------------------------------
+        ExecLocal(_) | ExecImported(_) | CallLocal(_) | CallMastRoot(_) | CallImported(_)
+        | SysCall(_) | DynExec | DynCall => return None,
+        ProcRefLocal(_) | ProcRefImported(_) => 0,
+
+        // ----- decorators -----------------------------------------------------------------------
+        Breakpoint | Debug(_) | Emit(_) | Trace(_) => 0,
+    };
+
+    Some(inputs)
+}
+
+/// Returns the net change of the stack depth caused by executing the provided block, or `None` if
+/// it cannot be determined statically.
+///
+/// Unlike [estimate_depth_on_return()], this also handles control flow blocks:
+/// - The effect of an `if.true` block is known if both of its branches have the same effect.
+/// - The effect of a `while.true` loop is known only if its body leaves one more item on the stack
+///   than it consumes (i.e., the next condition), as the number of iterations is not known
+///   statically.
+/// - The effect of a `repeat` block is the effect of its body multiplied by the number of
+///   iterations.
+///
+/// The depth is not padded to 16 items, and thus the returned effect may be more negative than
+/// the effect observed at runtime for blocks which consume the padding.
+pub fn block_stack_effect(body: &CodeBody) -> Option<i32> {
+    body.nodes()
+        .iter()
+        .try_fold(0_i32, |effect, node| Some(effect + node_stack_effect(node)?))
+}
+
+/// Returns the net change of the stack depth caused by executing the provided node, or `None` if
+/// it cannot be determined statically (see [block_stack_effect()]).
+fn node_stack_effect(node: &Node) -> Option<i32> {
+    match node {
+        Node::Instruction(inst) => stack_effect(inst),
+        Node::IfElse {
+            true_case,
+            false_case,
+        } => {
+            let true_effect = block_stack_effect(true_case)?;
+            (true_effect == block_stack_effect(false_case)?).then_some(true_effect - 1)
+        }
+        Node::While { body } => (block_stack_effect(body)? == 1).then_some(-1),
+        Node::Repeat { times, body } => {
+            block_stack_effect(body)?.checked_mul(i32::try_from(*times).ok()?)
+        }
+    }
+}
+
+// STACK DEPTH ESTIMATOR
+// ================================================================================================
+
+/// A visitor which tracks the depth of the stack over the visited instructions, starting from a
+/// stack of depth 16.
+///
+/// The traversal is stopped as soon as a control flow block or an instruction without a statically
+/// known stack effect is reached.
+#[derive(Debug)]
+pub struct StackDepthEstimator {
+    depth: usize,
+}
+
+impl StackDepthEstimator {
+    /// Returns the depth of the stack after the instructions visited so far.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+}
+
+impl Default for StackDepthEstimator {
+    fn default() -> Self {
+        Self {
+            depth: STACK_TOP_SIZE,
+        }
+    }
+}
+
+impl Visit for StackDepthEstimator {
+    fn visit_node(&mut self, node: &Node, location: Option<&SourceLocation>) -> ControlFlow<()> {
+        match node {
+            Node::Instruction(inst) => self.visit_inst(inst, location),
+            _ => ControlFlow::Break(()),
+        }
+    }
+
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        _location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        match stack_effect(inst) {
+            Some(effect) => {
+                let depth = (self.depth as i64 + effect as i64).max(STACK_TOP_SIZE as i64);
+                self.depth = depth as usize;
+                ControlFlow::Continue(())
+            }
+            None => ControlFlow::Break(()),
+        }
+    }
+}
+
+// CALL DEPTH CHECKER
+// ================================================================================================
+
+/// A visitor which collects the local procedures invoked via `call` instructions, and checks
+/// their stack depth on return.
+#[derive(Debug)]
+pub struct CallDepthChecker<'a> {
+    procs: &'a [ProcedureAst],
+    called: BTreeSet<u16>,
+}
+
+impl<'a> CallDepthChecker<'a> {
+    /// Returns a new checker for a module or a program with the provided local procedures.
+    pub fn new(procs: &'a [ProcedureAst]) -> Self {
+        Self {
+            procs,
+            called: BTreeSet::new(),
+        }
+    }
+
+    /// Returns a warning for every visited procedure which is invoked via `call` and provably
+    /// returns with a stack deeper than 16.
+    pub fn into_warnings(self) -> Vec<StackDepthWarning> {
+        self.called
+            .iter()
+            .filter_map(|&index| self.procs.get(index as usize))
+            .filter_map(|procedure| {
+                let depth = estimate_depth_on_return(procedure)?;
+                (depth != STACK_TOP_SIZE)
+                    .then(|| StackDepthWarning::new(procedure.name.clone(), depth, procedure.start))
+            })
+            .collect()
+    }
+}
+
+impl<'a> Visit for CallDepthChecker<'a> {
+    fn visit_call(&mut self, callee: Callee) -> ControlFlow<()> {
+        if let Callee::Local(index) = callee {
+            self.called.insert(index);
+        }
+        ControlFlow::Continue(())
+    }
+}
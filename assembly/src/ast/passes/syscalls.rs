@@ -0,0 +1,95 @@
+use super::{
+    visit, Instruction, LibraryPath, ModuleAst, ProcedureId, ProgramAst, SourceLocation, Visit,
+    VisitInst,
+};
+use crate::errors::ValidationError;
+use alloc::{collections::BTreeSet, vec::Vec};
+use core::ops::ControlFlow;
+
+// SYSCALL VALIDATION
+// ================================================================================================
+
+/// Checks that the targets of all `syscall` instructions in the provided program are procedures
+/// exported from the provided kernel module.
+///
+/// Unresolved syscall targets are otherwise detected only when the program is compiled, and only
+/// one target at a time.
+///
+/// # Errors
+/// Returns an error for every `syscall` instruction in the program which invokes a procedure not
+/// exported from the kernel.
+pub fn validate_syscalls(
+    program: &ProgramAst,
+    kernel: &ModuleAst,
+) -> Result<(), Vec<ValidationError>> {
+    let mut validator = SyscallValidator::new(kernel);
+    let _ = validator.visit_program(program);
+    validator.into_result()
+}
+
+// SYSCALL VALIDATOR
+// ================================================================================================
+
+/// A visitor which collects all `syscall` instructions invoking procedures not exported from a
+/// kernel.
+#[derive(Debug)]
+pub struct SyscallValidator {
+    /// IDs of all procedures exported from the kernel.
+    exports: BTreeSet<ProcedureId>,
+    /// The instruction currently being visited, if any.
+    current: Option<(Instruction, Option<SourceLocation>)>,
+    errors: Vec<ValidationError>,
+}
+
+impl SyscallValidator {
+    /// Returns a new validator for syscalls into the provided kernel module.
+    pub fn new(kernel: &ModuleAst) -> Self {
+        // syscall targets are always resolved against the kernel path, regardless of the path
+        // the kernel module may have been loaded from
+        let kernel_path = LibraryPath::kernel_path();
+        let exports = kernel
+            .procs()
+            .iter()
+            .filter(|proc| proc.is_export)
+            .map(|proc| ProcedureId::from_name(proc.name.as_ref(), &kernel_path))
+            .collect();
+
+        Self {
+            exports,
+            current: None,
+            errors: Vec::new(),
+        }
+    }
+
+    /// Returns the errors collected by this validator, if any.
+    pub fn into_result(self) -> Result<(), Vec<ValidationError>> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
+
+impl Visit for SyscallValidator {
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.current = Some((inst.clone(), location.copied()));
+        visit::visit_inst(self, inst, location)
+    }
+
+    fn visit_syscall(&mut self, callee: &ProcedureId) -> ControlFlow<()> {
+        if let Some((inst, location)) = self.current.as_ref() {
+            if !self.exports.contains(callee) {
+                self.errors
+                    .push(ValidationError::unresolved_syscall_target(inst, callee, *location));
+            }
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+impl VisitInst for SyscallValidator {}
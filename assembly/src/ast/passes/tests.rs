@@ -0,0 +1,1079 @@
+use super::{
+    check_call_depths, estimate_depth_on_return, estimate_expanded_size, find_first,
+    find_redundant_ops, find_stack_underflows, fold_constants, format_module, inline_procedures,
+    max_nesting_depth, procedure_fan_in, program_cost_report, validate_immediates,
+    validate_kernel_procedures, validate_local_accesses, validate_local_references, CallGraph,
+    CostEstimator, ExpandedSizeEstimator, ImmediateRangeValidator, Instruction,
+    InstructionCategory, LibraryPath, LocalAccessValidator, LocalReferenceValidator, ModuleAst,
+    NestingDepthCounter, ProcedureId, ProgramAst, SourceLocation, StackUnderflowDetector, Visit,
+    VisitInst,
+};
+use crate::{
+    ast::{visit::run_all, AdviceInjectorNode, CodeBody, Node, ProcedureAst},
+    instruction_cost, Assembler, AssemblyError, Module, ValidationErrorKind,
+};
+use alloc::{collections::BTreeSet, format, string::ToString, vec::Vec};
+use core::ops::ControlFlow;
+
+// IMMEDIATE VALIDATION
+// ================================================================================================
+
+#[test]
+fn validate_immediates_accepts_parsed_module() {
+    let source = "\
+    export.foo
+        u32shr.31 u32rotl.0 exp.u64 adv_push.16 adv.push_mapval.12
+    end";
+    let module =
+        Module::new(LibraryPath::new("test::foo").unwrap(), ModuleAst::parse(source).unwrap());
+    assert!(validate_immediates(&module).is_ok());
+}
+
+#[test]
+fn validate_immediates_reports_all_errors() {
+    let body = vec![
+        Node::Instruction(Instruction::U32ShrImm(40)),
+        Node::Instruction(Instruction::AdvPush(0)),
+        Node::Repeat {
+            times: 2,
+            body: CodeBody::new([Node::Instruction(Instruction::AdvInject(
+                AdviceInjectorNode::PushMapValImm { offset: 13 },
+            ))]),
+        },
+        Node::Instruction(Instruction::U32ShlImm(31)),
+    ];
+    let locations = (1..5).map(|line| SourceLocation::new(line, 5));
+    let procedure = ProcedureAst::new("foo".try_into().unwrap(), 0, body, true, None)
+        .with_source_locations(locations, SourceLocation::default());
+    let ast = ModuleAst::new(vec![procedure], vec![], None).unwrap();
+    let module = Module::new(LibraryPath::new("test::foo").unwrap(), ast);
+
+    let errors = validate_immediates(&module).unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert_eq!(
+        errors[0].kind(),
+        &ValidationErrorKind::ImmediateOutOfRange {
+            value: 40,
+            min: 0,
+            max: 31
+        }
+    );
+    assert_eq!(errors[0].location(), Some(&SourceLocation::new(1, 5)));
+    assert_eq!(
+        errors[0].to_string(),
+        "'u32shr.40' at [1:5] has immediate value 40 outside of the valid range [0, 31]"
+    );
+    assert_eq!(errors[1].operation(), "adv_push.0");
+    assert!(matches!(
+        errors[2].kind(),
+        ValidationErrorKind::ImmediateOutOfRange { value: 13, .. }
+    ));
+}
+
+// SYSCALL VALIDATION
+// ================================================================================================
+
+#[test]
+fn validate_syscalls_reports_all_unresolved_targets() {
+    let kernel = "\
+    export.foo
+        add
+    end
+    export.bar
+        mul
+    end
+    proc.baz
+        sub
+    end";
+    let kernel = Module::kernel(ModuleAst::parse(kernel).unwrap());
+
+    let source = "\
+    begin
+        syscall.foo
+        syscall.baz
+        syscall.bar
+        syscall.qux
+    end";
+    let program = ProgramAst::parse(source).unwrap();
+
+    let errors = Assembler::validate_syscalls(&program, &kernel).unwrap_err();
+    assert_eq!(errors.len(), 2);
+    let baz = ProcedureId::from_kernel_name("baz");
+    assert_eq!(errors[0].kind(), &ValidationErrorKind::UnresolvedSyscallTarget(baz));
+    assert_eq!(errors[0].operation(), format!("syscall.{baz}"));
+    assert_eq!(errors[0].location(), Some(&SourceLocation::new(3, 9)));
+    let qux = ProcedureId::from_kernel_name("qux");
+    assert_eq!(errors[1].kind(), &ValidationErrorKind::UnresolvedSyscallTarget(qux));
+    assert_eq!(errors[1].location(), Some(&SourceLocation::new(5, 9)));
+
+    let source = "begin syscall.foo syscall.bar end";
+    let program = ProgramAst::parse(source).unwrap();
+    assert!(Assembler::validate_syscalls(&program, &kernel).is_ok());
+}
+
+// KERNEL PROCEDURE VALIDATION
+// ================================================================================================
+
+#[test]
+fn validate_kernel_procedures_reports_offending_procedures() {
+    let kernel = "\
+    export.foo
+        add
+    end
+    proc.bar
+        push.1 drop
+        call.foo
+    end
+    export.baz
+        procref.foo
+        dynexec
+    end
+    export.qux
+        dyncall
+    end";
+    let kernel = Module::kernel(ModuleAst::parse(kernel).unwrap());
+
+    // only the first offending instruction of every procedure is reported
+    let errors = Assembler::validate_kernel(&kernel).unwrap_err();
+    assert_eq!(errors.len(), 3);
+    let reported = errors
+        .iter()
+        .map(|error| match error.kind() {
+            ValidationErrorKind::InvalidKernelInstruction(proc_name) => {
+                (proc_name.as_ref(), error.operation(), error.location().copied())
+            }
+            kind => panic!("unexpected error kind {kind:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        reported,
+        [
+            ("bar", "call.0", Some(SourceLocation::new(6, 9))),
+            ("baz", "procref.0", Some(SourceLocation::new(9, 9))),
+            ("qux", "dyncall", Some(SourceLocation::new(13, 9))),
+        ]
+    );
+    assert_eq!(
+        errors[0].to_string(),
+        "'call.0' at [6:9] in kernel procedure 'bar' cannot be executed within a syscall"
+    );
+
+    let kernel = "export.foo add end export.bar exec.foo mul end";
+    assert!(validate_kernel_procedures(&ModuleAst::parse(kernel).unwrap()).is_ok());
+
+    // kernel procedures cannot invoke other kernel procedures via syscall either
+    let kernel = "export.foo add end export.bar syscall.foo end";
+    let errors = validate_kernel_procedures(&ModuleAst::parse(kernel).unwrap()).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors[0].kind(),
+        &ValidationErrorKind::InvalidKernelInstruction("bar".try_into().unwrap())
+    );
+}
+
+#[test]
+fn kernel_procedures_are_validated_when_setting_kernel() {
+    let kernel = "export.foo add end export.bar dynexec end export.baz syscall.foo end";
+    let Err(AssemblyError::InvalidKernelProcedures(errors)) =
+        Assembler::default().with_kernel(kernel)
+    else {
+        panic!("the kernel should have been rejected");
+    };
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].operation(), "dynexec");
+    assert_eq!(
+        errors[1].kind(),
+        &ValidationErrorKind::InvalidKernelInstruction("baz".try_into().unwrap())
+    );
+
+    // every module of a multi-module kernel is validated
+    let modules = ["export.foo add end", "export.bar dyncall end"]
+        .map(|source| ModuleAst::parse(source).unwrap());
+    let result = Assembler::default().with_kernel_modules(&modules);
+    assert!(
+        matches!(result, Err(AssemblyError::InvalidKernelProcedures(errors)) if errors.len() == 1)
+    );
+}
+
+// LOCAL REFERENCE VALIDATION
+// ================================================================================================
+
+#[test]
+fn validate_local_references_reports_dangling_references() {
+    let source = "\
+    proc.foo
+        push.1
+    end
+
+    proc.baz
+        push.2
+    end
+
+    export.bar
+        exec.foo
+        exec.baz
+        call.baz
+        procref.baz
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    assert!(validate_local_references(&module).is_ok());
+
+    // with bar being the only procedure of the module, references to baz (index 1) are dangling,
+    // while references to foo (index 0) now refer to bar
+    let bar = module.procs()[2].clone();
+    let module = ModuleAst::new(vec![bar], vec![], None).unwrap();
+    let errors = validate_local_references(&module).unwrap_err();
+    assert_eq!(errors.len(), 3);
+    assert!(errors
+        .iter()
+        .all(|error| error.kind() == &ValidationErrorKind::UnresolvedLocalProcedure(1)));
+    assert_eq!(
+        errors[0].to_string(),
+        "'exec.1' at [11:9] refers to local procedure 1 which is not defined in the module"
+    );
+}
+
+// LOCAL ACCESS VALIDATION
+// ================================================================================================
+
+#[test]
+fn validate_local_accesses_reports_out_of_bounds_indexes() {
+    let source = "\
+    proc.foo.4
+        loc_load.3 loc_storew.0
+        if.true
+            loc_store.4
+        end
+    end
+
+    export.bar.1
+        locaddr.0 loc_loadw.1
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+
+    let errors = validate_local_accesses(&module).unwrap_err();
+    let accesses = errors
+        .iter()
+        .map(|error| match error.kind() {
+            ValidationErrorKind::LocalOutOfBounds { index, num_locals } => (*index, *num_locals),
+            kind => panic!("unexpected error kind {kind:?}"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(accesses, [(4, 4), (1, 1)]);
+    assert_eq!(
+        errors[0].to_string(),
+        "'loc_store.4' at [4:13] accesses local 4 which is out of bounds for a procedure with 4 locals"
+    );
+
+    // the body of a program declares no locals
+    let program = ProgramAst::parse("begin loc_load.0 end").unwrap();
+    let mut validator = LocalAccessValidator::default();
+    let _ = validator.visit_program(&program);
+    assert_eq!(validator.into_result().unwrap_err().len(), 1);
+}
+
+// CALL GRAPH
+// ================================================================================================
+
+#[test]
+fn call_graph_resolves_local_and_imported_callees() {
+    let helpers = "\
+    use.math::alias
+
+    proc.square
+        dup mul
+    end
+
+    export.double
+        push.2 mul
+    end
+
+    export.quad
+        exec.double exec.double exec.square
+    end
+
+    export.ping
+        exec.alias::pong
+    end";
+
+    let alias = "\
+    use.math::helpers
+
+    export.helpers::quad->quadruple
+
+    export.pong
+        exec.helpers::ping
+    end";
+
+    let app = "\
+    use.math::helpers
+    use.math::alias
+
+    export.run
+        exec.helpers::double
+        call.alias::quadruple
+        syscall.foo
+    end";
+
+    let module = |path: &str, source: &str| {
+        Module::new(LibraryPath::new(path).unwrap(), ModuleAst::parse(source).unwrap())
+    };
+    let modules = [
+        module("math::helpers", helpers),
+        module("math::alias", alias),
+        module("app::main", app),
+    ];
+    let graph = CallGraph::new(&modules);
+
+    let proc_id =
+        |path: &str, name: &str| ProcedureId::from_name(name, &LibraryPath::new(path).unwrap());
+    let square = proc_id("math::helpers", "square");
+    let double = proc_id("math::helpers", "double");
+    let quad = proc_id("math::helpers", "quad");
+    let ping = proc_id("math::helpers", "ping");
+    let pong = proc_id("math::alias", "pong");
+    let run = proc_id("app::main", "run");
+    let foo = ProcedureId::from_kernel_name("foo");
+
+    // local invocations, invocations via imports, and invocations of re-exported procedures
+    assert_eq!(graph.callees_of(&quad).collect::<Vec<_>>(), sorted([&square, &double]));
+    assert_eq!(graph.callees_of(&run).collect::<Vec<_>>(), sorted([&double, &quad, &foo]));
+    assert_eq!(graph.callers_of(&double).collect::<Vec<_>>(), sorted([&quad, &run]));
+    assert_eq!(graph.callers_of(&quad).collect::<Vec<_>>(), vec![&run]);
+    assert_eq!(graph.callees_of(&square).count(), 0);
+    assert_eq!(graph.callers_of(&run).count(), 0);
+
+    // `ping` and `pong` invoke each other across modules
+    assert!(graph.is_recursive(&ping));
+    assert!(graph.is_recursive(&pong));
+    assert!(!graph.is_recursive(&quad));
+    assert!(!graph.is_recursive(&run));
+}
+
+fn sorted<const N: usize>(mut ids: [&ProcedureId; N]) -> Vec<&ProcedureId> {
+    ids.sort();
+    ids.to_vec()
+}
+
+// PROCEDURE FAN-IN
+// ================================================================================================
+
+#[test]
+fn procedure_fan_in_counts_call_sites_of_defining_procedures() {
+    let helpers = "\
+    proc.square
+        dup mul
+    end
+
+    export.double
+        push.2 mul
+    end
+
+    export.quad
+        exec.double exec.double
+        repeat.4
+            exec.square
+        end
+    end";
+
+    let alias = "\
+    use.math::helpers
+
+    export.helpers::quad->quadruple";
+
+    let app = "\
+    use.math::helpers
+    use.math::alias
+
+    export.run
+        exec.helpers::double
+        call.alias::quadruple
+        exec.alias::quadruple
+    end";
+
+    let module = |path: &str, source: &str| {
+        Module::new(LibraryPath::new(path).unwrap(), ModuleAst::parse(source).unwrap())
+    };
+    let modules = [
+        module("math::helpers", helpers),
+        module("math::alias", alias),
+        module("app::main", app),
+    ];
+    let fan_in = procedure_fan_in(&modules);
+
+    let proc_id =
+        |path: &str, name: &str| ProcedureId::from_name(name, &LibraryPath::new(path).unwrap());
+    assert_eq!(fan_in.len(), 4);
+    assert_eq!(fan_in[&proc_id("math::helpers", "double")], 3);
+    assert_eq!(fan_in[&proc_id("math::helpers", "square")], 1);
+    // invocations of the re-exported procedure are counted against the original one
+    assert_eq!(fan_in[&proc_id("math::helpers", "quad")], 2);
+    assert_eq!(fan_in[&proc_id("app::main", "run")], 0);
+}
+
+// REPEAT EXPANSION
+// ================================================================================================
+
+#[test]
+fn estimate_expanded_size_multiplies_nested_repeats() {
+    let source = "\
+    proc.foo
+        push.1
+        repeat.10
+            add
+        end
+    end
+
+    export.bar
+        exec.foo
+        repeat.4
+            dup
+            repeat.1000
+                mul swap
+            end
+            if.true
+                drop
+            else
+                repeat.2 dup end
+            end
+        end
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+
+    // foo: 1 + 10; bar: 1 + 4 * (1 + 1000 * 2 + 1 + 2)
+    assert_eq!(estimate_expanded_size(&module), 11 + 1 + 4 * 2004);
+
+    let source = "begin repeat.3 push.1 repeat.5 add end end end";
+    let program = ProgramAst::parse(source).unwrap();
+    let mut estimator = ExpandedSizeEstimator::default();
+    let _ = estimator.visit_program(&program);
+    assert_eq!(estimator.size(), 3 * 6);
+}
+
+// COST REPORT
+// ================================================================================================
+
+#[test]
+fn program_cost_report_multiplies_repeats_and_counts_loops_once() {
+    let source = "\
+    proc.foo
+        add
+    end
+
+    export.bar
+        push.1
+        repeat.2
+            repeat.3 swap end
+            while.true
+                hperm push.0
+            end
+        end
+        exec.foo
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    let report = program_cost_report(&module);
+
+    let cost = |inst: Instruction| instruction_cost(&inst) as u64;
+    let field = report.category(InstructionCategory::Field);
+    assert_eq!((field.count, field.cycles), (1, cost(Instruction::Add)));
+    let stack = report.category(InstructionCategory::Stack);
+    assert_eq!((stack.count, stack.cycles), (6, 6 * cost(Instruction::Swap1)));
+    let crypto = report.category(InstructionCategory::Crypto);
+    assert_eq!((crypto.count, crypto.cycles), (2, 2 * cost(Instruction::HPerm)));
+    let io = report.category(InstructionCategory::Io);
+    let push_cycles = cost(Instruction::PushU8(1)) + 2 * cost(Instruction::PushU8(0));
+    assert_eq!((io.count, io.cycles), (3, push_cycles));
+    let invocation = report.category(InstructionCategory::Invocation);
+    assert_eq!((invocation.count, invocation.cycles), (1, 0));
+    assert_eq!(report.category(InstructionCategory::U32).count, 0);
+
+    assert_eq!(report.num_instructions(), 13);
+    let categories = report.categories().map(|(category, _)| category).collect::<Vec<_>>();
+    assert_eq!(
+        categories,
+        [
+            InstructionCategory::Field,
+            InstructionCategory::Stack,
+            InstructionCategory::Io,
+            InstructionCategory::Crypto,
+            InstructionCategory::Invocation
+        ]
+    );
+    assert_eq!(
+        report.total_cycles(),
+        cost(Instruction::Add)
+            + 6 * cost(Instruction::Swap1)
+            + 2 * cost(Instruction::HPerm)
+            + push_cycles
+    );
+
+    let program = ProgramAst::parse("begin repeat.4 u32wrapping_add end end").unwrap();
+    let mut estimator = CostEstimator::default();
+    let _ = estimator.visit_program(&program);
+    assert_eq!(estimator.into_report().category(InstructionCategory::U32).count, 4);
+
+    // `caller` is valid only in kernel procedures, but it takes a cycle wherever it is used
+    let program = ProgramAst::parse("begin caller end").unwrap();
+    let mut estimator = CostEstimator::default();
+    let _ = estimator.visit_program(&program);
+    assert_eq!(estimator.into_report().total_cycles(), 1);
+}
+
+// NESTING DEPTH
+// ================================================================================================
+
+#[test]
+fn max_nesting_depth_tracks_deepest_block() {
+    let source = "\
+    proc.foo
+        push.1
+    end
+
+    proc.bar
+        repeat.2
+            add
+        end
+        if.true
+            mul
+        else
+            repeat.2
+                sub
+            end
+        end
+    end
+
+    export.baz
+        dup eq.10
+        if.true
+            dup push.0 movdn.2 neq.0
+            while.true
+                dup movup.2 dup.1 eq.5
+                if.true
+                    mul
+                else
+                    add
+                end
+                swap push.1 sub dup neq.0
+            end
+            drop
+        else
+            dup mul
+        end
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    assert_eq!(max_nesting_depth(&module), 3);
+
+    let depths = module
+        .procs()
+        .iter()
+        .map(|procedure| {
+            let mut counter = NestingDepthCounter::default();
+            let _ = counter.visit_procedure(procedure);
+            counter.max_depth()
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(depths, [0, 2, 3]);
+
+    let module = ModuleAst::new(vec![], vec![], None).unwrap();
+    assert_eq!(max_nesting_depth(&module), 0);
+}
+
+// INSTRUCTION SEARCH
+// ================================================================================================
+
+#[test]
+fn find_first_stops_at_first_match() {
+    let source = "\
+    proc.foo
+        push.1
+        if.true
+            repeat.2
+                mul
+            end
+        else
+            u32shr.4
+        end
+        u32shr.5
+    end
+
+    export.bar
+        u32shr.6
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+
+    let mut visited = Vec::new();
+    let found = find_first(&module, |inst| {
+        visited.push(inst.clone());
+        matches!(inst, Instruction::U32ShrImm(_))
+    });
+    let (inst, location) = found.unwrap();
+    assert_eq!(inst, Instruction::U32ShrImm(4));
+    assert_eq!(location, Some(SourceLocation::new(8, 13)));
+
+    // the traversal halts at the match, so neither the rest of `foo` nor `bar` are visited
+    assert_eq!(visited, [Instruction::PushU8(1), Instruction::Mul, Instruction::U32ShrImm(4)]);
+
+    assert_eq!(find_first(&module, |inst| matches!(inst, Instruction::Add)), None);
+}
+
+// CALL DEPTH CHECK
+// ================================================================================================
+
+#[test]
+fn estimate_depth_on_return_tracks_stack_effects() {
+    let source = "\
+    proc.balanced
+        push.1 push.2 add drop
+    end
+
+    proc.padded
+        drop drop push.1
+    end
+
+    proc.unbalanced
+        padw dup.0 swap movup.2 u32split
+    end
+
+    proc.branching
+        if.true push.1 end
+    end
+
+    proc.invoking
+        exec.balanced push.1
+    end
+
+    begin
+        call.balanced call.padded call.unbalanced call.branching call.invoking
+    end";
+    let program = ProgramAst::parse(source).unwrap();
+    let depths = program.procedures().iter().map(estimate_depth_on_return).collect::<Vec<_>>();
+
+    // dropping items never decreases the depth below 16, and control flow blocks and procedure
+    // invocations are skipped
+    assert_eq!(depths, [Some(16), Some(17), Some(22), None, None]);
+}
+
+#[test]
+fn check_call_depths_reports_unbalanced_procedures() {
+    let source = "\
+    proc.foo
+        push.1 push.2
+    end
+
+    proc.bar
+        push.1 drop
+    end
+
+    proc.baz
+        push.1
+    end
+
+    export.qux
+        call.foo call.bar exec.baz call.foo
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    let warnings = check_call_depths(&module);
+
+    // only procedures invoked via call are checked, once per procedure
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(warnings[0].name().as_ref(), "foo");
+    assert_eq!(warnings[0].depth(), 18);
+    assert_eq!(*warnings[0].location(), SourceLocation::new(1, 1));
+}
+
+// STACK UNDERFLOW DETECTION
+// ================================================================================================
+
+#[test]
+fn find_stack_underflows_reports_procedures_reading_below_the_top_16_items() {
+    let source = "\
+    proc.fits
+        push.1 movup.15 add movup.15 drop
+    end
+
+    proc.deep
+        dropw
+        swapw.3
+    end
+
+    proc.branching
+        if.true
+            push.1
+        else
+            dropw dropw dropw dropw
+        end
+    end
+
+    proc.looping
+        repeat.20
+            drop
+        end
+    end
+
+    proc.invoking
+        exec.fits
+        dropw dropw dropw dropw drop
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+    let warnings = find_stack_underflows(&module);
+
+    // consumers are counted before producers, and the traversal stops at procedure invocations
+    let names = warnings.iter().map(|warning| warning.name().as_ref()).collect::<Vec<_>>();
+    assert_eq!(names, ["deep", "branching", "looping"]);
+    assert_eq!(warnings[0].depth(), 20);
+    assert_eq!(warnings[0].location(), Some(&SourceLocation::new(7, 9)));
+    assert_eq!(warnings[1].depth(), 17);
+    assert_eq!(warnings[1].location(), Some(&SourceLocation::new(14, 31)));
+    assert_eq!(warnings[2].depth(), 17);
+    assert_eq!(warnings[2].location(), Some(&SourceLocation::new(20, 13)));
+    assert_eq!(
+        warnings[0].to_string(),
+        "procedure deep reads below the top 16 stack items at [7:9] and requires a stack depth of \
+        at least 20 on entry"
+    );
+}
+
+#[test]
+fn stack_underflow_detector_tracks_required_depth() {
+    let source = "\
+    begin
+        push.1 push.2 movup.15 drop
+        while.true
+            dup.15 add push.1
+        end
+    end";
+    let program = ProgramAst::parse(source).unwrap();
+    let mut detector = StackUnderflowDetector::default();
+    let _ = detector.visit_program(&program);
+
+    // the loop body starts at the depth of the procedure entry, and reads exactly 16 items
+    assert_eq!(detector.required_depth(), 16);
+    assert_eq!(detector.location(), None);
+}
+
+// CONSTANT FOLDING
+// ================================================================================================
+
+#[test]
+fn fold_constants_folds_nested_blocks() {
+    let source = "\
+    export.foo
+        push.1 push.2 add push.3 mul
+        if.true
+            push.10 push.4 sub push.5
+        else
+            repeat.2
+                push.2 dup mul
+            end
+        end
+    end";
+    let mut module = ModuleAst::parse(source).unwrap();
+    assert_eq!(fold_constants(&mut module), 3);
+
+    let expected = "\
+    export.foo
+        push.9
+        if.true
+            push.6 push.5
+        else
+            repeat.2
+                push.2 dup mul
+            end
+        end
+    end";
+    let expected = ModuleAst::parse(expected).unwrap();
+    assert_eq!(format_module(&module), format_module(&expected));
+
+    // the folded push keeps the location of the first push, and the block keeps its end location
+    let body = &module.procs()[0].body;
+    let locations = body.source_locations();
+    assert_eq!(locations.len(), body.nodes().len() + 1);
+    assert_eq!(locations[0], SourceLocation::new(2, 9));
+    assert_eq!(locations[1], SourceLocation::new(3, 9));
+}
+
+// PROCEDURE INLINING
+// ================================================================================================
+
+#[test]
+fn inline_procedures_skips_recursive_procedures() {
+    let exec = |index| Node::Instruction(Instruction::ExecLocal(index));
+    let procedure =
+        |name: &str, body| ProcedureAst::new(name.try_into().unwrap(), 0, body, true, None);
+    // foo and bar invoke each other, and thus only baz can be inlined
+    let procs = vec![
+        procedure("foo", vec![Node::Instruction(Instruction::Add), exec(1)]),
+        procedure("bar", vec![exec(0)]),
+        procedure("baz", vec![Node::Instruction(Instruction::Mul)]),
+        procedure("qux", vec![exec(2), exec(0), exec(2)]),
+    ];
+    let mut module = ModuleAst::new(procs, vec![], None).unwrap();
+    let names = ["foo", "bar", "baz"]
+        .into_iter()
+        .map(|name| name.try_into().unwrap())
+        .collect::<BTreeSet<_>>();
+
+    assert_eq!(inline_procedures(&mut module, &names), 2);
+    assert_eq!(
+        module.procs()[3].body.nodes(),
+        [
+            Node::Instruction(Instruction::Mul),
+            exec(0),
+            Node::Instruction(Instruction::Mul)
+        ]
+    );
+    assert_eq!(module.procs()[0].body.nodes(), [Node::Instruction(Instruction::Add), exec(1)]);
+}
+
+// MULTIPLE VISITORS
+// ================================================================================================
+
+#[test]
+fn run_all_runs_every_visitor_in_a_single_traversal() {
+    let body = vec![
+        Node::Repeat {
+            times: 3,
+            body: CodeBody::new([
+                Node::Instruction(Instruction::U32ShrImm(40)),
+                Node::Instruction(Instruction::ExecLocal(1)),
+            ]),
+        },
+        Node::Instruction(Instruction::Add),
+    ];
+    let procedure = ProcedureAst::new("foo".try_into().unwrap(), 0, body, true, None);
+    let module = ModuleAst::new(vec![procedure], vec![], None).unwrap();
+
+    // every instruction is dispatched to each visitor exactly once
+    let mut counter = InstructionCounter::new(usize::MAX);
+    let mut immediates = ImmediateRangeValidator::default();
+    let mut references = LocalReferenceValidator::new(module.procs().len());
+    let mut visitors: Vec<&mut dyn VisitInst> =
+        vec![&mut counter, &mut immediates, &mut references];
+    assert!(run_all(&mut visitors, &module).is_continue());
+
+    assert_eq!(counter.count, 3);
+    assert_eq!(immediates.into_result().unwrap_err().len(), 1);
+    assert_eq!(references.into_result().unwrap_err().len(), 1);
+
+    // once a visitor breaks, the remaining instructions are not dispatched to any visitor
+    let mut counter = InstructionCounter::new(1);
+    let mut references = LocalReferenceValidator::new(module.procs().len());
+    let mut visitors: Vec<&mut dyn VisitInst> = vec![&mut counter, &mut references];
+    assert!(run_all(&mut visitors, &module).is_break());
+
+    assert_eq!(counter.count, 1);
+    assert!(references.into_result().is_ok());
+}
+
+// FORMATTING
+// ================================================================================================
+
+#[test]
+fn format_module_normalizes_source() {
+    let source = "\
+    use.std::math::u64
+    use.std::crypto::hashes::blake3->hashes
+    proc.foo.2   push.1 add
+        repeat.2 u32wrapping_add.7 end
+    end
+    #! Invokes foo.
+    export.bar
+        exec.foo if.true exec.u64::checked_add else while.true push.0x00ff.18446744069414584320 end end
+        call.hashes::hash_1to1
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+
+    let expected = "\
+use.std::crypto::hashes::blake3->hashes
+use.std::math::u64
+
+proc.foo.2
+    push.1
+    add
+    repeat.2
+        u32wrapping_add.7
+    end
+end
+
+#! Invokes foo.
+export.bar.0
+    exec.foo
+    if.true
+        exec.u64::checked_add
+    else
+        while.true
+            push.255.18446744069414584320
+        end
+    end
+    call.hashes::hash_1to1
+end
+
+";
+    let formatted = format_module(&module);
+    assert_eq!(formatted, expected);
+
+    // formatting is idempotent
+    assert_eq!(format_module(&ModuleAst::parse(&formatted).unwrap()), expected);
+}
+
+#[test]
+fn format_module_preserves_reexports() {
+    let source = "\
+    use.std::math::u64
+    use.std::crypto::hashes::blake3->hashes
+    export.u64::checked_add
+    #! Hashes two digests.
+    export.hashes::hash_2to1->merge";
+    let module = ModuleAst::parse(source).unwrap();
+
+    let expected = "\
+use.std::crypto::hashes::blake3->hashes
+use.std::math::u64
+
+export.u64::checked_add
+
+#! Hashes two digests.
+export.hashes::hash_2to1->merge
+
+";
+    let formatted = format_module(&module);
+    assert_eq!(formatted, expected);
+
+    // the formatted module re-exports the same procedures under the same names
+    let parsed = ModuleAst::parse(&formatted).unwrap();
+    assert_eq!(parsed.reexported_procs(), module.reexported_procs());
+    assert_eq!(format_module(&parsed), expected);
+}
+
+#[test]
+fn format_module_with_stack_effect_comments_tracks_depth() {
+    let source = "\
+    proc.bar
+        add
+    end
+    proc.foo
+        push.1 push.2
+        if.true dup else push.3 end
+        repeat.2 swap end
+        repeat.2 push.0 end
+        push.1 while.true push.0 end
+        while.true push.1 push.0 end
+        exec.bar
+        drop
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+
+    let expected = "\
+proc.bar.0
+    add # effect: -1, depth: -1
+end # depth: -1
+
+proc.foo.0
+    push.1 # effect: +1, depth: +1
+    push.2 # effect: +1, depth: +2
+    if.true # effect: -1, depth: +1
+        dup.0 # effect: +1, depth: +2
+    else
+        push.3 # effect: +1, depth: +2
+    end # depth: +2
+    repeat.2
+        swap.1 # effect: +0, depth: +2
+    end # depth: +2
+    repeat.2
+        push.0 # effect: +1, depth: ?
+    end # depth: +4
+    push.1 # effect: +1, depth: +5
+    while.true # effect: -1, depth: +4
+        push.0 # effect: +1, depth: +5
+    end # depth: +4
+    while.true # effect: -1, depth: +3
+        push.1 # effect: +1, depth: ?
+        push.0 # effect: +1, depth: ?
+    end # depth: ?
+    exec.bar # effect: ?, depth: ?
+    drop # effect: -1, depth: ?
+end # depth: ?
+
+";
+    let assembler = Assembler::default().with_stack_effect_comments(true);
+    let formatted = assembler.format_module(&module);
+    assert_eq!(formatted, expected);
+
+    // without the option, the module is formatted without annotations
+    assert_eq!(Assembler::default().format_module(&module), format_module(&module));
+
+    // the annotations are comments, and thus the annotated source parses into the same module
+    assert_eq!(format_module(&ModuleAst::parse(&formatted).unwrap()), format_module(&module));
+}
+
+// REDUNDANT OPERATIONS
+// ================================================================================================
+
+#[test]
+fn find_redundant_ops_reports_canceling_pairs() {
+    let source = "\
+    export.foo
+        swap swap swap
+        dup drop
+        movup.3 movdn.3
+        movdn.2 movup.3
+        if.true
+            movdnw.2 movupw.2
+        else
+            dup.1 drop
+        end
+    end";
+    let module = ModuleAst::parse(source).unwrap();
+
+    let warnings = find_redundant_ops(&module);
+    let pairs = warnings
+        .iter()
+        .map(|warning| (warning.first().clone(), warning.second().clone()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pairs,
+        [
+            (Instruction::Swap1, Instruction::Swap1),
+            (Instruction::Dup0, Instruction::Drop),
+            (Instruction::MovUp3, Instruction::MovDn3),
+            (Instruction::MovDnW2, Instruction::MovUpW2),
+        ]
+    );
+    assert_eq!(
+        warnings[0].to_string(),
+        "'swap.1' followed by 'swap.1' at [2:9]..[2:14] leaves the stack unchanged and can be removed"
+    );
+}
+
+// HELPERS
+// ================================================================================================
+
+/// A visitor which counts the visited instructions, and stops the traversal once `limit` of them
+/// have been visited.
+struct InstructionCounter {
+    count: usize,
+    limit: usize,
+}
+
+impl InstructionCounter {
+    fn new(limit: usize) -> Self {
+        Self { count: 0, limit }
+    }
+}
+
+impl Visit for InstructionCounter {
+    fn visit_inst(
+        &mut self,
+        _inst: &Instruction,
+        _location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.count += 1;
+        if self.count == self.limit {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+impl VisitInst for InstructionCounter {}
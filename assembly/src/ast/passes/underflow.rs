@@ -0,0 +1,170 @@
+use super::{
+    stack_depth::{stack_effect, stack_inputs},
+    Instruction, ModuleAst, Node, ProcedureAst, SourceLocation, Visit,
+};
+use crate::errors::StackUnderflowWarning;
+use alloc::vec::Vec;
+use core::ops::ControlFlow;
+use vm_core::stack::STACK_TOP_SIZE;
+
+// STACK UNDERFLOW DETECTION
+// ================================================================================================
+
+/// Returns a warning for every procedure in the provided module which reads below the top 16
+/// items of the stack it receives.
+///
+/// Only the top 16 items of the stack are guaranteed to be provided to a procedure; reading
+/// deeper items implies that the procedure relies on the items in the stack overflow table or, if
+/// there are none, on the zeros the VM pads the stack with, which is often a bug. The minimum
+/// depth of the stack required by a procedure is computed via [StackUnderflowDetector].
+pub fn find_stack_underflows(module: &ModuleAst) -> Vec<StackUnderflowWarning> {
+    module
+        .procs()
+        .iter()
+        .filter_map(|procedure| {
+            let mut detector = StackUnderflowDetector::default();
+            let _ = detector.visit_procedure(procedure);
+            detector.into_warning(procedure)
+        })
+        .collect()
+}
+
+// STACK UNDERFLOW DETECTOR
+// ================================================================================================
+
+/// A visitor which computes the minimum depth of the stack required by the visited instructions,
+/// i.e., the number of items they consume from the stack they start with before producing their
+/// own.
+///
+/// The depth is tracked relative to the start of the traversal, using the stack effects of the
+/// instructions (see [stack_effect()](super::stack_effect)):
+/// - Both branches of an `if.true` block are visited; the traversal is stopped after the block if
+///   the branches have different stack effects.
+/// - The body of a `while.true` loop is visited once; the traversal is stopped after the loop if
+///   the body does not leave exactly one more item on the stack than it consumes.
+/// - The body of a `repeat` block is visited once per iteration for as long as each iteration
+///   consumes items, and only once otherwise.
+///
+/// The traversal is also stopped as soon as an instruction without a statically known stack
+/// effect (e.g., a procedure invocation) is reached.
+#[derive(Debug, Clone, Default)]
+pub struct StackUnderflowDetector {
+    depth: i64,
+    required_depth: usize,
+    location: Option<SourceLocation>,
+}
+
+impl StackUnderflowDetector {
+    /// Returns the minimum depth of the stack required by the instructions visited so far.
+    pub fn required_depth(&self) -> usize {
+        self.required_depth
+    }
+
+    /// Returns the source location of the first visited instruction which reads below the top 16
+    /// items of the stack, if any such instruction was visited and its location is known.
+    pub fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_ref()
+    }
+
+    /// Returns a warning for the provided procedure if the instructions visited so far read below
+    /// the top 16 items of the stack.
+    pub fn into_warning(self, procedure: &ProcedureAst) -> Option<StackUnderflowWarning> {
+        (self.required_depth > STACK_TOP_SIZE).then(|| {
+            StackUnderflowWarning::new(procedure.name.clone(), self.required_depth, self.location)
+        })
+    }
+
+    /// Records that `inputs` items are read from the top of the stack at the current depth.
+    fn read(&mut self, inputs: usize, location: Option<&SourceLocation>) {
+        let required_depth = (inputs as i64 - self.depth).max(0) as usize;
+        if required_depth > STACK_TOP_SIZE && self.required_depth <= STACK_TOP_SIZE {
+            self.location = location.copied();
+        }
+        self.required_depth = self.required_depth.max(required_depth);
+    }
+
+    /// Merges the state of the provided detector, which visited an alternative branch, into this
+    /// one, and returns true if both branches end at the same depth.
+    fn merge(&mut self, other: Self) -> bool {
+        if other.required_depth > self.required_depth {
+            if self.required_depth <= STACK_TOP_SIZE {
+                self.location = other.location;
+            }
+            self.required_depth = other.required_depth;
+        }
+        self.depth == other.depth
+    }
+}
+
+impl Visit for StackUnderflowDetector {
+    fn visit_node(&mut self, node: &Node, location: Option<&SourceLocation>) -> ControlFlow<()> {
+        match node {
+            Node::Instruction(inst) => self.visit_inst(inst, location),
+            Node::IfElse {
+                true_case,
+                false_case,
+            } => {
+                // the condition is consumed before either branch is executed
+                self.read(1, location);
+                self.depth -= 1;
+
+                let mut false_branch = self.clone();
+                let true_result = self.visit_block(true_case);
+                let false_result = false_branch.visit_block(false_case);
+                let same_depth = self.merge(false_branch);
+                if true_result.is_break() || false_result.is_break() || !same_depth {
+                    return ControlFlow::Break(());
+                }
+                ControlFlow::Continue(())
+            }
+            Node::While { body } => {
+                self.read(1, location);
+                self.depth -= 1;
+
+                let start_depth = self.depth;
+                self.visit_block(body)?;
+                if self.depth != start_depth + 1 {
+                    return ControlFlow::Break(());
+                }
+
+                // the condition of the next iteration is consumed at the end of the body
+                self.read(1, location);
+                self.depth -= 1;
+                ControlFlow::Continue(())
+            }
+            Node::Repeat { times, body } => {
+                for iteration in 0..*times {
+                    let start_depth = self.depth;
+                    self.visit_block(body)?;
+
+                    // subsequent iterations can read deeper only if an iteration consumes items;
+                    // otherwise, the depth after all iterations is known after the first one
+                    let effect = self.depth - start_depth;
+                    if effect >= 0 {
+                        self.depth += effect * (*times - iteration - 1) as i64;
+                        break;
+                    }
+                    if self.required_depth > STACK_TOP_SIZE {
+                        return ControlFlow::Break(());
+                    }
+                }
+                ControlFlow::Continue(())
+            }
+        }
+    }
+
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        match (stack_inputs(inst), stack_effect(inst)) {
+            (Some(inputs), Some(effect)) => {
+                self.read(inputs, location);
+                self.depth += effect as i64;
+                ControlFlow::Continue(())
+            }
+            _ => ControlFlow::Break(()),
+        }
+    }
+}
@@ -178,15 +178,25 @@ impl Deserializable for ProcedureAst {
 pub struct ProcReExport {
     pub(crate) proc_id: ProcedureId,
     pub(crate) name: ProcedureName,
+    pub(crate) module_path: LibraryPath,
+    pub(crate) ref_name: ProcedureName,
     pub(crate) docs: Option<String>,
 }
 
 impl ProcReExport {
-    /// Creates a new re-exported procedure.
-    pub fn new(proc_id: ProcedureId, name: ProcedureName, docs: Option<String>) -> Self {
+    /// Creates a new re-exported procedure, which re-exports the procedure `ref_name` defined in
+    /// the module at `module_path` under the specified name.
+    pub fn new(
+        module_path: LibraryPath,
+        ref_name: ProcedureName,
+        name: ProcedureName,
+        docs: Option<String>,
+    ) -> Self {
         Self {
-            proc_id,
+            proc_id: ProcedureId::from_name(&ref_name, &module_path),
             name,
+            module_path,
+            ref_name,
             docs,
         }
     }
@@ -204,6 +214,16 @@ impl ProcReExport {
         &self.name
     }
 
+    /// Returns the path of the module in which the re-exported procedure is defined.
+    pub fn module_path(&self) -> &LibraryPath {
+        &self.module_path
+    }
+
+    /// Returns the name of the re-exported procedure in the module in which it is defined.
+    pub fn ref_name(&self) -> &ProcedureName {
+        &self.ref_name
+    }
+
     /// Returns the documentation of the re-exported procedure, if present.
     pub fn docs(&self) -> Option<&str> {
         self.docs.as_deref()
@@ -219,6 +239,8 @@ impl Serializable for ProcReExport {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.proc_id.write_into(target);
         self.name.write_into(target);
+        self.module_path.write_into(target);
+        self.ref_name.write_into(target);
         match &self.docs {
             Some(docs) => {
                 assert!(docs.len() <= MAX_DOCS_LEN, "docs too long");
@@ -236,6 +258,8 @@ impl Deserializable for ProcReExport {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let proc_id = ProcedureId::read_from(source)?;
         let name = ProcedureName::read_from(source)?;
+        let module_path = LibraryPath::read_from(source)?;
+        let ref_name = ProcedureName::read_from(source)?;
         let docs_len = source.read_u16()? as usize;
         let docs = if docs_len != 0 {
             let str = source.read_vec(docs_len)?;
@@ -248,6 +272,8 @@ impl Deserializable for ProcReExport {
         Ok(Self {
             proc_id,
             name,
+            module_path,
+            ref_name,
             docs,
         })
     }
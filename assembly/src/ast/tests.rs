@@ -1,13 +1,14 @@
 use super::{
-    AstSerdeOptions, CodeBody, Felt, Instruction, LocalProcMap, ModuleAst, Node, ParsingError,
-    ProcedureAst, ProcedureId, ProcedureName, ProgramAst, SourceLocation, Token,
+    AstSerdeOptions, CodeBody, Felt, ImmediateArity, Instruction, InvocationTarget, LocalProcMap,
+    ModuleAst, Node, ParsingError, ProcedureAst, ProcedureId, ProcedureName, ProgramAst,
+    SourceLocation, Token,
 };
 use alloc::{
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     string::{String, ToString},
     vec::Vec,
 };
-use vm_core::utils::SliceReader;
+use vm_core::utils::{Serializable, SliceReader};
 
 // UNIT TESTS
 // ================================================================================================
@@ -261,6 +262,45 @@ fn test_ast_parsing_ilog2() {
     assert_program_output(source, BTreeMap::new(), nodes);
 }
 
+#[test]
+fn test_ast_parsing_debug_mem() {
+    use vm_core::DebugOptions::*;
+    use Instruction::Debug;
+
+    let source = "begin debug.mem.2.5 debug.mem_ctx.3.7 debug.mem_ctx.3.2.5 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Debug(MemInterval(2, 5))),
+        Node::Instruction(Debug(MemIntervalCtx(3, 7, 7))),
+        Node::Instruction(Debug(MemIntervalCtx(3, 2, 5))),
+    ];
+    assert_program_output(source, BTreeMap::new(), nodes);
+    assert_correct_program_serialization(source, true);
+
+    // the end of the interval must not precede its beginning, and the context must be specified
+    assert!(ProgramAst::parse("begin debug.mem_ctx.3.5.2 end").is_err());
+    assert!(ProgramAst::parse("begin debug.mem_ctx.3 end").is_err());
+    assert!(ProgramAst::parse("begin debug.mem_ctx.3.2.5.6 end").is_err());
+}
+
+#[test]
+fn test_ast_parsing_debug_checkpoint() {
+    use vm_core::DebugOptions::*;
+    use Instruction::Debug;
+
+    let source = "begin debug.checkpoint.0 debug.checkpoint.4294967295 end";
+    let nodes: Vec<Node> = vec![
+        Node::Instruction(Debug(Checkpoint(0))),
+        Node::Instruction(Debug(Checkpoint(u32::MAX))),
+    ];
+    assert_program_output(source, BTreeMap::new(), nodes);
+    assert_correct_program_serialization(source, true);
+
+    // the label must be specified and fit into a u32
+    assert!(ProgramAst::parse("begin debug.checkpoint end").is_err());
+    assert!(ProgramAst::parse("begin debug.checkpoint.4294967296 end").is_err());
+    assert!(ProgramAst::parse("begin debug.checkpoint.1.2 end").is_err());
+}
+
 #[test]
 fn test_ast_parsing_use() {
     let source = "\
@@ -510,6 +550,23 @@ fn test_missing_import() {
     }
 }
 
+// INVOCATION TARGETS
+// ================================================================================================
+
+#[test]
+fn test_invocation_target_display() {
+    let token = Token::new_dummy();
+    let labels = [
+        "foo",
+        "u64::wrapping_add",
+        "0x0000000000000000000000000000000000000000000000000000000000000001",
+    ];
+    for label in labels {
+        let target = InvocationTarget::parse(label, &token).unwrap();
+        assert_eq!(target.to_string(), label);
+    }
+}
+
 // INVALID BODY TESTS
 // ================================================================================================
 
@@ -899,6 +956,53 @@ fn assert_parsing_line_invalid_op() {
     assert_eq!(err, ParsingError::invalid_op(&Token::new("u32overflowing_mulx", location)));
 }
 
+#[test]
+fn test_all_mnemonics_are_parsed() {
+    let location = SourceLocation::new(1, 7);
+    for &(mnemonic, arity) in Instruction::all_mnemonics() {
+        let source = format!("begin {mnemonic} end");
+        let result = ProgramAst::parse(&source);
+        match arity {
+            ImmediateArity::Zero | ImmediateArity::ZeroOrOne => {
+                assert!(result.is_ok(), "failed to parse '{mnemonic}'");
+            }
+            ImmediateArity::One | ImmediateArity::OneOrMore => {
+                let err = result.expect_err(mnemonic);
+                assert_ne!(err, ParsingError::invalid_op(&Token::new(mnemonic, location)));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_all_parsed_mnemonics_are_listed() {
+    // the mnemonics accepted by the parser are the patterns of the match over the first part of
+    // an operation token in the parser context
+    let parser = include_str!("parsers/context.rs");
+    let start = parser.find("fn parse_op_token").expect("op token parser not found");
+    let end = start
+        + parser[start..]
+            .find("_ => Err(ParsingError::invalid_op(op))")
+            .expect("catch all arm of the op token parser not found");
+
+    let listed = Instruction::all_mnemonics()
+        .iter()
+        .map(|(mnemonic, _)| *mnemonic)
+        .collect::<BTreeSet<_>>();
+    let mut num_parsed = 0;
+    for line in parser[start..end].lines().map(str::trim).filter(|line| line.starts_with('"')) {
+        let (patterns, _) = line.split_once("=>").expect("match arm expected");
+        for mnemonic in patterns.split('|').map(|pattern| pattern.trim().trim_matches('"')) {
+            // constants are declared via `const` statements, which are not instructions
+            if mnemonic != "const" {
+                assert!(listed.contains(mnemonic), "'{mnemonic}' is not a listed mnemonic");
+                num_parsed += 1;
+            }
+        }
+    }
+    assert_eq!(num_parsed, listed.len());
+}
+
 #[test]
 fn assert_parsing_line_unexpected_eof() {
     let source = "proc.foo\nadd\nend".to_string();
@@ -971,6 +1075,17 @@ fn test_ast_module_serde_imports_not_serialized() {
     assert_correct_module_serialization(source, false);
 }
 
+#[test]
+fn test_ast_serde_opcodes_are_stable() {
+    // new opcodes are appended after the existing ones, such that serialized ASTs remain readable
+    let opcode = |inst: Instruction| inst.to_bytes()[0];
+    assert_eq!(opcode(Instruction::Caller), 188);
+    assert_eq!(opcode(Instruction::FriExt2Fold4), 214);
+    assert_eq!(opcode(Instruction::Trace(1)), 228);
+    assert_eq!(opcode(Instruction::MTreeAssertRoot), 229);
+    assert_eq!(opcode(Instruction::OverflowDepth), 230);
+}
+
 #[test]
 fn test_repeat_with_constant_count() {
     let source = "\
@@ -0,0 +1,516 @@
+//! Visitors over Miden assembly ASTs.
+//!
+//! The [Visit] trait provides a hook for every interesting element of a [ModuleAst] or
+//! [ProgramAst]. Each hook has a default implementation which delegates to the free function of
+//! the same name in this module; these functions perform the actual traversal of the tree. An
+//! implementation of [Visit] therefore only needs to override the hooks it is interested in, and
+//! can resume the default traversal from within an overridden hook by calling the corresponding
+//! free function.
+//!
+//! All hooks return a [ControlFlow], which allows a visitor to stop the traversal early by
+//! returning [ControlFlow::Break].
+//!
+//! The [VisitMut] trait follows the same model for visitors which modify the visited tree in
+//! place; its default traversal is implemented by the `visit_mut_*` free functions.
+use super::{
+    AdviceInjectorNode, CodeBody, Felt, Instruction, ModuleAst, Node, ProcedureAst, ProcedureId,
+    ProgramAst, RpoDigest, SourceLocation,
+};
+use core::ops::ControlFlow;
+use vm_core::DebugOptions;
+
+// CALLEE
+// ================================================================================================
+
+/// The target of an `exec`, a `call`, or a `procref` instruction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Callee<'a> {
+    /// A procedure defined in the same module, identified by its index in the module.
+    Local(u16),
+    /// A procedure imported from another module.
+    Imported(&'a ProcedureId),
+    /// A procedure identified by the MAST root of its body.
+    MastRoot(&'a RpoDigest),
+}
+
+// VISITOR
+// ================================================================================================
+
+/// A read-only visitor over the nodes of a Miden assembly AST.
+///
+/// The type parameter `T` is the type of value carried by [ControlFlow::Break] when a visitor
+/// terminates the traversal early.
+pub trait Visit<T = ()> {
+    fn visit_module(&mut self, module: &ModuleAst) -> ControlFlow<T> {
+        visit_module(self, module)
+    }
+
+    fn visit_program(&mut self, program: &ProgramAst) -> ControlFlow<T> {
+        visit_program(self, program)
+    }
+
+    fn visit_procedure(&mut self, procedure: &ProcedureAst) -> ControlFlow<T> {
+        visit_procedure(self, procedure)
+    }
+
+    fn visit_block(&mut self, block: &CodeBody) -> ControlFlow<T> {
+        visit_block(self, block)
+    }
+
+    /// Visits a single node; `location` is the source location of the node, if known.
+    fn visit_node(&mut self, node: &Node, location: Option<&SourceLocation>) -> ControlFlow<T> {
+        visit_node(self, node, location)
+    }
+
+    /// Visits a single instruction; `location` is the source location of the instruction, if
+    /// known.
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<T> {
+        visit_inst(self, inst, location)
+    }
+
+    /// Visits the target of an `exec` instruction.
+    fn visit_exec(&mut self, callee: Callee) -> ControlFlow<T> {
+        visit_exec(self, callee)
+    }
+
+    /// Visits the target of a `call` instruction.
+    fn visit_call(&mut self, callee: Callee) -> ControlFlow<T> {
+        visit_call(self, callee)
+    }
+
+    /// Visits the target of a `syscall` instruction, i.e., the ID of the invoked kernel procedure.
+    fn visit_syscall(&mut self, callee: &ProcedureId) -> ControlFlow<T> {
+        visit_syscall(self, callee)
+    }
+
+    /// Visits the procedure referenced by a `procref` instruction.
+    fn visit_procref(&mut self, callee: Callee) -> ControlFlow<T> {
+        visit_procref(self, callee)
+    }
+
+    fn visit_advice_injector(&mut self, injector: &AdviceInjectorNode) -> ControlFlow<T> {
+        visit_advice_injector(self, injector)
+    }
+
+    fn visit_debug_options(&mut self, options: &DebugOptions) -> ControlFlow<T> {
+        visit_debug_options(self, options)
+    }
+
+    fn visit_immediate_u8(&mut self, imm: &u8) -> ControlFlow<T> {
+        visit_immediate_u8(self, imm)
+    }
+
+    fn visit_immediate_u16(&mut self, imm: &u16) -> ControlFlow<T> {
+        visit_immediate_u16(self, imm)
+    }
+
+    fn visit_immediate_u32(&mut self, imm: &u32) -> ControlFlow<T> {
+        visit_immediate_u32(self, imm)
+    }
+
+    fn visit_immediate_felt(&mut self, imm: &Felt) -> ControlFlow<T> {
+        visit_immediate_felt(self, imm)
+    }
+
+    fn visit_immediate_error_code(&mut self, code: &u32) -> ControlFlow<T> {
+        visit_immediate_error_code(self, code)
+    }
+}
+
+// MULTIPLE VISITORS
+// ================================================================================================
+
+/// A visitor whose results depend only on the visited instructions, and not on the structure of
+/// the code containing them.
+///
+/// Implementors must not override the structural hooks of [Visit] (i.e., `visit_module()`,
+/// `visit_program()`, `visit_procedure()`, `visit_block()`, and `visit_node()`), as these are not
+/// invoked when the visitor is run via [run_all()]. Only [Visit::visit_inst()] and the hooks
+/// invoked from it (e.g., hooks for immediate values and invoked procedures) may be overridden.
+pub trait VisitInst: Visit {}
+
+/// Runs every visitor in `visitors` over the provided module in a single traversal.
+///
+/// [Visit] with the default `T = ()` is object safe, and thus visitors of different types (e.g.,
+/// independent lint checks) can be collected as trait objects and executed together. The
+/// procedures, blocks, and nodes of the module are traversed only once, and every instruction is
+/// dispatched to [Visit::visit_inst()] of each visitor, in order. Since structural hooks are not
+/// dispatched to the visitors, only visitors implementing [VisitInst] are accepted.
+///
+/// If a visitor returns [ControlFlow::Break], the traversal stops for all visitors.
+pub fn run_all(visitors: &mut [&mut dyn VisitInst], module: &ModuleAst) -> ControlFlow<()> {
+    VisitAll(visitors).visit_module(module)
+}
+
+/// A visitor which dispatches every visited instruction to each of the wrapped visitors.
+struct VisitAll<'a, 'b>(&'a mut [&'b mut dyn VisitInst]);
+
+impl Visit for VisitAll<'_, '_> {
+    fn visit_inst(
+        &mut self,
+        inst: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<()> {
+        self.0.iter_mut().try_for_each(|visitor| visitor.visit_inst(inst, location))
+    }
+}
+
+// DEFAULT TRAVERSAL
+// ================================================================================================
+
+/// Visits all local procedures of the module in the order of their indexes.
+pub fn visit_module<V, T>(visitor: &mut V, module: &ModuleAst) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    for procedure in module.procs() {
+        visitor.visit_procedure(procedure)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Visits all local procedures of the program followed by the program's body.
+pub fn visit_program<V, T>(visitor: &mut V, program: &ProgramAst) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    for procedure in program.procedures() {
+        visitor.visit_procedure(procedure)?;
+    }
+    visitor.visit_block(program.body())
+}
+
+pub fn visit_procedure<V, T>(visitor: &mut V, procedure: &ProcedureAst) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    visitor.visit_block(&procedure.body)
+}
+
+/// Visits all nodes of the block, passing along their source locations when present.
+pub fn visit_block<V, T>(visitor: &mut V, block: &CodeBody) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    let locations = block.source_locations();
+    for (idx, node) in block.nodes().iter().enumerate() {
+        visitor.visit_node(node, locations.get(idx))?;
+    }
+    ControlFlow::Continue(())
+}
+
+pub fn visit_node<V, T>(
+    visitor: &mut V,
+    node: &Node,
+    location: Option<&SourceLocation>,
+) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    match node {
+        Node::Instruction(inst) => visitor.visit_inst(inst, location),
+        Node::IfElse {
+            true_case,
+            false_case,
+        } => {
+            visitor.visit_block(true_case)?;
+            visitor.visit_block(false_case)
+        }
+        Node::Repeat { body, .. } | Node::While { body } => visitor.visit_block(body),
+    }
+}
+
+/// Visits the immediate values of the instruction, if any.
+pub fn visit_inst<V, T>(
+    visitor: &mut V,
+    inst: &Instruction,
+    _location: Option<&SourceLocation>,
+) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    use Instruction::*;
+
+    match inst {
+        AssertWithError(code)
+        | AssertEqWithError(code)
+        | AssertEqwWithError(code)
+        | AssertzWithError(code)
+        | U32AssertWithError(code)
+        | U32Assert2WithError(code)
+        | U32AssertWWithError(code) => visitor.visit_immediate_error_code(code),
+        AddImm(imm) | SubImm(imm) | MulImm(imm) | DivImm(imm) | ExpImm(imm) | EqImm(imm)
+        | NeqImm(imm) | PushFelt(imm) => visitor.visit_immediate_felt(imm),
+        ExpBitLength(imm) | U32ShrImm(imm) | U32ShlImm(imm) | U32RotrImm(imm) | U32RotlImm(imm)
+        | PushU8(imm) | AdvPush(imm) => visitor.visit_immediate_u8(imm),
+        PushU16(imm) | Locaddr(imm) | LocLoad(imm) | LocLoadW(imm) | LocStore(imm)
+        | LocStoreW(imm) => visitor.visit_immediate_u16(imm),
+        U32WrappingAddImm(imm)
+        | U32OverflowingAddImm(imm)
+        | U32WrappingSubImm(imm)
+        | U32OverflowingSubImm(imm)
+        | U32WrappingMulImm(imm)
+        | U32OverflowingMulImm(imm)
+        | U32DivImm(imm)
+        | U32ModImm(imm)
+        | U32DivModImm(imm)
+        | PushU32(imm)
+        | MemLoadImm(imm)
+        | MemLoadWImm(imm)
+        | MemStoreImm(imm)
+        | MemStoreWImm(imm)
+        | Emit(imm)
+        | Trace(imm) => visitor.visit_immediate_u32(imm),
+        PushWord(values) => values.iter().try_for_each(|imm| visitor.visit_immediate_felt(imm)),
+        PushFeltList(values) => values.iter().try_for_each(|imm| visitor.visit_immediate_felt(imm)),
+        PushU8List(values) => values.iter().try_for_each(|imm| visitor.visit_immediate_u8(imm)),
+        PushU16List(values) => values.iter().try_for_each(|imm| visitor.visit_immediate_u16(imm)),
+        PushU32List(values) => values.iter().try_for_each(|imm| visitor.visit_immediate_u32(imm)),
+        ExecLocal(index) => visitor.visit_exec(Callee::Local(*index)),
+        ExecImported(proc_id) => visitor.visit_exec(Callee::Imported(proc_id)),
+        CallLocal(index) => visitor.visit_call(Callee::Local(*index)),
+        CallImported(proc_id) => visitor.visit_call(Callee::Imported(proc_id)),
+        CallMastRoot(root) => visitor.visit_call(Callee::MastRoot(root)),
+        SysCall(callee) => visitor.visit_syscall(callee),
+        ProcRefLocal(index) => visitor.visit_procref(Callee::Local(*index)),
+        ProcRefImported(proc_id) => visitor.visit_procref(Callee::Imported(proc_id)),
+        AdvInject(injector) => visitor.visit_advice_injector(injector),
+        Debug(options) => visitor.visit_debug_options(options),
+        _ => ControlFlow::Continue(()),
+    }
+}
+
+#[inline(always)]
+pub fn visit_exec<V, T>(_visitor: &mut V, _callee: Callee) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+#[inline(always)]
+pub fn visit_call<V, T>(_visitor: &mut V, _callee: Callee) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+#[inline(always)]
+pub fn visit_syscall<V, T>(_visitor: &mut V, _callee: &ProcedureId) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+#[inline(always)]
+pub fn visit_procref<V, T>(_visitor: &mut V, _callee: Callee) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+pub fn visit_advice_injector<V, T>(visitor: &mut V, injector: &AdviceInjectorNode) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    match injector {
+        AdviceInjectorNode::PushMapValImm { offset }
+        | AdviceInjectorNode::PushMapValNImm { offset }
+        | AdviceInjectorNode::PushMapValLenImm { offset } => visitor.visit_immediate_u8(offset),
+        AdviceInjectorNode::InsertHdwordImm { domain } => visitor.visit_immediate_u8(domain),
+        _ => ControlFlow::Continue(()),
+    }
+}
+
+pub fn visit_debug_options<V, T>(visitor: &mut V, options: &DebugOptions) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    match options {
+        DebugOptions::StackTop(n) => visitor.visit_immediate_u16(n),
+        DebugOptions::MemInterval(start, end) => {
+            visitor.visit_immediate_u32(start)?;
+            visitor.visit_immediate_u32(end)
+        }
+        DebugOptions::MemIntervalCtx(ctx, start, end) => {
+            visitor.visit_immediate_u32(ctx)?;
+            visitor.visit_immediate_u32(start)?;
+            visitor.visit_immediate_u32(end)
+        }
+        DebugOptions::LocalInterval(start, end, _) => {
+            visitor.visit_immediate_u16(start)?;
+            visitor.visit_immediate_u16(end)
+        }
+        DebugOptions::Checkpoint(label) => visitor.visit_immediate_u32(label),
+        DebugOptions::StackAll | DebugOptions::MemAll => ControlFlow::Continue(()),
+    }
+}
+
+#[inline(always)]
+pub fn visit_immediate_u8<V, T>(_visitor: &mut V, _imm: &u8) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+#[inline(always)]
+pub fn visit_immediate_u16<V, T>(_visitor: &mut V, _imm: &u16) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+#[inline(always)]
+pub fn visit_immediate_u32<V, T>(_visitor: &mut V, _imm: &u32) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+#[inline(always)]
+pub fn visit_immediate_felt<V, T>(_visitor: &mut V, _imm: &Felt) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    ControlFlow::Continue(())
+}
+
+/// Error codes are `u32` values, and by default are visited as such.
+#[inline(always)]
+pub fn visit_immediate_error_code<V, T>(visitor: &mut V, code: &u32) -> ControlFlow<T>
+where
+    V: ?Sized + Visit<T>,
+{
+    visitor.visit_immediate_u32(code)
+}
+
+// MUTABLE VISITOR
+// ================================================================================================
+
+/// A visitor over the nodes of a Miden assembly AST which can modify the visited nodes in place.
+///
+/// The type parameter `T` is the type of value carried by [ControlFlow::Break] when a visitor
+/// terminates the traversal early.
+pub trait VisitMut<T = ()> {
+    fn visit_mut_module(&mut self, module: &mut ModuleAst) -> ControlFlow<T> {
+        visit_mut_module(self, module)
+    }
+
+    fn visit_mut_program(&mut self, program: &mut ProgramAst) -> ControlFlow<T> {
+        visit_mut_program(self, program)
+    }
+
+    fn visit_mut_procedure(&mut self, procedure: &mut ProcedureAst) -> ControlFlow<T> {
+        visit_mut_procedure(self, procedure)
+    }
+
+    fn visit_mut_block(&mut self, block: &mut CodeBody) -> ControlFlow<T> {
+        visit_mut_block(self, block)
+    }
+
+    /// Visits a single node; `location` is the source location of the node, if known.
+    fn visit_mut_node(
+        &mut self,
+        node: &mut Node,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<T> {
+        visit_mut_node(self, node, location)
+    }
+
+    /// Visits a single instruction; `location` is the source location of the instruction, if
+    /// known.
+    fn visit_mut_inst(
+        &mut self,
+        inst: &mut Instruction,
+        location: Option<&SourceLocation>,
+    ) -> ControlFlow<T> {
+        visit_mut_inst(self, inst, location)
+    }
+}
+
+// DEFAULT MUTABLE TRAVERSAL
+// ================================================================================================
+
+/// Visits all local procedures of the module in the order of their indexes.
+pub fn visit_mut_module<V, T>(visitor: &mut V, module: &mut ModuleAst) -> ControlFlow<T>
+where
+    V: ?Sized + VisitMut<T>,
+{
+    for procedure in module.local_procs.iter_mut() {
+        visitor.visit_mut_procedure(procedure)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Visits all local procedures of the program followed by the program's body.
+pub fn visit_mut_program<V, T>(visitor: &mut V, program: &mut ProgramAst) -> ControlFlow<T>
+where
+    V: ?Sized + VisitMut<T>,
+{
+    for procedure in program.local_procs.iter_mut() {
+        visitor.visit_mut_procedure(procedure)?;
+    }
+    visitor.visit_mut_block(&mut program.body)
+}
+
+pub fn visit_mut_procedure<V, T>(visitor: &mut V, procedure: &mut ProcedureAst) -> ControlFlow<T>
+where
+    V: ?Sized + VisitMut<T>,
+{
+    visitor.visit_mut_block(&mut procedure.body)
+}
+
+/// Visits all nodes of the block, passing along their source locations when present.
+pub fn visit_mut_block<V, T>(visitor: &mut V, block: &mut CodeBody) -> ControlFlow<T>
+where
+    V: ?Sized + VisitMut<T>,
+{
+    let (nodes, locations) = block.nodes_mut();
+    for (idx, node) in nodes.iter_mut().enumerate() {
+        visitor.visit_mut_node(node, locations.get(idx))?;
+    }
+    ControlFlow::Continue(())
+}
+
+pub fn visit_mut_node<V, T>(
+    visitor: &mut V,
+    node: &mut Node,
+    location: Option<&SourceLocation>,
+) -> ControlFlow<T>
+where
+    V: ?Sized + VisitMut<T>,
+{
+    match node {
+        Node::Instruction(inst) => visitor.visit_mut_inst(inst, location),
+        Node::IfElse {
+            true_case,
+            false_case,
+        } => {
+            visitor.visit_mut_block(true_case)?;
+            visitor.visit_mut_block(false_case)
+        }
+        Node::Repeat { body, .. } | Node::While { body } => visitor.visit_mut_block(body),
+    }
+}
+
+#[inline(always)]
+pub fn visit_mut_inst<V, T>(
+    _visitor: &mut V,
+    _inst: &mut Instruction,
+    _location: Option<&SourceLocation>,
+) -> ControlFlow<T>
+where
+    V: ?Sized + VisitMut<T>,
+{
+    ControlFlow::Continue(())
+}
@@ -1,8 +1,11 @@
 use super::{
-    ast::ProcReExport, crypto::hash::RpoDigest, tokens::SourceLocation, KernelError,
-    LibraryNamespace, ProcedureId, ProcedureName, Token,
+    ast::{Instruction, ProcReExport},
+    crypto::hash::RpoDigest,
+    tokens::SourceLocation,
+    KernelError, LibraryNamespace, ProcedureId, ProcedureName, Token,
 };
 use alloc::{
+    boxed::Box,
     string::{String, ToString},
     vec::Vec,
 };
@@ -22,10 +25,12 @@ pub enum AssemblyError {
     DivisionByZero,
     DuplicateProcId(ProcedureId),
     DuplicateProcName(String, String),
+    DynamicExecNotAllowed(String, Option<SourceLocation>),
     ExportedProcInProgram(String),
     ImportedProcModuleNotFound(ProcedureId, String),
     ImportedProcNotFoundInModule(ProcedureId, String),
     InvalidCacheLock,
+    InvalidKernelProcedures(Vec<ValidationError>),
     InvalidProgramAssemblyContext,
     Io(String),
     KernelError(KernelError),
@@ -36,7 +41,7 @@ pub enum AssemblyError {
     ParsingError(String),
     PhantomCallsNotAllowed(RpoDigest),
     ProcedureNameError(String),
-    ReExportedProcModuleNotFound(ProcReExport),
+    ReExportedProcModuleNotFound(Box<ProcReExport>),
     SysCallInKernel(String),
 }
 
@@ -72,6 +77,13 @@ impl AssemblyError {
         Self::DuplicateProcId(*proc_id)
     }
 
+    pub fn dynamic_exec_not_allowed(
+        instruction: &Instruction,
+        location: Option<&SourceLocation>,
+    ) -> Self {
+        Self::DynamicExecNotAllowed(instruction.to_string(), location.copied())
+    }
+
     pub fn exported_proc_in_program(proc_name: &str) -> Self {
         Self::ExportedProcInProgram(proc_name.to_string())
     }
@@ -91,6 +103,10 @@ impl AssemblyError {
         Self::ImportedProcNotFoundInModule(*proc_id, module_path.to_string())
     }
 
+    pub fn invalid_kernel_procedures(errors: Vec<ValidationError>) -> Self {
+        Self::InvalidKernelProcedures(errors)
+    }
+
     pub fn kernel_proc_not_found(kernel_proc_id: &ProcedureId) -> Self {
         Self::KernelProcNotFound(*kernel_proc_id)
     }
@@ -146,10 +162,16 @@ impl fmt::Display for AssemblyError {
             DivisionByZero => write!(f, "division by zero"),
             DuplicateProcId(proc_id) => write!(f, "duplicate proc id {proc_id}"),
             DuplicateProcName(proc_name, module_path) => write!(f, "duplicate proc name '{proc_name}' in module {module_path}"),
+            DynamicExecNotAllowed(op, Some(location)) => write!(f, "instruction '{op}' at {location} is not allowed: dynamic execution is disabled"),
+            DynamicExecNotAllowed(op, None) => write!(f, "instruction '{op}' is not allowed: dynamic execution is disabled"),
             ExportedProcInProgram(proc_name) => write!(f, "exported procedure '{proc_name}' in executable program"),
             ImportedProcModuleNotFound(proc_id, proc_name) => write!(f, "module for imported procedure `{proc_name}` with ID {proc_id} not found"),
             ImportedProcNotFoundInModule(proc_id, module_path) => write!(f, "imported procedure {proc_id} not found in module {module_path}"),
             InvalidCacheLock => write!(f, "an attempt was made to lock a borrowed procedures cache"),
+            InvalidKernelProcedures(errors) => {
+                let errors = errors.iter().map(|err| err.to_string()).collect::<Vec<_>>();
+                write!(f, "invalid kernel procedures: {}", errors.join("; "))
+            }
             InvalidProgramAssemblyContext => write!(f, "assembly context improperly initialized for program compilation"),
             Io(description) => write!(f, "I/O error: {description}"),
             KernelError(error) => write!(f, "{}", error),
@@ -661,7 +683,7 @@ impl ParsingError {
         &self.message
     }
 
-    pub fn operation(&self) -> &String {
+    pub fn operation(&self) -> &str {
         &self.op
     }
 
@@ -1039,3 +1061,281 @@ impl fmt::Display for PathError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for PathError {}
+
+// VALIDATION ERROR
+// ================================================================================================
+
+/// An instruction rejected by one of the validation passes over a module or a program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    kind: ValidationErrorKind,
+    op: String,
+    location: Option<SourceLocation>,
+}
+
+/// An out-of-range immediate reported by the immediate validation pass.
+///
+/// Immediate errors are validation errors of kind [ValidationErrorKind::ImmediateOutOfRange].
+pub type ImmediateError = ValidationError;
+
+/// The reason an instruction was rejected by a validation pass.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationErrorKind {
+    /// An immediate value outside of the range of values accepted by the instruction.
+    ImmediateOutOfRange { value: u64, min: u64, max: u64 },
+    /// An instruction in a kernel procedure which cannot be executed within a `syscall`.
+    InvalidKernelInstruction(ProcedureName),
+    /// An access to a procedure local beyond the number of locals declared by the procedure.
+    LocalOutOfBounds { index: u16, num_locals: u16 },
+    /// A reference to a local procedure which is not defined in the module.
+    UnresolvedLocalProcedure(u16),
+    /// A `syscall` to a procedure which is not exported from the kernel.
+    UnresolvedSyscallTarget(ProcedureId),
+}
+
+impl ValidationError {
+    pub fn immediate_out_of_range(
+        op: &Instruction,
+        value: u64,
+        min: u64,
+        max: u64,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self::new(ValidationErrorKind::ImmediateOutOfRange { value, min, max }, op, location)
+    }
+
+    pub fn invalid_kernel_instruction(
+        proc_name: &ProcedureName,
+        op: &Instruction,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self::new(ValidationErrorKind::InvalidKernelInstruction(proc_name.clone()), op, location)
+    }
+
+    pub fn local_out_of_bounds(
+        op: &Instruction,
+        index: u16,
+        num_locals: u16,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self::new(ValidationErrorKind::LocalOutOfBounds { index, num_locals }, op, location)
+    }
+
+    pub fn unresolved_local_procedure(
+        op: &Instruction,
+        index: u16,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self::new(ValidationErrorKind::UnresolvedLocalProcedure(index), op, location)
+    }
+
+    pub fn unresolved_syscall_target(
+        op: &Instruction,
+        proc_id: &ProcedureId,
+        location: Option<SourceLocation>,
+    ) -> Self {
+        Self::new(ValidationErrorKind::UnresolvedSyscallTarget(*proc_id), op, location)
+    }
+
+    fn new(kind: ValidationErrorKind, op: &Instruction, location: Option<SourceLocation>) -> Self {
+        Self {
+            kind,
+            op: op.to_string(),
+            location,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    pub const fn kind(&self) -> &ValidationErrorKind {
+        &self.kind
+    }
+
+    pub fn operation(&self) -> &str {
+        &self.op
+    }
+
+    pub const fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_ref()
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ValidationErrorKind::*;
+        write!(f, "'{}' ", self.op)?;
+        if let Some(location) = self.location {
+            write!(f, "at {location} ")?;
+        }
+        match &self.kind {
+            ImmediateOutOfRange { value, min, max } => {
+                write!(f, "has immediate value {value} outside of the valid range [{min}, {max}]")
+            }
+            InvalidKernelInstruction(proc_name) => {
+                write!(f, "in kernel procedure '{proc_name}' cannot be executed within a syscall")
+            }
+            LocalOutOfBounds { index, num_locals } => write!(
+                f,
+                "accesses local {index} which is out of bounds for a procedure with {num_locals} locals"
+            ),
+            UnresolvedLocalProcedure(index) => {
+                write!(f, "refers to local procedure {index} which is not defined in the module")
+            }
+            UnresolvedSyscallTarget(proc_id) => {
+                write!(f, "invokes {proc_id} which is not exported from the kernel")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValidationError {}
+
+// STACK DEPTH WARNING
+// ================================================================================================
+
+/// A procedure invoked via `call` which returns with a stack depth other than 16.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackDepthWarning {
+    name: ProcedureName,
+    depth: usize,
+    location: SourceLocation,
+}
+
+impl StackDepthWarning {
+    pub fn new(name: ProcedureName, depth: usize, location: SourceLocation) -> Self {
+        Self {
+            name,
+            depth,
+            location,
+        }
+    }
+
+    pub const fn name(&self) -> &ProcedureName {
+        &self.name
+    }
+
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub const fn location(&self) -> &SourceLocation {
+        &self.location
+    }
+}
+
+impl fmt::Display for StackDepthWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "procedure {} at {} is invoked via call, but returns with stack depth {} instead of 16",
+            self.name, self.location, self.depth
+        )
+    }
+}
+
+// STACK UNDERFLOW WARNING
+// ================================================================================================
+
+/// A procedure which reads more items from the stack than the 16 items it is guaranteed to
+/// receive, and thus relies on the stack overflow table or on the padding zeros.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackUnderflowWarning {
+    name: ProcedureName,
+    depth: usize,
+    location: Option<SourceLocation>,
+}
+
+impl StackUnderflowWarning {
+    pub fn new(name: ProcedureName, depth: usize, location: Option<SourceLocation>) -> Self {
+        Self {
+            name,
+            depth,
+            location,
+        }
+    }
+
+    pub const fn name(&self) -> &ProcedureName {
+        &self.name
+    }
+
+    /// Returns the minimum depth of the stack required by the procedure when it is invoked.
+    pub const fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns the source location of the first instruction which reads below the top 16 items
+    /// of the stack, if known.
+    pub const fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_ref()
+    }
+}
+
+impl fmt::Display for StackUnderflowWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "procedure {} ", self.name)?;
+        if let Some(location) = self.location {
+            write!(f, "reads below the top 16 stack items at {location} ")?;
+        } else {
+            write!(f, "reads below the top 16 stack items ")?;
+        }
+        write!(f, "and requires a stack depth of at least {} on entry", self.depth)
+    }
+}
+
+// REDUNDANT OPERATION WARNING
+// ================================================================================================
+
+/// A pair of adjacent instructions whose effects on the stack cancel each other out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RedundantOpWarning {
+    first: Instruction,
+    second: Instruction,
+    start: Option<SourceLocation>,
+    end: Option<SourceLocation>,
+}
+
+impl RedundantOpWarning {
+    pub fn new(
+        first: Instruction,
+        second: Instruction,
+        start: Option<SourceLocation>,
+        end: Option<SourceLocation>,
+    ) -> Self {
+        Self {
+            first,
+            second,
+            start,
+            end,
+        }
+    }
+
+    pub const fn first(&self) -> &Instruction {
+        &self.first
+    }
+
+    pub const fn second(&self) -> &Instruction {
+        &self.second
+    }
+
+    /// Returns the source location of the first instruction of the pair, if known.
+    pub const fn start(&self) -> Option<&SourceLocation> {
+        self.start.as_ref()
+    }
+
+    /// Returns the source location of the second instruction of the pair, if known.
+    pub const fn end(&self) -> Option<&SourceLocation> {
+        self.end.as_ref()
+    }
+}
+
+impl fmt::Display for RedundantOpWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "'{}' followed by '{}' ", self.first, self.second)?;
+        if let (Some(start), Some(end)) = (self.start, self.end) {
+            write!(f, "at {start}..{end} ")?;
+        }
+        write!(f, "leaves the stack unchanged and can be removed")
+    }
+}
@@ -30,10 +30,14 @@ mod tokens;
 use tokens::{Token, TokenStream};
 
 mod errors;
-pub use errors::{AssemblyError, LabelError, LibraryError, ParsingError, PathError};
+pub use errors::{
+    AssemblyError, ImmediateError, LabelError, LibraryError, ParsingError, PathError,
+    RedundantOpWarning, StackDepthWarning, StackUnderflowWarning, ValidationError,
+    ValidationErrorKind,
+};
 
 mod assembler;
-pub use assembler::{Assembler, AssemblyContext};
+pub use assembler::{instruction_cost, Assembler, AssemblyContext, RootCache};
 
 #[cfg(test)]
 mod tests;
@@ -1,5 +1,5 @@
 use crate::{
-    ast::{ModuleAst, ProgramAst},
+    ast::{AdviceInjectorNode, Instruction, ModuleAst, Node, ProgramAst, SourceLocation},
     Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath, MaslLibrary,
     Module, ProcedureName, Version,
 };
@@ -694,6 +694,69 @@ fn mem_operations_with_constants() {
     assert_eq!(expected_program.to_string(), program.to_string());
 }
 
+#[test]
+fn adv_insert_hdword_with_constants() {
+    let source = "\
+    const.DOMAIN=10
+
+    begin
+        adv.insert_hdword.DOMAIN
+    end
+    ";
+    let program = ProgramAst::parse(source).unwrap();
+    let expected = Node::Instruction(Instruction::AdvInject(AdviceInjectorNode::InsertHdwordImm {
+        domain: 10,
+    }));
+    assert_eq!(program.body().nodes(), [expected]);
+
+    // an undefined constant results in an error
+    let source = "begin adv.insert_hdword.UNDEFINED end";
+    let err = Assembler::default().compile(source).err().unwrap();
+    let expected_error = "constant used in operation `adv.insert_hdword.UNDEFINED` not found";
+    assert_eq!(expected_error, err.to_string());
+}
+
+#[test]
+fn adv_insert_hdword_default_domain() {
+    // the domain is resolved by the executor only if it is omitted
+    let source = "begin adv.insert_hdword adv.insert_hdword.0 adv.insert_hdword.2 end";
+    let program = ProgramAst::parse(source).unwrap();
+    let injectors = [
+        AdviceInjectorNode::InsertHdwordDefault,
+        AdviceInjectorNode::InsertHdword,
+        AdviceInjectorNode::InsertHdwordImm { domain: 2 },
+    ];
+    let expected = injectors.map(|injector| Node::Instruction(Instruction::AdvInject(injector)));
+    assert_eq!(program.body().nodes(), expected);
+
+    // the injectors are displayed as they were parsed
+    let displayed = injectors.map(|injector| injector.to_string());
+    assert_eq!(displayed, ["insert_hdword", "insert_hdword.0", "insert_hdword.2"]);
+}
+
+#[test]
+fn const_conversion_failed_to_u8() {
+    // Define constant value greater than u8::MAX
+    let constant_value: u64 = u8::MAX as u64 + 1;
+
+    let source = format!(
+        "\
+    const.CONSTANT={constant_value}
+
+    begin
+        adv.insert_hdword.CONSTANT
+    end
+    "
+    );
+    let assembler = Assembler::default();
+    let result = assembler.compile(source);
+    assert!(result.is_err());
+    let err = result.err().unwrap();
+    let expected_error =
+        "failed to convert u64 constant used in `adv.insert_hdword.CONSTANT` to required type u8";
+    assert_eq!(expected_error, err.to_string());
+}
+
 #[test]
 fn const_conversion_failed_to_u16() {
     // Define constant value greater than u16::MAX
@@ -1045,6 +1108,35 @@ fn program_with_dynamic_code_execution_in_new_context() {
     assert_eq!(expected, format!("{program}"));
 }
 
+#[test]
+fn program_with_dynamic_code_execution_disabled() {
+    let assembler = super::Assembler::default().with_dynamic_exec(false);
+
+    let source = "begin push.1 dynexec end";
+    let err = assembler.compile(source).unwrap_err();
+    let expected = AssemblyError::DynamicExecNotAllowed(
+        "dynexec".to_string(),
+        Some(SourceLocation::new(1, 14)),
+    );
+    assert_eq!(expected, err);
+    assert_eq!(
+        "instruction 'dynexec' at [1:14] is not allowed: dynamic execution is disabled",
+        err.to_string()
+    );
+
+    let source = "proc.foo dyncall end begin exec.foo end";
+    let err = assembler.compile(source).unwrap_err();
+    let expected = AssemblyError::DynamicExecNotAllowed(
+        "dyncall".to_string(),
+        Some(SourceLocation::new(1, 10)),
+    );
+    assert_eq!(expected, err);
+
+    // programs without dynamic code blocks are not affected
+    let source = "begin push.1 push.2 add end";
+    assert!(assembler.compile(source).is_ok());
+}
+
 // MAST ROOT CALLS
 // ================================================================================================
 
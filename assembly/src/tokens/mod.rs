@@ -1,7 +1,6 @@
 use super::{
     ast::{parse_param_with_constant_lookup, InvocationTarget},
-    ByteReader, ByteWriter, Deserializable, DeserializationError, LibraryPath, ParsingError,
-    ProcedureName, Serializable,
+    LibraryPath, ParsingError, ProcedureName,
 };
 use alloc::{
     collections::BTreeMap,
@@ -13,8 +12,7 @@ use core::fmt;
 mod lines;
 pub use lines::{LineInfo, LinesStream};
 
-mod location;
-pub use location::SourceLocation;
+pub use vm_core::SourceLocation;
 
 mod stream;
 pub use stream::TokenStream;
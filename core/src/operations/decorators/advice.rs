@@ -1,5 +1,8 @@
 use super::SignatureKind;
-use crate::Felt;
+use crate::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Felt,
+};
 use core::fmt;
 
 // ADVICE INJECTORS
@@ -11,8 +14,8 @@ use core::fmt;
 /// These actions can affect all 3 components of the advice provider: Merkle store, advice stack,
 /// and advice map.
 ///
-/// All actions, except for `MerkleNodeMerge`, `Ext2Inv` and `UpdateMerkleNode` can be invoked
-/// directly from Miden assembly via dedicated instructions.
+/// All actions, except for `MerkleNodeMerge`, `MerkleRootCheck`, `Ext2Inv` and `UpdateMerkleNode`
+/// can be invoked directly from Miden assembly via dedicated instructions.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum AdviceInjector {
     // MERKLE STORE INJECTORS
@@ -32,6 +35,21 @@ pub enum AdviceInjector {
     /// provider (i.e., the input trees are not removed).
     MerkleNodeMerge,
 
+    /// Checks that a Merkle tree with the root specified by the word at the top of the operand
+    /// stack is present in the advice provider.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Merkle store: {ROOT}
+    ///
+    /// Outputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Merkle store: {ROOT}
+    ///
+    /// The operation fails if a Merkle tree with the specified root cannot be found in the advice
+    /// provider.
+    MerkleRootCheck,
+
     // ADVICE STACK INJECTORS
     // --------------------------------------------------------------------------------------------
     /// Pushes a node of the Merkle tree specified by the values on the top of the operand stack
@@ -48,6 +66,18 @@ pub enum AdviceInjector {
     ///   Merkle store: {TREE_ROOT<-NODE}
     MerkleNodeToStack,
 
+    /// Pushes ONE onto the advice stack if a Merkle tree with the root specified by the word at
+    /// the top of the operand stack is present in the advice provider, and ZERO otherwise.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Advice stack: [has_root, ...]
+    HasMerkleRoot,
+
     /// Updates the node of a Merkle tree specified by the values at the top of the operand stack.
     /// Returns the path from the updated node to the new root of the tree to the caller.
     ///
@@ -88,6 +118,40 @@ pub enum AdviceInjector {
         key_offset: usize,
     },
 
+    /// Pushes the number of elements in a list of field elements onto the advice stack. The list
+    /// is looked up in the advice map using the specified word from the operand stack as the key;
+    /// the elements of the list themselves are not pushed.
+    ///
+    /// Inputs:
+    ///   Operand stack: [..., KEY, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {KEY: values}
+    ///
+    /// Outputs:
+    ///   Operand stack: [..., KEY, ...]
+    ///   Advice stack: [values_len, ...]
+    ///   Advice map: {KEY: values}
+    ///
+    /// The `key_offset` value specifies the location of the `KEY` on the stack in the same way as
+    /// for the map value injector, i.e., the valid values of `key_offset` are 0 through 12
+    /// (inclusive).
+    MapValueLenToStack { key_offset: usize },
+
+    /// Reads words from memory at the specified range and pushes their elements onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [start_addr, end_addr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [start_addr, end_addr, ...]
+    ///   Advice stack: [values, ...]
+    ///
+    /// Where `values` are the elements located in memory[start_addr..end_addr], placed such that
+    /// the first element of the word at `start_addr` is read from the advice stack first.
+    MemToStack,
+
     /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
     /// stack.
     ///
@@ -250,6 +314,14 @@ pub enum AdviceInjector {
     /// value.
     HdwordToMap { domain: Felt },
 
+    /// Reads two words from the operand stack and inserts them into the advice map under the key
+    /// defined by the hash of these words, using the default domain of the executor.
+    ///
+    /// This is the same as [AdviceInjector::HdwordToMap], except that the domain is not provided
+    /// via an immediate value, but is taken from the execution options at execution time (ZERO
+    /// unless configured otherwise).
+    HdwordToMapDefault,
+
     /// Reads three words from the operand stack and inserts the top two words into the advice map
     /// under the key defined by applying an RPO permutation to all three words.
     ///
@@ -285,7 +357,9 @@ impl fmt::Display for AdviceInjector {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::MerkleNodeMerge => write!(f, "merkle_node_merge"),
+            Self::MerkleRootCheck => write!(f, "merkle_root_check"),
             Self::MerkleNodeToStack => write!(f, "merkle_node_to_stack"),
+            Self::HasMerkleRoot => write!(f, "has_merkle_root"),
             Self::UpdateMerkleNode => {
                 write!(f, "update_merkle_node")
             }
@@ -299,6 +373,10 @@ impl fmt::Display for AdviceInjector {
                     write!(f, "map_value_to_stack.{key_offset}")
                 }
             }
+            Self::MapValueLenToStack { key_offset } => {
+                write!(f, "map_value_len_to_stack.{key_offset}")
+            }
+            Self::MemToStack => write!(f, "mem_to_stack"),
             Self::U64Div => write!(f, "div_u64"),
             Self::Ext2Inv => write!(f, "ext2_inv"),
             Self::Ext2Intt => write!(f, "ext2_intt"),
@@ -312,8 +390,106 @@ impl fmt::Display for AdviceInjector {
             Self::ILog2 => write!(f, "ilog2"),
             Self::MemToMap => write!(f, "mem_to_map"),
             Self::HdwordToMap { domain } => write!(f, "hdword_to_map.{domain}"),
+            Self::HdwordToMapDefault => write!(f, "hdword_to_map_default"),
             Self::HpermToMap => write!(f, "hperm_to_map"),
             Self::SigToStack { kind } => write!(f, "sig_to_stack.{kind}"),
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceInjector {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::MerkleNodeMerge => target.write_u8(0),
+            Self::MerkleRootCheck => target.write_u8(1),
+            Self::MerkleNodeToStack => target.write_u8(2),
+            Self::HasMerkleRoot => target.write_u8(3),
+            Self::UpdateMerkleNode => target.write_u8(4),
+            Self::MapValueToStack {
+                include_len,
+                key_offset,
+            } => {
+                target.write_u8(5);
+                target.write_bool(*include_len);
+                target.write_usize(*key_offset);
+            }
+            Self::MemToStack => target.write_u8(6),
+            Self::U64Div => target.write_u8(7),
+            Self::Ext2Inv => target.write_u8(8),
+            Self::Ext2Intt => target.write_u8(9),
+            Self::SmtGet => target.write_u8(10),
+            Self::SmtSet => target.write_u8(11),
+            Self::SmtPeek => target.write_u8(12),
+            Self::U32Clz => target.write_u8(13),
+            Self::U32Ctz => target.write_u8(14),
+            Self::U32Clo => target.write_u8(15),
+            Self::U32Cto => target.write_u8(16),
+            Self::ILog2 => target.write_u8(17),
+            Self::MemToMap => target.write_u8(18),
+            Self::HdwordToMap { domain } => {
+                target.write_u8(19);
+                domain.write_into(target);
+            }
+            Self::HpermToMap => target.write_u8(20),
+            Self::SigToStack { kind } => {
+                target.write_u8(21);
+                kind.write_into(target);
+            }
+            Self::MapValueLenToStack { key_offset } => {
+                target.write_u8(22);
+                target.write_usize(*key_offset);
+            }
+            Self::HdwordToMapDefault => target.write_u8(23),
+        }
+    }
+}
+
+impl Deserializable for AdviceInjector {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tag = source.read_u8()?;
+        let injector = match tag {
+            0 => Self::MerkleNodeMerge,
+            1 => Self::MerkleRootCheck,
+            2 => Self::MerkleNodeToStack,
+            3 => Self::HasMerkleRoot,
+            4 => Self::UpdateMerkleNode,
+            5 => Self::MapValueToStack {
+                include_len: source.read_bool()?,
+                key_offset: source.read_usize()?,
+            },
+            6 => Self::MemToStack,
+            7 => Self::U64Div,
+            8 => Self::Ext2Inv,
+            9 => Self::Ext2Intt,
+            10 => Self::SmtGet,
+            11 => Self::SmtSet,
+            12 => Self::SmtPeek,
+            13 => Self::U32Clz,
+            14 => Self::U32Ctz,
+            15 => Self::U32Clo,
+            16 => Self::U32Cto,
+            17 => Self::ILog2,
+            18 => Self::MemToMap,
+            19 => Self::HdwordToMap {
+                domain: Felt::read_from(source)?,
+            },
+            20 => Self::HpermToMap,
+            21 => Self::SigToStack {
+                kind: SignatureKind::read_from(source)?,
+            },
+            22 => Self::MapValueLenToStack {
+                key_offset: source.read_usize()?,
+            },
+            23 => Self::HdwordToMapDefault,
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid advice injector tag: {tag}"
+                )))
+            }
+        };
+        Ok(injector)
+    }
+}
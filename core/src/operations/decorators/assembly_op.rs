@@ -1,3 +1,5 @@
+use super::SourceLocation;
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::string::String;
 use core::fmt;
 
@@ -11,6 +13,7 @@ pub struct AssemblyOp {
     num_cycles: u8,
     op: String,
     should_break: bool,
+    location: Option<SourceLocation>,
 }
 
 impl AssemblyOp {
@@ -22,9 +25,16 @@ impl AssemblyOp {
             num_cycles,
             op,
             should_break,
+            location: None,
         }
     }
 
+    /// Associates the provided source location with this operation.
+    pub fn with_location(mut self, location: SourceLocation) -> Self {
+        self.location = Some(location);
+        self
+    }
+
     /// Returns the context name for this operation.
     pub fn context_name(&self) -> &str {
         &self.context_name
@@ -40,6 +50,11 @@ impl AssemblyOp {
         &self.op
     }
 
+    /// Returns the location of the assembly instruction in the source code, if known.
+    pub const fn location(&self) -> Option<&SourceLocation> {
+        self.location.as_ref()
+    }
+
     /// Returns `true` if there is a breakpoint for the current operation.
     pub const fn should_break(&self) -> bool {
         self.should_break
@@ -63,3 +78,28 @@ impl fmt::Display for AssemblyOp {
         )
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AssemblyOp {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.context_name.write_into(target);
+        target.write_u8(self.num_cycles);
+        self.op.write_into(target);
+        target.write_bool(self.should_break);
+        self.location.write_into(target);
+    }
+}
+
+impl Deserializable for AssemblyOp {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            context_name: String::read_from(source)?,
+            num_cycles: source.read_u8()?,
+            op: String::read_from(source)?,
+            should_break: source.read_bool()?,
+            location: Option::<SourceLocation>::read_from(source)?,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use core::fmt;
 
 // DEBUG OPTIONS
@@ -20,12 +21,22 @@ pub enum DebugOptions {
     ///
     /// First parameter specifies the interval starting address, second -- the ending address.
     MemInterval(u32, u32),
+    /// Prints out the contents of memory stored in the provided interval of the specified
+    /// execution context. Interval boundaries are both inclusive.
+    ///
+    /// First parameter specifies the ID of the execution context, second -- the interval starting
+    /// address, and the third -- the ending address.
+    MemIntervalCtx(u32, u32, u32),
     /// Prints out locals stored in the provided interval of the currently executing procedure.
     /// Interval boundaries are both inclusive.
     ///
     /// First parameter specifies the starting address, second -- the ending address, and the third
     /// specifies the overall number of locals.
     LocalInterval(u16, u16, u16),
+    /// Records the top 16 items of the stack under the provided label, rather than printing them.
+    ///
+    /// The recorded stack states are available from the result of the program execution.
+    Checkpoint(u32),
 }
 
 impl fmt::Display for DebugOptions {
@@ -35,9 +46,69 @@ impl fmt::Display for DebugOptions {
             Self::StackTop(n) => write!(f, "stack.{n}"),
             Self::MemAll => write!(f, "mem"),
             Self::MemInterval(n, m) => write!(f, "mem.{n}.{m}"),
+            Self::MemIntervalCtx(ctx, n, m) => write!(f, "mem_ctx.{ctx}.{n}.{m}"),
             Self::LocalInterval(start, end, _) => {
                 write!(f, "local.{start}.{end}")
             }
+            Self::Checkpoint(label) => write!(f, "checkpoint.{label}"),
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for DebugOptions {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::StackAll => target.write_u8(0),
+            Self::StackTop(n) => {
+                target.write_u8(1);
+                target.write_u16(*n);
+            }
+            Self::MemAll => target.write_u8(2),
+            Self::MemInterval(n, m) => {
+                target.write_u8(3);
+                target.write_u32(*n);
+                target.write_u32(*m);
+            }
+            Self::MemIntervalCtx(ctx, n, m) => {
+                target.write_u8(4);
+                target.write_u32(*ctx);
+                target.write_u32(*n);
+                target.write_u32(*m);
+            }
+            Self::LocalInterval(n, m, num_locals) => {
+                target.write_u8(5);
+                target.write_u16(*n);
+                target.write_u16(*m);
+                target.write_u16(*num_locals);
+            }
+            Self::Checkpoint(label) => {
+                target.write_u8(6);
+                target.write_u32(*label);
+            }
         }
     }
 }
+
+impl Deserializable for DebugOptions {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tag = source.read_u8()?;
+        let options = match tag {
+            0 => Self::StackAll,
+            1 => Self::StackTop(source.read_u16()?),
+            2 => Self::MemAll,
+            3 => Self::MemInterval(source.read_u32()?, source.read_u32()?),
+            4 => Self::MemIntervalCtx(source.read_u32()?, source.read_u32()?, source.read_u32()?),
+            5 => Self::LocalInterval(source.read_u16()?, source.read_u16()?, source.read_u16()?),
+            6 => Self::Checkpoint(source.read_u32()?),
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid debug options tag: {tag}"
+                )))
+            }
+        };
+        Ok(options)
+    }
+}
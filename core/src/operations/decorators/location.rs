@@ -1,4 +1,4 @@
-use super::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use core::fmt;
 
 // SOURCE LOCATION
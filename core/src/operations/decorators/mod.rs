@@ -1,3 +1,4 @@
+use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
 use alloc::vec::Vec;
 use core::fmt;
 
@@ -10,6 +11,9 @@ pub use assembly_op::AssemblyOp;
 mod debug;
 pub use debug::DebugOptions;
 
+mod location;
+pub use location::SourceLocation;
+
 // DECORATORS
 // ================================================================================================
 
@@ -108,3 +112,71 @@ impl fmt::Display for SignatureKind {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Decorator {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Advice(injector) => {
+                target.write_u8(0);
+                injector.write_into(target);
+            }
+            Self::AsmOp(assembly_op) => {
+                target.write_u8(1);
+                assembly_op.write_into(target);
+            }
+            Self::Debug(options) => {
+                target.write_u8(2);
+                options.write_into(target);
+            }
+            Self::Event(event_id) => {
+                target.write_u8(3);
+                target.write_u32(*event_id);
+            }
+            Self::Trace(trace_id) => {
+                target.write_u8(4);
+                target.write_u32(*trace_id);
+            }
+        }
+    }
+}
+
+impl Deserializable for Decorator {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tag = source.read_u8()?;
+        let decorator = match tag {
+            0 => Self::Advice(AdviceInjector::read_from(source)?),
+            1 => Self::AsmOp(AssemblyOp::read_from(source)?),
+            2 => Self::Debug(DebugOptions::read_from(source)?),
+            3 => Self::Event(source.read_u32()?),
+            4 => Self::Trace(source.read_u32()?),
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid decorator tag: {tag}"
+                )))
+            }
+        };
+        Ok(decorator)
+    }
+}
+
+impl Serializable for SignatureKind {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::RpoFalcon512 => target.write_u8(0),
+        }
+    }
+}
+
+impl Deserializable for SignatureKind {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::RpoFalcon512),
+            tag => Err(DeserializationError::InvalidValue(format!(
+                "invalid signature kind tag: {tag}"
+            ))),
+        }
+    }
+}
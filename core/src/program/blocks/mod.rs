@@ -1,5 +1,8 @@
 use super::{hasher, Digest, Felt, Operation};
-use crate::DecoratorList;
+use crate::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    DecoratorList,
+};
 use alloc::vec::Vec;
 use core::fmt;
 
@@ -138,3 +141,99 @@ impl fmt::Display for CodeBlock {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CodeBlock {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            CodeBlock::Span(block) => {
+                target.write_u8(0);
+                block.write_into(target);
+            }
+            CodeBlock::Join(block) => {
+                target.write_u8(1);
+                block.first().write_into(target);
+                block.second().write_into(target);
+            }
+            CodeBlock::Split(block) => {
+                target.write_u8(2);
+                block.on_true().write_into(target);
+                block.on_false().write_into(target);
+            }
+            CodeBlock::Loop(block) => {
+                target.write_u8(3);
+                block.body().write_into(target);
+            }
+            CodeBlock::Call(block) => {
+                target.write_u8(4);
+                block.fn_hash().write_into(target);
+                target.write_bool(block.is_syscall());
+            }
+            CodeBlock::Dyn(_) => target.write_u8(5),
+            CodeBlock::Proxy(block) => {
+                target.write_u8(6);
+                block.hash().write_into(target);
+            }
+        }
+    }
+}
+
+/// Maximum nesting depth of code blocks accepted by [CodeBlock::read_from].
+///
+/// Deserialization recurses once per nesting level, so inputs which are nested deeper than this
+/// are rejected to avoid overflowing the stack.
+pub const MAX_BLOCK_DEPTH: usize = 256;
+
+impl Deserializable for CodeBlock {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_with_depth(source, 0)
+    }
+}
+
+impl CodeBlock {
+    /// Reads a code block nested `depth` levels deep from the specified source.
+    fn read_with_depth<R: ByteReader>(
+        source: &mut R,
+        depth: usize,
+    ) -> Result<Self, DeserializationError> {
+        if depth >= MAX_BLOCK_DEPTH {
+            return Err(DeserializationError::InvalidValue(format!(
+                "code blocks are nested deeper than {MAX_BLOCK_DEPTH} levels"
+            )));
+        }
+
+        let tag = source.read_u8()?;
+        let block = match tag {
+            0 => CodeBlock::Span(Span::read_from(source)?),
+            1 => {
+                let first = CodeBlock::read_with_depth(source, depth + 1)?;
+                let second = CodeBlock::read_with_depth(source, depth + 1)?;
+                CodeBlock::new_join([first, second])
+            }
+            2 => {
+                let on_true = CodeBlock::read_with_depth(source, depth + 1)?;
+                let on_false = CodeBlock::read_with_depth(source, depth + 1)?;
+                CodeBlock::new_split(on_true, on_false)
+            }
+            3 => CodeBlock::new_loop(CodeBlock::read_with_depth(source, depth + 1)?),
+            4 => {
+                let fn_hash = Digest::read_from(source)?;
+                if source.read_bool()? {
+                    CodeBlock::new_syscall(fn_hash)
+                } else {
+                    CodeBlock::new_call(fn_hash)
+                }
+            }
+            5 => CodeBlock::new_dyn(),
+            6 => CodeBlock::new_proxy(Digest::read_from(source)?),
+            _ => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid code block tag: {tag}"
+                )))
+            }
+        };
+        Ok(block)
+    }
+}
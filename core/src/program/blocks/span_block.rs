@@ -1,5 +1,8 @@
 use super::{fmt, hasher, Digest, Felt, Operation};
-use crate::{DecoratorIterator, DecoratorList, ZERO};
+use crate::{
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Decorator, DecoratorIterator, DecoratorList, ZERO,
+};
 use alloc::vec::Vec;
 use winter_utils::flatten_slice_elements;
 
@@ -164,6 +167,37 @@ impl fmt::Display for Span {
     }
 }
 
+impl Serializable for Span {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.get_ops().write_into(target);
+        self.decorators.write_into(target);
+    }
+}
+
+impl Deserializable for Span {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let operations = Vec::<Operation>::read_from(source)?;
+        let decorators = Vec::<(usize, Decorator)>::read_from(source)?;
+
+        if operations.is_empty() {
+            return Err(DeserializationError::InvalidValue(
+                "span block must contain at least one operation".into(),
+            ));
+        }
+        let is_sorted = decorators.windows(2).all(|pair| pair[0].0 <= pair[1].0);
+        let in_bounds = decorators.last().map_or(true, |(idx, _)| *idx <= operations.len());
+        if !is_sorted || !in_bounds {
+            return Err(DeserializationError::InvalidValue(
+                "invalid decorator list of a span block".into(),
+            ));
+        }
+
+        // the batches and the hash are rebuilt from the operations, and thus the hash of the
+        // deserialized block is the same as the hash of the serialized one
+        Ok(Self::with_decorators(operations, decorators))
+    }
+}
+
 // OPERATION BATCH
 // ================================================================================================
 
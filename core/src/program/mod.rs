@@ -2,9 +2,11 @@ use super::{
     chiplets::hasher::{self, Digest},
     errors, Felt, Operation,
 };
-use crate::utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
-use alloc::{collections::BTreeMap, vec::Vec};
-use core::fmt;
+use crate::utils::{
+    to_hex, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable, SliceReader,
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::fmt::{self, Write};
 
 pub mod blocks;
 use blocks::CodeBlock;
@@ -68,6 +70,68 @@ impl Program {
     pub fn cb_table(&self) -> &CodeBlockTable {
         &self.cb_table
     }
+
+    // FORMATTING
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a human-readable rendering of the MAST of this program.
+    ///
+    /// Each node of the tree is rendered on a separate line as its kind followed by its hash in
+    /// hex, and the children of a node are indented by two spaces relative to their parent. For
+    /// `call` and `syscall` nodes the hash of the invoked procedure is rendered as well; if the
+    /// procedure is present in the code block table of this program, its MAST is rendered as the
+    /// child of the node.
+    pub fn to_mast_string(&self) -> String {
+        let mut result = String::new();
+        write_mast_node(&mut result, &self.root, &self.cb_table, 0)
+            .expect("writing into a string should not fail");
+        result
+    }
+
+    // SERIALIZATION / DESERIALIZATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Serializes this program into a vector of bytes.
+    ///
+    /// The serialized program contains the full MAST of the program together with its kernel and
+    /// code block table, and thus it can be executed after deserialization without re-assembling
+    /// it. Decorators of the program (including debug info) are preserved.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut target = Vec::new();
+        self.write_into(&mut target);
+        target
+    }
+
+    /// Returns a [Program] deserialized from the provided bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the bytes do not encode a valid program, or if they contain data
+    /// beyond the encoded program.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DeserializationError> {
+        let mut source = SliceReader::new(bytes);
+        let program = Self::read_from(&mut source)?;
+        if source.has_more_bytes() {
+            return Err(DeserializationError::UnconsumedBytes);
+        }
+        Ok(program)
+    }
+}
+
+impl Serializable for Program {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.root.write_into(target);
+        self.kernel.write_into(target);
+        self.cb_table.write_into(target);
+    }
+}
+
+impl Deserializable for Program {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let root = CodeBlock::read_from(source)?;
+        let kernel = Kernel::read_from(source)?;
+        let cb_table = CodeBlockTable::read_from(source)?;
+        Ok(Self::with_kernel(root, kernel, cb_table))
+    }
 }
 
 impl fmt::Display for Program {
@@ -76,6 +140,46 @@ impl fmt::Display for Program {
     }
 }
 
+/// Writes the MAST rooted at the provided code block into the target, indenting every node by
+/// two spaces per level of nesting.
+fn write_mast_node(
+    target: &mut String,
+    block: &CodeBlock,
+    cb_table: &CodeBlockTable,
+    depth: usize,
+) -> fmt::Result {
+    let indent = depth * 2;
+    let hash = to_hex(&block.hash().as_bytes())?;
+    match block {
+        CodeBlock::Span(_) => writeln!(target, "{:indent$}span 0x{hash}", ""),
+        CodeBlock::Join(join) => {
+            writeln!(target, "{:indent$}join 0x{hash}", "")?;
+            write_mast_node(target, join.first(), cb_table, depth + 1)?;
+            write_mast_node(target, join.second(), cb_table, depth + 1)
+        }
+        CodeBlock::Split(split) => {
+            writeln!(target, "{:indent$}split 0x{hash}", "")?;
+            write_mast_node(target, split.on_true(), cb_table, depth + 1)?;
+            write_mast_node(target, split.on_false(), cb_table, depth + 1)
+        }
+        CodeBlock::Loop(loop_block) => {
+            writeln!(target, "{:indent$}loop 0x{hash}", "")?;
+            write_mast_node(target, loop_block.body(), cb_table, depth + 1)
+        }
+        CodeBlock::Call(call) => {
+            let kind = if call.is_syscall() { "syscall" } else { "call" };
+            let fn_hash = to_hex(&call.fn_hash().as_bytes())?;
+            writeln!(target, "{:indent$}{kind} 0x{hash} -> 0x{fn_hash}", "")?;
+            match cb_table.get(call.fn_hash()) {
+                Some(callee) => write_mast_node(target, callee, cb_table, depth + 1),
+                None => Ok(()),
+            }
+        }
+        CodeBlock::Dyn(_) => writeln!(target, "{:indent$}dyn 0x{hash}", ""),
+        CodeBlock::Proxy(_) => writeln!(target, "{:indent$}proxy 0x{hash}", ""),
+    }
+}
+
 // CODE BLOCK TABLE
 // ================================================================================================
 
@@ -107,12 +211,35 @@ impl CodeBlockTable {
         self.0.insert(key, block);
     }
 
+    /// Inserts all code blocks from the provided table into this table.
+    pub fn extend(&mut self, other: CodeBlockTable) {
+        self.0.extend(other.0);
+    }
+
     /// Returns true if this code block table is empty.
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
 }
 
+impl Serializable for CodeBlockTable {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.0.len());
+        target.write_many(self.0.values());
+    }
+}
+
+impl Deserializable for CodeBlockTable {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let len = source.read_usize()?;
+        let mut table = Self::default();
+        for _ in 0..len {
+            table.insert(CodeBlock::read_from(source)?);
+        }
+        Ok(table)
+    }
+}
+
 // KERNEL
 // ================================================================================================
 
@@ -1,6 +1,13 @@
-use super::{blocks::Dyn, Deserializable, Digest, Felt, Kernel, ProgramInfo, Serializable};
-use crate::{chiplets::hasher, Word};
-use alloc::vec::Vec;
+use super::{
+    blocks::{CodeBlock, Dyn, MAX_BLOCK_DEPTH},
+    CodeBlockTable, Deserializable, DeserializationError, Digest, Felt, Kernel, Program,
+    ProgramInfo, Serializable,
+};
+use crate::{
+    chiplets::hasher, utils::to_hex, AdviceInjector, AssemblyOp, DebugOptions, Decorator,
+    Operation, SourceLocation, Word, ONE,
+};
+use alloc::{format, vec::Vec};
 use proptest::prelude::*;
 use rand_utils::prng_array;
 
@@ -11,6 +18,120 @@ fn dyn_hash_is_correct() {
     assert_eq!(expected_constant, Dyn::new().hash());
 }
 
+#[test]
+fn mast_string_renders_nested_blocks() {
+    let callee = CodeBlock::new_span(vec![Operation::Add]);
+    let call = CodeBlock::new_call(callee.hash());
+    let span = CodeBlock::new_span(vec![Operation::Pad, Operation::Drop]);
+    let on_true = CodeBlock::new_loop(span.clone());
+    let split = CodeBlock::new_split(on_true.clone(), CodeBlock::new_dyn());
+    let root = CodeBlock::new_join([call.clone(), split.clone()]);
+
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(callee.clone());
+    let program = Program::with_kernel(root.clone(), Kernel::default(), cb_table);
+
+    let hex = |block: &CodeBlock| to_hex(&block.hash().as_bytes()).unwrap();
+    let expected = format!(
+        "join 0x{}\n  call 0x{} -> 0x{}\n    span 0x{}\n  split 0x{}\n    loop 0x{}\n      span 0x{}\n    dyn 0x{}\n",
+        hex(&root),
+        hex(&call),
+        hex(&callee),
+        hex(&callee),
+        hex(&split),
+        hex(&on_true),
+        hex(&span),
+        hex(&CodeBlock::new_dyn()),
+    );
+    assert_eq!(expected, program.to_mast_string());
+}
+
+#[test]
+fn program_serialization_round_trip() {
+    let callee = CodeBlock::new_span_with_decorators(
+        vec![Operation::Push(Felt::new(7)), Operation::Add, Operation::Assert(3)],
+        vec![
+            (
+                0,
+                Decorator::Advice(AdviceInjector::MapValueToStack {
+                    include_len: true,
+                    key_offset: 4,
+                }),
+            ),
+            (1, Decorator::Debug(DebugOptions::MemIntervalCtx(1, 2, 3))),
+            (
+                3,
+                Decorator::AsmOp(
+                    AssemblyOp::new("#exec::foo".into(), 1, "assert.err=3".into(), true)
+                        .with_location(SourceLocation::new(4, 5)),
+                ),
+            ),
+        ],
+    );
+    let body = CodeBlock::new_span(vec![Operation::U32assert2(ONE), Operation::Noop]);
+    let root = CodeBlock::new_join([
+        CodeBlock::new_call(callee.hash()),
+        CodeBlock::new_split(
+            CodeBlock::new_loop(body),
+            CodeBlock::new_join([CodeBlock::new_syscall(callee.hash()), CodeBlock::new_dyn()]),
+        ),
+    ]);
+    let root = CodeBlock::new_join([root, CodeBlock::new_proxy(callee.hash())]);
+
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(callee.clone());
+    let kernel = Kernel::new(&[callee.hash()]).unwrap();
+    let program = Program::with_kernel(root, kernel, cb_table);
+
+    let bytes = program.to_bytes();
+    let deserialized = Program::from_bytes(&bytes).unwrap();
+    assert_eq!(program.hash(), deserialized.hash());
+    assert_eq!(program.root(), deserialized.root());
+    assert_eq!(program.kernel(), deserialized.kernel());
+    assert_eq!(Some(&callee), deserialized.cb_table().get(callee.hash()));
+    assert_eq!(program.to_mast_string(), deserialized.to_mast_string());
+
+    // trailing and truncated data is rejected
+    let mut extended = bytes.clone();
+    extended.push(0);
+    assert!(matches!(
+        Program::from_bytes(&extended),
+        Err(DeserializationError::UnconsumedBytes)
+    ));
+    assert!(Program::from_bytes(&bytes[..bytes.len() - 1]).is_err());
+}
+
+#[test]
+fn deeply_nested_program_is_rejected() {
+    // the root block is serialized first, so prefixing loop tags nests it inside that many loops
+    let bytes = Program::new(CodeBlock::new_dyn()).to_bytes();
+    let nest = |depth: usize| {
+        let mut nested = vec![3u8; depth];
+        nested.extend_from_slice(&bytes);
+        nested
+    };
+
+    assert!(Program::from_bytes(&nest(MAX_BLOCK_DEPTH - 1)).is_ok());
+    assert!(matches!(
+        Program::from_bytes(&nest(MAX_BLOCK_DEPTH)),
+        Err(DeserializationError::InvalidValue(_))
+    ));
+    assert!(Program::from_bytes(&nest(1_000_000)).is_err());
+}
+
+#[test]
+fn advice_injector_tags_are_stable() {
+    // new injectors are appended after the existing ones, such that serialized programs remain
+    // readable
+    assert_eq!(AdviceInjector::MemToStack.to_bytes(), [6]);
+    assert_eq!(AdviceInjector::HpermToMap.to_bytes(), [20]);
+
+    let injector = AdviceInjector::MapValueLenToStack { key_offset: 4 };
+    let bytes = injector.to_bytes();
+    assert_eq!(bytes[0], 22);
+    assert_eq!(AdviceInjector::read_from_bytes(&bytes).unwrap(), injector);
+}
+
 proptest! {
     #[test]
     fn arbitrary_program_info_serialization_works(
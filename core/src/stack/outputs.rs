@@ -126,6 +126,24 @@ impl StackOutputs {
         Some(word_elements)
     }
 
+    /// Returns the word at the top of the stack or `None` if the stack contains fewer than 4
+    /// elements.
+    ///
+    /// The elements of the word are ordered in the same way as for [Self::get_stack_word()], e.g.,
+    /// a digest computed via `hperm` and left at the top of the stack is returned as is.
+    pub fn top_word(&self) -> Option<Word> {
+        self.get_stack_word(0)
+    }
+
+    /// Returns the specified number of words from the top of the stack, starting with the word at
+    /// the top of the stack, or all words of the stack if fewer than the requested number of words
+    /// exist.
+    ///
+    /// The elements of each word are ordered in the same way as for [Self::get_stack_word()].
+    pub fn words(&self, num_words: usize) -> Vec<Word> {
+        (0..num_words).map_while(|idx| self.get_stack_word(idx * 4)).collect()
+    }
+
     /// Returns the stack outputs, which is state of the stack at the end of execution converted to
     /// integers.
     pub fn stack(&self) -> &[Felt] {
@@ -182,6 +200,22 @@ impl StackOutputs {
         overflow
     }
 
+    /// Returns `(index, expected, actual)` tuples for all positions on the stack at which the
+    /// elements of these outputs (`expected`) and the `other` outputs (`actual`) differ.
+    ///
+    /// If one stack is deeper than the other, the missing elements of the shallower stack are
+    /// treated as [ZERO].
+    pub fn diff(&self, other: &StackOutputs) -> Vec<(usize, Felt, Felt)> {
+        let depth = self.stack.len().max(other.stack.len());
+        (0..depth)
+            .filter_map(|idx| {
+                let expected = self.get_stack_item(idx).unwrap_or(ZERO);
+                let actual = other.get_stack_item(idx).unwrap_or(ZERO);
+                (expected != actual).then_some((idx, expected, actual))
+            })
+            .collect()
+    }
+
     // PUBLIC MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -245,3 +279,51 @@ impl Deserializable for StackOutputs {
         })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Felt, StackOutputs, ZERO};
+
+    #[test]
+    fn diff_stack_outputs() {
+        let expected = StackOutputs::try_from_ints(vec![1, 2, 3], vec![]).unwrap();
+        assert!(expected.diff(&expected).is_empty());
+
+        let mut actual = expected.clone();
+        actual.stack_mut()[0] += Felt::new(1);
+        actual.stack_mut()[15] = Felt::new(7);
+        let diff = expected.diff(&actual);
+        assert_eq!(diff, vec![(0, Felt::new(1), Felt::new(2)), (15, ZERO, Felt::new(7))]);
+
+        // elements missing from the shallower stack are treated as zeros
+        let stack = (1..=17).collect();
+        let actual = StackOutputs::try_from_ints(stack, vec![0, 1]).unwrap();
+        let diff = expected.diff(&actual);
+        assert_eq!(diff.len(), 14);
+        assert_eq!(diff.last(), Some(&(16, ZERO, Felt::new(17))));
+    }
+
+    #[test]
+    fn stack_output_words() {
+        let stack = (1..=18).collect();
+        let outputs = StackOutputs::try_from_ints(stack, vec![0, 1, 2]).unwrap();
+        let word = |values: [u64; 4]| values.map(Felt::new);
+
+        // the elements of a word are stored on the stack in reverse order
+        assert_eq!(outputs.top_word(), Some(word([4, 3, 2, 1])));
+        assert_eq!(outputs.words(2), vec![word([4, 3, 2, 1]), word([8, 7, 6, 5])]);
+
+        // only complete words are returned
+        let words = outputs.words(10);
+        assert_eq!(words.len(), 4);
+        assert_eq!(words[3], word([16, 15, 14, 13]));
+        assert!(outputs.words(0).is_empty());
+
+        // an empty stack has no words
+        assert_eq!(StackOutputs::default().top_word(), None);
+        assert!(StackOutputs::default().words(1).is_empty());
+    }
+}
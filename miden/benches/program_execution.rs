@@ -29,6 +29,26 @@ fn program_execution(c: &mut Criterion) {
         });
     });
 
+    group.bench_function("overflow_push_pop", |bench| {
+        // pushes and pops values beyond the top 16 stack items symmetrically, which adds rows to
+        // and removes rows from the stack overflow table in most of the cycles
+        let source = "
+            begin
+                repeat.5000
+                    padw padw dropw dropw
+                end
+            end";
+        let program = Assembler::default().compile(source).expect("Failed to compile test source.");
+        bench.iter(|| {
+            execute(
+                &program,
+                StackInputs::default(),
+                DefaultHost::default(),
+                ExecutionOptions::default(),
+            )
+        });
+    });
+
     group.finish();
 }
 
@@ -10,7 +10,7 @@ use serde_derive::{Deserialize, Serialize};
 use std::{
     collections::{BTreeMap, HashMap},
     fs,
-    io::Write,
+    io::{BufRead, Write},
     path::{Path, PathBuf},
 };
 use stdlib::StdLibrary;
@@ -118,6 +118,12 @@ impl InputFile {
 
     /// Parse advice provider data from the input file.
     pub fn parse_advice_provider(&self) -> Result<MemAdviceProvider, String> {
+        self.parse_advice_inputs().map(MemAdviceProvider::from)
+    }
+
+    /// Parse advice inputs (i.e., the advice stack, the advice map, and the merkle store) from
+    /// the input file.
+    pub fn parse_advice_inputs(&self) -> Result<AdviceInputs, String> {
         let mut advice_inputs = AdviceInputs::default();
 
         let stack = self
@@ -139,7 +145,7 @@ impl InputFile {
             advice_inputs = advice_inputs.with_merkle_store(merkle_store);
         }
 
-        Ok(MemAdviceProvider::from(advice_inputs))
+        Ok(advice_inputs)
     }
 
     /// Parse advice stack data from the input file.
@@ -441,6 +447,57 @@ impl ProgramFile {
     }
 }
 
+// ADVICE STREAM
+// ================================================================================================
+
+pub struct AdviceStream;
+
+/// Helper methods to read advice stack values from a stream (e.g., stdin)
+impl AdviceStream {
+    /// Reads advice stack values from the provided reader and appends them to the advice stack of
+    /// the provided advice inputs.
+    ///
+    /// The stream must contain one value per line, and each value must be either a decimal or a
+    /// `0x`-prefixed hexadecimal representation of a field element. Empty lines are ignored. The
+    /// values are read in the same order as the `advice_stack` of an input file, i.e., the first
+    /// value is the first one to be popped from the advice stack, and the values are appended
+    /// after the ones already in the advice inputs.
+    ///
+    /// Values are parsed as they are read, and thus the stream is never held in memory as a
+    /// whole. Returns an error specifying the line number of the first malformed value.
+    #[instrument(name = "read_advice_stream", skip_all)]
+    pub fn read<R: BufRead>(reader: R, advice_inputs: &mut AdviceInputs) -> Result<(), String> {
+        for (idx, line) in reader.lines().enumerate() {
+            let line_num = idx + 1;
+            let line = line.map_err(|err| {
+                format!("Failed to read advice stream at line {line_num} - {err}")
+            })?;
+            let value = line.trim();
+            if value.is_empty() {
+                continue;
+            }
+
+            let value = Self::parse_value(value).map_err(|err| {
+                format!("Failed to parse advice stack value '{value}' at line {line_num} - {err}")
+            })?;
+            advice_inputs.extend_stack([value]);
+        }
+
+        Ok(())
+    }
+
+    /// Parses a decimal or a `0x`-prefixed hexadecimal representation of a field element.
+    fn parse_value(value: &str) -> Result<Felt, String> {
+        let value = match value.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => value.parse::<u64>(),
+        }
+        .map_err(|err| err.to_string())?;
+
+        Felt::try_from(value).map_err(|err| err.to_string())
+    }
+}
+
 // PROOF FILE
 // ================================================================================================
 
@@ -555,7 +612,8 @@ impl Libraries {
 // ================================================================================================
 #[cfg(test)]
 mod test {
-    use super::InputFile;
+    use super::{AdviceStream, InputFile};
+    use miden_vm::{math::Felt, AdviceInputs};
 
     #[test]
     fn test_merkle_data_parsing() {
@@ -629,4 +687,24 @@ mod test {
         let merkle_store = inputs.parse_merkle_store().unwrap();
         assert!(merkle_store.is_some());
     }
+
+    #[test]
+    fn test_advice_stream_parsing() {
+        let stream = "1\n0x10\n\n  42  \n0xffffffff00000000\n";
+        let mut advice_inputs = AdviceInputs::default().with_stack_values([7]).unwrap();
+        AdviceStream::read(stream.as_bytes(), &mut advice_inputs).unwrap();
+
+        let expected = [7, 1, 16, 42, 0xffffffff00000000].map(Felt::new);
+        assert_eq!(advice_inputs.stack(), expected);
+
+        // the line number of the first malformed value is reported
+        let stream = "1\n\n0x1g\nabc\n";
+        let err = AdviceStream::read(stream.as_bytes(), &mut AdviceInputs::default()).unwrap_err();
+        assert!(err.contains("'0x1g' at line 3"), "{err}");
+
+        // values which are not field elements are rejected
+        let stream = "0xffffffffffffffff";
+        let err = AdviceStream::read(stream.as_bytes(), &mut AdviceInputs::default()).unwrap_err();
+        assert!(err.contains("at line 1"), "{err}");
+    }
 }
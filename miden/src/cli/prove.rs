@@ -1,9 +1,11 @@
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile};
+use super::data::{
+    instrument, AdviceStream, Debug, InputFile, Libraries, OutputFile, ProgramFile, ProofFile,
+};
 use clap::Parser;
 use miden_vm::ProvingOptions;
-use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, Program};
+use processor::{DefaultHost, ExecutionOptions, ExecutionOptionsError, MemAdviceProvider, Program};
 
-use std::{path::PathBuf, time::Instant};
+use std::{io, path::PathBuf, time::Instant};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Prove a miden program")]
@@ -12,6 +14,11 @@ pub struct ProveCmd {
     #[clap(short = 'a', long = "assembly", value_parser)]
     assembly_file: PathBuf,
 
+    /// Read additional advice stack values from stdin, one decimal or `0x`-prefixed hexadecimal
+    /// value per line; the values are pushed after the advice stack of the input file
+    #[clap(long = "advice-stdin")]
+    advice_stdin: bool,
+
     /// Number of cycles the program is expected to consume
     #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
     expected_cycles: u32,
@@ -78,7 +85,11 @@ impl ProveCmd {
 
         // fetch the stack and program inputs from the arguments
         let stack_inputs = input_data.parse_stack_inputs()?;
-        let host = DefaultHost::new(input_data.parse_advice_provider()?);
+        let mut advice_inputs = input_data.parse_advice_inputs()?;
+        if self.advice_stdin {
+            AdviceStream::read(io::stdin().lock(), &mut advice_inputs)?;
+        }
+        let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
 
         let proving_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
 
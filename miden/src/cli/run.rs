@@ -1,7 +1,7 @@
-use super::data::{instrument, Debug, InputFile, Libraries, OutputFile, ProgramFile};
+use super::data::{instrument, AdviceStream, Debug, InputFile, Libraries, OutputFile, ProgramFile};
 use clap::Parser;
-use processor::{DefaultHost, ExecutionOptions, ExecutionTrace};
-use std::{path::PathBuf, time::Instant};
+use processor::{DefaultHost, ExecutionOptions, ExecutionTrace, MemAdviceProvider};
+use std::{io, path::PathBuf, time::Instant};
 
 #[derive(Debug, Clone, Parser)]
 #[clap(about = "Run a miden program")]
@@ -10,6 +10,11 @@ pub struct RunCmd {
     #[clap(short = 'a', long = "assembly", value_parser)]
     assembly_file: PathBuf,
 
+    /// Read additional advice stack values from stdin, one decimal or `0x`-prefixed hexadecimal
+    /// value per line; the values are pushed after the advice stack of the input file
+    #[clap(long = "advice-stdin")]
+    advice_stdin: bool,
+
     /// Number of cycles the program is expected to consume
     #[clap(short = 'e', long = "exp-cycles", default_value = "64")]
     expected_cycles: u32,
@@ -116,7 +121,11 @@ fn run_program(params: &RunCmd) -> Result<(ExecutionTrace, [u8; 32]), String> {
 
     // fetch the stack and program inputs from the arguments
     let stack_inputs = input_data.parse_stack_inputs()?;
-    let host = DefaultHost::new(input_data.parse_advice_provider()?);
+    let mut advice_inputs = input_data.parse_advice_inputs()?;
+    if params.advice_stdin {
+        AdviceStream::read(io::stdin().lock(), &mut advice_inputs)?;
+    }
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs));
 
     let program_hash: [u8; 32] = program.hash().into();
 
@@ -20,14 +20,12 @@ pub fn get_example(n: usize) -> Example<DefaultHost<MemAdviceProvider>> {
         n, expected_result
     );
 
-    Example {
+    Example::new(
         program,
-        stack_inputs: StackInputs::try_from_ints(INITIAL_HASH_VALUE.iter().map(|&v| v as u64))
-            .unwrap(),
-        host: DefaultHost::default(),
+        StackInputs::try_from_ints(INITIAL_HASH_VALUE.iter().map(|&v| v as u64)).unwrap(),
+        DefaultHost::default(),
         expected_result,
-        num_outputs: 8,
-    }
+    )
 }
 
 /// Generates a program to compute the `n`-th hash of blake3 1-to-1 hash chain
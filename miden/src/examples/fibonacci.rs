@@ -13,13 +13,12 @@ pub fn get_example(n: usize) -> Example<DefaultHost<MemAdviceProvider>> {
         n, expected_result[0]
     );
 
-    Example {
+    Example::new(
         program,
-        stack_inputs: StackInputs::try_from_ints([0, 1]).unwrap(),
-        host: DefaultHost::default(),
+        StackInputs::try_from_ints([0, 1]).unwrap(),
+        DefaultHost::default(),
         expected_result,
-        num_outputs: 1,
-    }
+    )
 }
 
 /// Generates a program to compute the `n`-th term of Fibonacci sequence
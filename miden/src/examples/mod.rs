@@ -1,12 +1,37 @@
 use clap::Parser;
-use miden_vm::{ExecutionProof, Host, Program, ProgramInfo, ProvingOptions, StackInputs};
-use processor::{ExecutionOptions, ExecutionOptionsError, Felt, ONE, ZERO};
+use miden_vm::{
+    DefaultHost, ExecutionProof, HashFunction, Host, MemAdviceProvider, Program, ProgramInfo,
+    ProofSizeBreakdown, ProvingOptions, StackInputs,
+};
+use processor::{ExecutionOptions, Felt, ONE, ZERO};
 
 use std::time::Instant;
 
 pub mod blake3;
 pub mod fibonacci;
 
+// CONSTANTS
+// ================================================================================================
+
+/// Smallest blowup factor supported by the VM, determined by the degree of its constraints.
+const MIN_BLOWUP_FACTOR: usize = 8;
+
+/// Largest blowup factor supported by the prover.
+const MAX_BLOWUP_FACTOR: usize = 128;
+
+/// Largest grinding factor (in bits) supported by the prover.
+const MAX_GRINDING_FACTOR: u32 = 32;
+
+/// Largest number of outputs an example can be checked against, i.e., the number of elements at
+/// the top of the operand stack.
+const MAX_NUM_OUTPUTS: usize = 16;
+
+/// Default length of the Fibonacci sequence computed by the Fibonacci example.
+const DEFAULT_FIB_SEQUENCE_LENGTH: usize = 1024;
+
+/// Default length of the hash chain computed by the BLAKE3 example.
+const DEFAULT_BLAKE3_CHAIN_LENGTH: usize = 32;
+
 // EXAMPLE
 // ================================================================================================
 
@@ -21,6 +46,41 @@ where
     pub expected_result: Vec<Felt>,
 }
 
+impl<H> Example<H>
+where
+    H: Host,
+{
+    /// Returns a new example executing the specified program with the provided stack inputs and
+    /// host, and checking the top of the resulting stack against `expected_result`.
+    ///
+    /// The number of outputs of the example is inferred from the length of `expected_result`.
+    ///
+    /// # Panics
+    /// Panics if `expected_result` is empty or contains more than 16 elements.
+    pub fn new(
+        program: Program,
+        stack_inputs: StackInputs,
+        host: H,
+        expected_result: Vec<Felt>,
+    ) -> Self {
+        let num_outputs = expected_result.len();
+        assert!(num_outputs > 0, "an example must have at least one expected output");
+        assert!(
+            num_outputs <= MAX_NUM_OUTPUTS,
+            "an example can have at most {MAX_NUM_OUTPUTS} expected outputs, but {num_outputs} \
+            were provided"
+        );
+
+        Self {
+            program,
+            stack_inputs,
+            host,
+            num_outputs,
+            expected_result,
+        }
+    }
+}
+
 // EXAMPLE OPTIONS
 // ================================================================================================
 
@@ -49,6 +109,25 @@ pub struct ExampleOptions {
     /// Enable tracing to monitor execution of the VM
     #[clap(short = 't', long = "tracing")]
     tracing: bool,
+
+    /// Blowup factor overriding the one of the security level preset; must be a power of two
+    /// between 8 and 128
+    #[clap(short = 'b', long = "blowup")]
+    blowup_factor: Option<usize>,
+
+    /// Grinding factor (in bits) overriding the one of the security level preset; must not be
+    /// greater than 32
+    #[clap(short = 'g', long = "grinding")]
+    grinding_factor: Option<u32>,
+
+    /// Number of threads used to generate proofs; requires the VM to be compiled with the
+    /// `concurrent` feature, and overrides `RAYON_NUM_THREADS` for the examples only
+    #[clap(long = "threads")]
+    num_threads: Option<usize>,
+
+    /// Report the number of bytes taken by each component of the execution proof
+    #[clap(long = "proof-breakdown")]
+    proof_breakdown: bool,
 }
 
 #[derive(Debug, Clone, Parser)]
@@ -57,87 +136,317 @@ pub enum ExampleType {
     /// Compute a Fibonacci sequence of the specified length
     Fib {
         /// Length of Fibonacci sequence
-        #[clap(short = 'n', default_value = "1024")]
+        #[clap(short = 'n', default_value_t = DEFAULT_FIB_SEQUENCE_LENGTH)]
         sequence_length: usize,
     },
 
     /// Compute a chain of the BLAKE3 1-to-1 hashes
     Blake3 {
         /// Length of the hash chain
-        #[clap(short = 'n', default_value = "32")]
+        #[clap(short = 'n', default_value_t = DEFAULT_BLAKE3_CHAIN_LENGTH)]
         chain_length: usize,
     },
+
+    /// Run all examples with their default parameters and print a summary of the results
+    All,
 }
 
 impl ExampleOptions {
-    pub fn get_proof_options(&self) -> Result<ProvingOptions, ExecutionOptionsError> {
+    pub fn get_proof_options(&self) -> Result<ProvingOptions, String> {
         let exec_options =
-            ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, self.tracing)?;
-        Ok(match self.security.as_str() {
-            "96bits" => ProvingOptions::with_96_bit_security(self.recursive),
-            "128bits" => ProvingOptions::with_128_bit_security(self.recursive),
-            other => panic!("{} is not a valid security level", other),
-        }
+            ExecutionOptions::new(Some(self.max_cycles), self.expected_cycles, self.tracing)
+                .map_err(|err| format!("{err}"))?;
+        let (preset, hash_fn) = match (self.security.as_str(), self.recursive) {
+            ("96bits", false) => (ProvingOptions::REGULAR_96_BITS, HashFunction::Blake3_192),
+            ("96bits", true) => (ProvingOptions::RECURSIVE_96_BITS, HashFunction::Rpo256),
+            ("128bits", false) => (ProvingOptions::REGULAR_128_BITS, HashFunction::Blake3_256),
+            ("128bits", true) => (ProvingOptions::RECURSIVE_128_BITS, HashFunction::Rpo256),
+            (other, _) => panic!("{} is not a valid security level", other),
+        };
+
+        let blowup_factor = match self.blowup_factor {
+            Some(blowup_factor)
+                if !blowup_factor.is_power_of_two()
+                    || !(MIN_BLOWUP_FACTOR..=MAX_BLOWUP_FACTOR).contains(&blowup_factor) =>
+            {
+                return Err(format!(
+                    "blowup factor must be a power of two between {MIN_BLOWUP_FACTOR} and \
+                    {MAX_BLOWUP_FACTOR}, but was {blowup_factor}"
+                ));
+            }
+            Some(blowup_factor) => blowup_factor,
+            None => preset.blowup_factor(),
+        };
+
+        let grinding_factor = match self.grinding_factor {
+            Some(grinding_factor) if grinding_factor > MAX_GRINDING_FACTOR => {
+                return Err(format!(
+                    "grinding factor must not be greater than {MAX_GRINDING_FACTOR}, but was \
+                    {grinding_factor}"
+                ));
+            }
+            Some(grinding_factor) => grinding_factor,
+            None => preset.grinding_factor(),
+        };
+
+        let fri_options = preset.to_fri_options();
+        Ok(ProvingOptions::new(
+            preset.num_queries(),
+            blowup_factor,
+            grinding_factor,
+            preset.field_extension(),
+            fri_options.folding_factor(),
+            fri_options.remainder_max_degree(),
+            hash_fn,
+        )
         .with_execution_options(exec_options))
     }
 
+    /// Runs the selected examples.
+    ///
+    /// If the number of threads is specified, the examples are run within a dedicated rayon
+    /// thread pool with the specified number of threads, which is used for all parallel
+    /// computations performed by the prover and is dropped once the examples complete. The global
+    /// rayon pool (configured via the `RAYON_NUM_THREADS` environment variable) is not affected.
     pub fn execute(&self) -> Result<(), String> {
-        println!("============================================================");
+        match self.num_threads {
+            Some(0) => Err("number of threads must be greater than 0".to_string()),
+            Some(num_threads) => self.execute_with_threads(num_threads),
+            None => self.run_examples(),
+        }
+    }
 
-        let proof_options = self.get_proof_options().map_err(|err| format!("{err}"))?;
+    #[cfg(feature = "concurrent")]
+    fn execute_with_threads(&self, num_threads: usize) -> Result<(), String> {
+        let thread_pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .map_err(|err| format!("Failed to build a thread pool: {err}"))?;
+        println!("Generating proofs using {num_threads} thread(s)");
+        thread_pool.install(|| self.run_examples())
+    }
 
-        // instantiate and prepare the example
-        let example = match self.example {
-            ExampleType::Fib { sequence_length } => fibonacci::get_example(sequence_length),
-            ExampleType::Blake3 { chain_length } => blake3::get_example(chain_length),
-        };
+    #[cfg(not(feature = "concurrent"))]
+    fn execute_with_threads(&self, _num_threads: usize) -> Result<(), String> {
+        println!("Ignoring the number of threads as the VM was compiled without concurrency");
+        self.run_examples()
+    }
 
-        let Example {
-            program,
-            stack_inputs,
-            host,
-            num_outputs,
-            expected_result,
-            ..
-        } = example;
-        println!("--------------------------------");
-
-        // execute the program and generate the proof of execution
-        let now = Instant::now();
-        let (stack_outputs, proof) =
-            miden_vm::prove(&program, stack_inputs.clone(), host, proof_options).unwrap();
-        println!("--------------------------------");
-
-        println!(
-            "Executed program in {} ms",
-            //hex::encode(program.hash()), // TODO: include into message
-            now.elapsed().as_millis()
-        );
-        println!("Stack outputs: {:?}", stack_outputs.stack_truncated(num_outputs));
-        assert_eq!(
-            expected_result,
-            stack_outputs.stack_truncated(num_outputs),
-            "Program result was computed incorrectly"
-        );
+    fn run_examples(&self) -> Result<(), String> {
+        let proof_options = self.get_proof_options()?;
+
+        // the verifier accepts only proofs generated with the preset parameters
+        let verify = self.blowup_factor.is_none() && self.grinding_factor.is_none();
 
-        // serialize the proof to see how big it is
-        let proof_bytes = proof.to_bytes();
-        println!("Execution proof size: {} KB", proof_bytes.len() / 1024);
-        println!("Execution proof security: {} bits", proof.security_level());
-        println!("--------------------------------");
-
-        // verify that executing a program with a given hash and given inputs
-        // results in the expected output
-        let proof = ExecutionProof::from_bytes(&proof_bytes).unwrap();
-        let now = Instant::now();
-        let program_info = ProgramInfo::from(program);
-
-        match miden_vm::verify(program_info, stack_inputs, stack_outputs, proof) {
-            Ok(_) => println!("Execution verified in {} ms", now.elapsed().as_millis()),
-            Err(err) => println!("Failed to verify execution: {}", err),
+        if let ExampleType::All = self.example {
+            let reports = ExampleType::all()
+                .iter()
+                .map(|example| {
+                    let report =
+                        run_example(example, proof_options.clone(), verify, self.proof_breakdown);
+                    (example.name(), report)
+                })
+                .collect::<Vec<_>>();
+            print_summary(&reports);
+
+            let passed = reports
+                .iter()
+                .all(|(_, report)| report.as_ref().is_ok_and(ExampleReport::passed));
+            return match passed {
+                true => Ok(()),
+                false => Err("Some of the examples failed".to_string()),
+            };
+        }
+
+        let report = run_example(&self.example, proof_options, verify, self.proof_breakdown)?;
+        match report.verification {
+            Some(Err(err)) => Err(format!("Failed to verify execution: {err}")),
+            _ => Ok(()),
         }
+    }
+}
+
+impl ExampleType {
+    /// Returns all examples with their default parameters.
+    fn all() -> [Self; 2] {
+        [
+            ExampleType::Fib {
+                sequence_length: DEFAULT_FIB_SEQUENCE_LENGTH,
+            },
+            ExampleType::Blake3 {
+                chain_length: DEFAULT_BLAKE3_CHAIN_LENGTH,
+            },
+        ]
+    }
 
-        Ok(())
+    /// Returns the name of this example as used on the command line.
+    fn name(&self) -> &'static str {
+        match self {
+            ExampleType::Fib { .. } => "fib",
+            ExampleType::Blake3 { .. } => "blake3",
+            ExampleType::All => "all",
+        }
+    }
+
+    /// Instantiates the example described by this example type.
+    ///
+    /// # Panics
+    /// Panics if called on [ExampleType::All].
+    fn get_example(&self) -> Example<DefaultHost<MemAdviceProvider>> {
+        match self {
+            ExampleType::Fib { sequence_length } => fibonacci::get_example(*sequence_length),
+            ExampleType::Blake3 { chain_length } => blake3::get_example(*chain_length),
+            ExampleType::All => panic!("all examples cannot be instantiated as a single example"),
+        }
+    }
+}
+
+// EXAMPLE RUNNER
+// ================================================================================================
+
+/// The outcome of a successful run of an example.
+struct ExampleReport {
+    /// Time (in milliseconds) it took to execute the program and to generate the proof.
+    proving_time: u128,
+    /// Size of the execution proof in bytes.
+    proof_size: usize,
+    /// Time (in milliseconds) it took to verify the proof, or the verification error; `None` if the
+    /// proof was not verified.
+    verification: Option<Result<u128, String>>,
+}
+
+impl ExampleReport {
+    /// Returns true if the proof was verified successfully or was not verified at all.
+    fn passed(&self) -> bool {
+        !matches!(self.verification, Some(Err(_)))
+    }
+}
+
+/// Executes the specified example, generates a proof of the execution, and verifies the proof if
+/// `verify` is set to true. The sizes of the proof components are printed if `proof_breakdown` is
+/// set to true.
+///
+/// Returns an error if the program could not be executed or computed an incorrect result.
+fn run_example(
+    example: &ExampleType,
+    proof_options: ProvingOptions,
+    verify: bool,
+    proof_breakdown: bool,
+) -> Result<ExampleReport, String> {
+    println!("============================================================");
+
+    // instantiate and prepare the example
+    let Example {
+        program,
+        stack_inputs,
+        host,
+        num_outputs,
+        expected_result,
+        ..
+    } = example.get_example();
+    println!("--------------------------------");
+
+    // execute the program and generate the proof of execution
+    let now = Instant::now();
+    let (stack_outputs, proof) =
+        miden_vm::prove(&program, stack_inputs.clone(), host, proof_options)
+            .map_err(|err| format!("Failed to prove execution: {err}"))?;
+    let proving_time = now.elapsed().as_millis();
+    println!("--------------------------------");
+
+    println!(
+        "Executed program in {} ms",
+        //hex::encode(program.hash()), // TODO: include into message
+        proving_time
+    );
+    println!("Stack outputs: {:?}", stack_outputs.stack_truncated(num_outputs));
+    if expected_result != stack_outputs.stack_truncated(num_outputs) {
+        return Err("Program result was computed incorrectly".to_string());
+    }
+
+    // serialize the proof to see how big it is
+    let proof_bytes = proof.to_bytes();
+    println!("Execution proof size: {} KB", proof_bytes.len() / 1024);
+    println!("Execution proof security: {} bits", proof.security_level());
+    if proof_breakdown {
+        print_proof_breakdown(&proof.size_breakdown());
+    }
+    println!("--------------------------------");
+
+    let mut report = ExampleReport {
+        proving_time,
+        proof_size: proof_bytes.len(),
+        verification: None,
+    };
+
+    if !verify {
+        println!("Skipping verification of a proof generated with custom parameters");
+        return Ok(report);
+    }
+
+    // verify that executing a program with a given hash and given inputs
+    // results in the expected output
+    let proof = ExecutionProof::from_bytes(&proof_bytes).unwrap();
+    let now = Instant::now();
+    let program_info = ProgramInfo::from(program);
+
+    let verification = match miden_vm::verify(program_info, stack_inputs, stack_outputs, proof) {
+        Ok(_) => {
+            let verification_time = now.elapsed().as_millis();
+            println!("Execution verified in {} ms", verification_time);
+            Ok(verification_time)
+        }
+        Err(err) => {
+            println!("Failed to verify execution: {}", err);
+            Err(err.to_string())
+        }
+    };
+    report.verification = Some(verification);
+
+    Ok(report)
+}
+
+/// Prints the number of bytes taken by each component of an execution proof.
+fn print_proof_breakdown(breakdown: &ProofSizeBreakdown) {
+    println!("Execution proof breakdown:");
+    println!("  {:<20} {:>10} B", "header", breakdown.header);
+    println!("  {:<20} {:>10} B", "commitments", breakdown.commitments);
+    for (segment, size) in breakdown.trace_queries.iter().enumerate() {
+        println!("  {:<20} {size:>10} B", format!("trace queries ({segment})"));
+    }
+    println!("  {:<20} {:>10} B", "constraint queries", breakdown.constraint_queries);
+    println!("  {:<20} {:>10} B", "ood frame", breakdown.ood_frame);
+    println!(
+        "  {:<20} {:>10} B",
+        format!("fri proof ({} layers)", breakdown.num_fri_layers),
+        breakdown.fri_proof
+    );
+    println!("  {:<20} {:>10} B", "pow nonce", breakdown.pow_nonce);
+}
+
+/// Prints a table with the timings and the verification results of the provided example runs.
+fn print_summary(reports: &[(&str, Result<ExampleReport, String>)]) {
+    println!("============================================================");
+    println!(
+        "{:<10} {:>12} {:>12} {:>14}  result",
+        "example", "proving (ms)", "proof (KB)", "verifying (ms)"
+    );
+    for (name, report) in reports {
+        match report {
+            Ok(report) => {
+                let (verification_time, result) = match &report.verification {
+                    Some(Ok(time)) => (time.to_string(), "passed".to_string()),
+                    Some(Err(err)) => ("-".to_string(), format!("failed: {err}")),
+                    None => ("-".to_string(), "passed (not verified)".to_string()),
+                };
+                println!(
+                    "{name:<10} {:>12} {:>12} {verification_time:>14}  {result}",
+                    report.proving_time,
+                    report.proof_size / 1024,
+                );
+            }
+            Err(err) => println!("{name:<10} {:>12} {:>12} {:>14}  failed: {err}", "-", "-", "-"),
+        }
     }
 }
 
@@ -170,9 +479,114 @@ where
     let program_info = ProgramInfo::new(program.hash(), kernel);
 
     if fail {
+        // the verifier cannot attribute a failure to a specific output, and thus the tampered
+        // outputs are compared against the outputs of the execution to describe the mismatch
+        let expected_outputs = outputs.clone();
         outputs.stack_mut()[0] += ONE;
-        assert!(miden_vm::verify(program_info, stack_inputs, outputs, proof).is_err())
+        let mismatch = expected_outputs.diff(&outputs);
+        assert!(
+            miden_vm::verify(program_info, stack_inputs, outputs, proof).is_err(),
+            "proof was accepted for outputs which differ at {mismatch:?}"
+        )
     } else {
         assert!(miden_vm::verify(program_info, stack_inputs, outputs, proof).is_ok());
     }
 }
+
+#[test]
+fn proof_options_overrides() {
+    let get_proof_options = |args: &[&str]| {
+        let args = ["example"].iter().chain(args).chain(&["fib"]);
+        ExampleOptions::try_parse_from(args).unwrap().get_proof_options()
+    };
+    let exec_options = ExecutionOptions::new(Some(u32::MAX), 64, false).unwrap();
+
+    // without overrides, the options of the security level preset are used
+    assert_eq!(
+        get_proof_options(&["--security", "128bits"]),
+        Ok(ProvingOptions::with_128_bit_security(false).with_execution_options(exec_options))
+    );
+
+    let expected = ProvingOptions::new(
+        27,
+        16,
+        20,
+        miden_vm::FieldExtension::Quadratic,
+        8,
+        255,
+        HashFunction::Blake3_192,
+    );
+    assert_eq!(
+        get_proof_options(&["--blowup", "16", "--grinding", "20"]),
+        Ok(expected.with_execution_options(exec_options))
+    );
+
+    assert!(get_proof_options(&["--blowup", "12"]).is_err());
+    assert!(get_proof_options(&["--blowup", "4"]).is_err());
+    assert!(get_proof_options(&["--blowup", "256"]).is_err());
+    assert!(get_proof_options(&["--grinding", "33"]).is_err());
+}
+
+#[test]
+fn num_threads_option() {
+    let options = ExampleOptions::try_parse_from(["example", "--threads", "2", "all"]).unwrap();
+    assert_eq!(options.num_threads, Some(2));
+
+    let options = ExampleOptions::try_parse_from(["example", "all"]).unwrap();
+    assert_eq!(options.num_threads, None);
+
+    let options = ExampleOptions::try_parse_from(["example", "--threads", "0", "all"]).unwrap();
+    assert!(options.execute().is_err());
+}
+
+#[test]
+fn example_new_infers_num_outputs() {
+    let program = miden_vm::Assembler::default().compile("begin push.1 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([2]).unwrap();
+    let example = Example::new(
+        program.clone(),
+        stack_inputs.clone(),
+        miden_vm::DefaultHost::default(),
+        vec![Felt::new(3)],
+    );
+    assert_eq!(example.num_outputs, 1);
+    test_example(example, false);
+
+    let too_many_outputs = std::panic::catch_unwind(|| {
+        Example::new(program, stack_inputs, miden_vm::DefaultHost::default(), vec![ZERO; 17])
+    });
+    assert!(too_many_outputs.is_err());
+}
+
+#[test]
+fn all_examples_use_default_parameters() {
+    let options = ExampleOptions::try_parse_from(["example", "all"]).unwrap();
+    assert!(matches!(options.example, ExampleType::All));
+
+    // every example run by `all` uses the same parameters as when it is run on its own
+    for example in ExampleType::all() {
+        let args = ["example", example.name()];
+        let parsed = ExampleOptions::try_parse_from(args).unwrap().example;
+        assert_eq!(format!("{parsed:?}"), format!("{example:?}"));
+    }
+}
+
+#[test]
+fn proof_breakdown_covers_whole_proof() {
+    let options = ExampleOptions::try_parse_from(["example", "--proof-breakdown", "all"]).unwrap();
+    assert!(options.proof_breakdown);
+
+    let program = miden_vm::Assembler::default().compile("begin push.1 add end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([2]).unwrap();
+    let (_, proof) = miden_vm::prove(
+        &program,
+        stack_inputs,
+        miden_vm::DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+
+    let breakdown = proof.size_breakdown();
+    assert_eq!(breakdown.trace_queries.len(), 2);
+    assert_eq!(breakdown.total(), proof.to_bytes().len());
+}
@@ -9,12 +9,17 @@ pub use assembly::{
     Assembler, AssemblyError, ParsingError,
 };
 pub use processor::{
-    crypto, execute, execute_iter, utils, AdviceInputs, AdviceProvider, AsmOpInfo, DefaultHost,
-    ExecutionError, ExecutionTrace, Host, Kernel, MemAdviceProvider, Operation, Program,
-    ProgramInfo, StackInputs, VmState, VmStateIterator, ZERO,
+    crypto, execute, execute_iter, execute_with_assert_handler, execute_with_code_blocks,
+    execute_with_initial_memory, location_at, utils, AdviceInputs, AdviceProvider, AsmOpInfo,
+    AssertAction, Breakpoints, DefaultHost, ExecutionError, ExecutionTrace, Host, Kernel,
+    MemAdviceProvider, MemoryWriteEvent, Operation, Program, ProgramInfo, StackInputs, TraceEntry,
+    VmState, VmStateIterator, ZERO,
 };
 pub use prover::{
-    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError, ProvingOptions,
-    StackOutputs, StarkProof, Word,
+    math, prove, Digest, ExecutionProof, FieldExtension, HashFunction, InputError,
+    ProofSizeBreakdown, ProvingOptions, StackOutputs, StarkProof, Word,
+};
+pub use verifier::{
+    verify, verify_batch, verify_completeness_only, verify_public_inputs, verify_with_min_security,
+    BatchVerificationError, PublicInputs, VerificationError,
 };
-pub use verifier::{verify, VerificationError};
@@ -1,6 +1,9 @@
-use processor::{AsmOpInfo, ContextId, VmState};
-use test_utils::{build_debug_test, Felt, ToElements, ONE};
-use vm_core::{AssemblyOp, Operation};
+use processor::{
+    AsmOpInfo, Breakpoints, ContextId, DefaultHost, ExecutionError, ExecutionOptions,
+    InstructionLogWriter, StackInputs, VmState,
+};
+use test_utils::{build_debug_test, build_test, Felt, ToElements, ONE, ZERO};
+use vm_core::{AssemblyOp, Operation, SourceLocation};
 
 // EXEC ITER TESTS
 // =================================================================
@@ -40,7 +43,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Pad),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_location(SourceLocation::new(1, 34)),
                 1,
             )),
             stack: [0, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
@@ -52,7 +56,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Incr),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_location(SourceLocation::new(1, 34)),
                 2,
             )),
             stack: [1, 16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2].to_elements(),
@@ -64,7 +69,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::MStoreW),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 3, "mem_storew.1".to_string(), false)
+                    .with_location(SourceLocation::new(1, 34)),
                 3,
             )),
             stack: [16, 15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1].to_elements(),
@@ -76,7 +82,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 1,
             )),
             stack: [15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0].to_elements(),
@@ -88,7 +95,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 2,
             )),
             stack: [14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0].to_elements(),
@@ -100,7 +108,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 3,
             )),
             stack: [13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
@@ -112,7 +121,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 4, "dropw".to_string(), false)
+                    .with_location(SourceLocation::new(1, 47)),
                 4,
             )),
             stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
@@ -124,7 +134,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Push(Felt::new(17))),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("#main".to_string(), 1, "push.17".to_string(), false),
+                AssemblyOp::new("#main".to_string(), 1, "push.17".to_string(), false)
+                    .with_location(SourceLocation::new(1, 53)),
                 1,
             )),
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
@@ -163,7 +174,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Pad),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 1,
             )),
             stack: [0, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0].to_elements(),
@@ -175,7 +187,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::FmpAdd),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 2,
             )),
             stack: [2u64.pow(30) + 1, 17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0]
@@ -188,7 +201,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::MStore),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 3,
             )),
             stack: [17, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0].to_elements(),
@@ -203,7 +217,8 @@ fn test_exec_iter() {
             ctx: ContextId::root(),
             op: Some(Operation::Drop),
             asmop: Some(AsmOpInfo::new(
-                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false),
+                AssemblyOp::new("foo".to_string(), 4, "loc_store.0".to_string(), false)
+                    .with_location(SourceLocation::new(1, 12)),
                 4,
             )),
             stack: [12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 0, 0, 0, 0].to_elements(),
@@ -220,6 +235,124 @@ fn test_exec_iter() {
     }
 }
 
+#[test]
+fn test_exec_iter_breakpoints() {
+    let source = "begin\n    push.1\n    push.2\n    add\n    push.3\n    mul\nend";
+    let test = build_debug_test!(source, &[]);
+    let mut traces = test.execute_iter();
+
+    let mut breakpoints = Breakpoints::new();
+    assert!(breakpoints.insert(3));
+    assert!(breakpoints.insert(5));
+    assert!(!breakpoints.insert(5));
+
+    // execution pauses at the first cycle of each instruction on a line with a breakpoint
+    let mut hits = Vec::new();
+    let state = traces.resume(&breakpoints, |state| hits.push(state.clk)).unwrap().unwrap();
+    assert_eq!(state.asmop.as_ref().unwrap().op(), "push.2");
+    assert_eq!(state.stack[..2], [2, 1].to_elements());
+
+    let state = traces.resume(&breakpoints, |state| hits.push(state.clk)).unwrap().unwrap();
+    assert_eq!(state.asmop.as_ref().unwrap().op(), "push.3");
+    assert_eq!(state.stack[..2], [3, 3].to_elements());
+    assert_eq!(state.asmop.as_ref().unwrap().location(), Some(&SourceLocation::new(5, 5)));
+
+    // once all breakpoints were passed, execution runs to completion
+    assert!(traces.resume(&breakpoints, |state| hits.push(state.clk)).is_none());
+    assert_eq!(hits, vec![4, 6]);
+}
+
+#[test]
+fn test_instruction_log() {
+    let source = "begin push.1 push.2 add end";
+    let program = build_test!(source).compile().unwrap();
+    let options = ExecutionOptions::default().with_instruction_log();
+
+    // the log is not recorded unless it is enabled
+    let trace = processor::execute(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    )
+    .unwrap();
+    assert!(trace.instruction_log().is_empty());
+
+    // every executed operation is recorded together with the stack top around it
+    let trace =
+        processor::execute(&program, StackInputs::default(), DefaultHost::default(), options)
+            .unwrap();
+    let log = trace.instruction_log();
+    let ops = log.iter().map(|entry| entry.op).collect::<Vec<_>>();
+    let expected_ops = vec![
+        Operation::Noop,
+        Operation::Pad,
+        Operation::Incr,
+        Operation::Push(Felt::new(2)),
+        Operation::Add,
+        Operation::Noop,
+    ];
+    assert_eq!(ops, expected_ops);
+    for (clk, entry) in log.iter().enumerate() {
+        assert_eq!(entry.clk, clk as u32);
+    }
+    for entries in log.windows(2) {
+        assert_eq!(entries[0].stack_after, entries[1].stack_before);
+    }
+    assert_eq!(log[4].stack_before[..3], [Felt::new(2), ONE, ZERO]);
+    assert_eq!(log[4].stack_after[..2], [Felt::new(3), ZERO]);
+
+    // when written into a sink, the entries are not buffered in the trace
+    let mut host = InstructionLogWriter::new(DefaultHost::default(), Vec::new());
+    let trace = processor::execute(&program, StackInputs::default(), &mut host, options).unwrap();
+    assert!(trace.instruction_log().is_empty());
+    let (_, output) = host.into_parts();
+    let lines = String::from_utf8(output).unwrap();
+    let lines = lines.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), log.len());
+    assert_eq!(lines[0], log[0].to_string());
+}
+
+#[test]
+fn test_location_at() {
+    let source = "\
+begin
+    push.1
+    push.2
+    add
+    assert
+end";
+    let program = build_debug_test!(source).compile().unwrap();
+    let location_at =
+        |clk| processor::location_at(&program, StackInputs::default(), DefaultHost::default(), clk);
+
+    // push.1 takes cycles 1 and 2, push.2 takes cycle 3, and add takes cycle 4; the locations of
+    // the instructions executed before the failed assertion are still returned
+    assert_eq!(location_at(0), Ok(None));
+    assert_eq!(location_at(2), Ok(Some(SourceLocation::new(2, 5))));
+    assert_eq!(location_at(3), Ok(Some(SourceLocation::new(3, 5))));
+    assert_eq!(location_at(4), Ok(Some(SourceLocation::new(4, 5))));
+
+    // the error of the failed assertion is returned for its cycle and for all subsequent ones
+    let result = processor::execute(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    );
+    let Err(err @ ExecutionError::FailedAssertion { clk, .. }) = result else {
+        panic!("expected the assertion to fail");
+    };
+    assert_eq!(location_at(clk), Err(err.clone()));
+    assert_eq!(location_at(100), Err(err));
+
+    // cycles past the end of a successful execution have no location
+    let program = build_debug_test!("begin push.1 drop end").compile().unwrap();
+    let location =
+        processor::location_at(&program, StackInputs::default(), DefaultHost::default(), 100);
+    assert_eq!(location, Ok(None));
+}
+
 // HELPER FUNCTIONS
 // =================================================================
 fn slice_to_word(values: &[i32]) -> [Felt; 4] {
@@ -1,8 +1,16 @@
-use assembly::{Assembler, AssemblyContext, LibraryPath};
-use miden_vm::ModuleAst;
-use processor::ExecutionError;
+use assembly::{
+    ast::{Inliner, VisitMut},
+    Assembler, AssemblyContext, LibraryPath, ProcedureName,
+};
+use miden_vm::{ModuleAst, ProgramAst};
+use processor::{execute_with_code_blocks, ExecutionError};
+use std::collections::BTreeSet;
 use stdlib::StdLibrary;
-use test_utils::{build_test, AdviceInputs, StackInputs, Test, TestError};
+use test_utils::{
+    build_debug_test, build_test, AdviceInputs, ContextId, DefaultHost, ExecutionOptions, Felt,
+    Process, ProcessState, StackInputs, Test, TestError, ZERO,
+};
+use vm_core::{code_blocks::CodeBlock, CodeBlockTable, Kernel, Operation, Program, SourceLocation};
 
 // SIMPLE FLOW CONTROL TESTS
 // ================================================================================================
@@ -28,6 +36,29 @@ fn conditional_execution() {
     test.expect_stack(&[6]);
 }
 
+#[test]
+fn conditional_execution_coverage() {
+    let source = "
+        begin
+            if.true
+                push.3 add
+            else
+                push.5 mul
+            end
+        end";
+
+    // only the instructions of the taken branch should be reported as covered
+    let test = build_debug_test!(source, &[2, 1]);
+    let trace = test.execute().unwrap();
+    let expected = [SourceLocation::new(4, 17), SourceLocation::new(4, 24)];
+    assert_eq!(trace.covered_locations().iter().copied().collect::<Vec<_>>(), expected);
+
+    // without debug information, no locations are recorded
+    let test = build_test!(source, &[2, 1]);
+    let trace = test.execute().unwrap();
+    assert!(trace.covered_locations().is_empty());
+}
+
 #[test]
 fn conditional_loop() {
     // --- entering the loop ----------------------------------------------------------------------
@@ -69,6 +100,62 @@ fn counter_controlled_loop() {
     test.expect_stack(&[1024]);
 }
 
+#[test]
+fn infinite_loop_exceeds_cycle_limit() {
+    let source = "
+        begin
+            push.1
+            while.true
+                push.1
+            end
+        end";
+
+    let program = build_test!(source).compile().unwrap();
+    let options = ExecutionOptions::new(Some(1024), 64, false).unwrap();
+    let result =
+        processor::execute(&program, StackInputs::default(), DefaultHost::default(), options);
+    assert_eq!(result.err(), Some(ExecutionError::CycleLimitExceeded(1024)));
+}
+
+#[test]
+fn unbounded_push_exceeds_stack_depth_limit() {
+    let source = "
+        begin
+            push.1
+            while.true
+                push.1 push.1
+            end
+        end";
+
+    let program = build_test!(source).compile().unwrap();
+    let options = ExecutionOptions::default().with_max_stack_depth(64);
+    let result =
+        processor::execute(&program, StackInputs::default(), DefaultHost::default(), options);
+    let expected = ExecutionError::StackDepthLimitExceeded {
+        depth: 65,
+        limit: 64,
+    };
+    assert_eq!(result.err(), Some(expected));
+}
+
+#[test]
+fn fixed_stack_depth_rejects_overflow() {
+    let options = ExecutionOptions::default().with_fixed_stack_depth();
+
+    // programs which keep the stack within its top 16 items execute normally
+    let program = build_test!("begin swap add add.1 neg end").compile().unwrap();
+    let result =
+        processor::execute(&program, StackInputs::default(), DefaultHost::default(), options);
+    assert!(result.is_ok());
+
+    // execution fails at the cycle of the first operation which overflows the stack; the stack
+    // always holds at least 16 items, and thus any push overflows it
+    let program = build_test!("begin swap push.1 drop end").compile().unwrap();
+    let result =
+        processor::execute(&program, StackInputs::default(), DefaultHost::default(), options);
+    assert_eq!(result.err(), Some(ExecutionError::UnexpectedOverflow(2)));
+}
+
 // NESTED CONTROL FLOW
 // ================================================================================================
 
@@ -137,9 +224,37 @@ fn local_fn_call() {
             call.foo
         end";
 
-    let expected_err = TestError::ExecutionError(ExecutionError::InvalidStackDepthOnReturn(17));
+    let expected_err =
+        TestError::ExecutionError(ExecutionError::InvalidStackDepthOnReturn(17, vec![ZERO], None));
     build_test!(source, &[1, 2]).expect_error(expected_err);
 
+    // in debug mode, the error should point to the last instruction executed by the callee
+    let location = SourceLocation::new(3, 13);
+    let expected_err = TestError::ExecutionError(ExecutionError::InvalidStackDepthOnReturn(
+        17,
+        vec![ZERO],
+        Some(location),
+    ));
+    build_debug_test!(source, &[1, 2]).expect_error(expected_err);
+
+    // the error should capture the values stranded in the overflow table of the callee, but not
+    // the values in the overflow table of the caller
+    let source = "
+        proc.foo
+            push.7 push.8
+        end
+
+        begin
+            push.5 push.6
+            call.foo
+        end";
+
+    let stranded = vec![Felt::new(4), Felt::new(3)];
+    let expected_err =
+        TestError::ExecutionError(ExecutionError::InvalidStackDepthOnReturn(18, stranded, None));
+    build_test!(source, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16])
+        .expect_error(expected_err);
+
     // dropping values from the stack in the current execution context should not affect values
     // in the overflow table from the parent execution context
     let source = "
@@ -187,6 +302,81 @@ fn local_fn_call_with_mem_access() {
     test.prove_and_verify(vec![3, 7], false);
 }
 
+#[test]
+fn local_fn_call_memory_isolation() {
+    // foo writes into memory[0] of its own context, leaving memory[0] of the root context intact
+    let source = "
+        proc.foo
+            mem_store.0
+        end
+
+        begin
+            mem_store.0
+            call.foo
+        end";
+
+    let test = build_test!(source, &[3, 7]);
+    let process = test.execute_process().unwrap();
+
+    let foo_ctx = test
+        .execute_iter()
+        .map(|state| state.unwrap().ctx)
+        .find(|&ctx| ctx != ContextId::root())
+        .unwrap();
+    let word = |value| Some([Felt::new(value), ZERO, ZERO, ZERO]);
+    assert_eq!(process.get_mem_value(ContextId::root(), 0), word(7));
+    assert_eq!(process.get_mem_value(foo_ctx, 0), word(3));
+}
+
+#[test]
+fn inlined_local_fn_exec() {
+    let source = "
+        proc.foo
+            add
+        end
+
+        proc.bar.1
+            loc_store.0 exec.foo loc_load.0 mul
+        end
+
+        proc.baz
+            exec.foo push.1
+            if.true
+                exec.foo
+            end
+        end
+
+        begin
+            exec.baz exec.bar call.foo
+        end";
+    let program = ProgramAst::parse(source).unwrap();
+    let names = ["foo", "bar", "baz"]
+        .into_iter()
+        .map(|name| ProcedureName::try_from(name).unwrap())
+        .collect::<BTreeSet<_>>();
+
+    // bar uses local memory and thus is not inlined, and neither is the call of foo; foo is
+    // inlined into the bodies of bar and baz, as well as into baz inlined into the program body
+    let mut inlined = program.clone();
+    let mut inliner = Inliner::new(inlined.procedures(), &names);
+    let _ = inliner.visit_mut_program(&mut inlined);
+    assert_eq!(inliner.inlined(), 6);
+
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3, 4, 5, 6]).unwrap();
+    let execute = |program: &ProgramAst| {
+        let program = Assembler::default().compile_ast(program).unwrap();
+        let trace = processor::execute(
+            &program,
+            stack_inputs.clone(),
+            DefaultHost::default(),
+            ExecutionOptions::default(),
+        )
+        .unwrap();
+        trace.stack_outputs().clone()
+    };
+    assert_eq!(execute(&inlined), execute(&program));
+}
+
 #[test]
 fn simple_syscall() {
     let kernel_source = "
@@ -204,6 +394,8 @@ fn simple_syscall() {
     let test = Test {
         source: program_source.to_string(),
         kernel: Some(kernel_source.to_string()),
+        named_kernels: Default::default(),
+        active_kernel: None,
         stack_inputs: StackInputs::try_from_ints([1, 2]).unwrap(),
         advice_inputs: AdviceInputs::default(),
         in_debug_mode: false,
@@ -214,6 +406,81 @@ fn simple_syscall() {
     test.prove_and_verify(vec![1, 2], false);
 }
 
+#[test]
+fn simple_syscall_named_kernels() {
+    let program_source = "
+        begin
+            syscall.foo
+        end";
+
+    // the same program is compiled against each of the registered kernels in turn
+    let mut test = build_test!(program_source, &[2, 3])
+        .with_named_kernel("add", "export.foo add end")
+        .with_named_kernel("mul", "export.foo mul end");
+    test.expect_stack(&[6]);
+
+    test.select_kernel("add");
+    test.expect_stack(&[5]);
+    test.prove_and_verify(vec![2, 3], false);
+}
+
+#[test]
+fn simple_syscall_kernel_modules() {
+    // both modules have an internal procedure at the same index, which must not collide
+    let modules = [
+        ModuleAst::parse("proc.inc add.1 end export.foo exec.inc add end").unwrap(),
+        ModuleAst::parse("proc.dec sub.1 end export.bar exec.dec mul end").unwrap(),
+    ];
+    let assembler = Assembler::default().with_kernel_modules(&modules).unwrap();
+    assert_eq!(assembler.kernel().proc_hashes().len(), 2);
+
+    let program = assembler.compile("begin syscall.foo syscall.bar end").unwrap();
+    let stack_inputs = StackInputs::try_from_ints([3, 4, 5]).unwrap();
+    let trace =
+        processor::execute(&program, stack_inputs, DefaultHost::default(), Default::default())
+            .unwrap();
+
+    // foo: 4 + (5 + 1) = 10; bar: 3 * (10 - 1) = 27
+    assert_eq!(trace.stack_outputs().stack_truncated(1), &[Felt::new(27)]);
+
+    // procedures exported from different kernel modules must have distinct names
+    let modules = [
+        ModuleAst::parse("export.foo add end").unwrap(),
+        ModuleAst::parse("export.foo mul end").unwrap(),
+    ];
+    assert!(Assembler::default().with_kernel_modules(&modules).is_err());
+}
+
+#[test]
+fn nested_syscall() {
+    // the kernel cannot issue a syscall directly, but could do so by dynamically executing a
+    // procedure which issues a syscall; the assembler rejects kernels using `dynexec`, and thus
+    // the program is built manually
+    let bar = CodeBlock::new_span(vec![Operation::Add]);
+    let foo = CodeBlock::new_dyn();
+    let baz = CodeBlock::new_syscall(bar.hash());
+    let kernel = Kernel::new(&[foo.hash(), bar.hash()]).unwrap();
+
+    let mut cb_table = CodeBlockTable::default();
+    cb_table.insert(foo.clone());
+    cb_table.insert(bar.clone());
+    cb_table.insert(baz.clone());
+    let program = Program::with_kernel(CodeBlock::new_syscall(foo.hash()), kernel, cb_table);
+
+    // put the hash of baz on the stack
+    let mut inputs = vec![2, 1];
+    inputs.extend(baz.hash().as_elements().iter().map(|e| e.as_int()));
+    let stack_inputs = StackInputs::try_from_ints(inputs).unwrap();
+
+    let result = processor::execute(
+        &program,
+        stack_inputs,
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    );
+    assert_eq!(result.err(), Some(ExecutionError::NestedSyscall(bar.hash())));
+}
+
 // DYNAMIC CODE EXECUTION
 // ================================================================================================
 
@@ -251,6 +518,8 @@ fn simple_dyn_exec() {
     let test = Test {
         source: program_source.to_string(),
         kernel: None,
+        named_kernels: Default::default(),
+        active_kernel: None,
         stack_inputs: StackInputs::try_from_ints([
             3,
             // put the hash of foo on the stack
@@ -283,6 +552,41 @@ fn simple_dyn_exec() {
     );
 }
 
+#[test]
+fn dynexec_pre_registered_block() {
+    // the body of foo is not referenced from the program, and thus is not in its code block table
+    let foo = Assembler::default().compile("begin dropw add end").unwrap().root().clone();
+    let program = build_test!("begin dynexec end").compile().unwrap();
+    assert!(!program.cb_table().has(foo.hash()));
+
+    // put the hash of foo on the stack
+    let mut inputs = vec![2, 1];
+    inputs.extend(foo.hash().as_elements().iter().map(|e| e.as_int()));
+    let stack_inputs = StackInputs::try_from_ints(inputs).unwrap();
+
+    // without pre-registering foo, the execution fails
+    let mut process = Process::new(
+        program.kernel().clone(),
+        stack_inputs.clone(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    );
+    assert_eq!(
+        process.execute(&program).err(),
+        Some(ExecutionError::DynamicCodeBlockNotFound(foo.hash()))
+    );
+
+    let trace = execute_with_code_blocks(
+        &program,
+        stack_inputs,
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+        [foo],
+    )
+    .unwrap();
+    assert_eq!(trace.stack_outputs().stack_truncated(1), [Felt::new(3)]);
+}
+
 #[test]
 fn dynexec_with_procref() {
     let program_source = "
@@ -301,8 +605,7 @@ fn dynexec_with_procref() {
         dynexec
     end";
 
-    let mut test = build_test!(program_source, &[]);
-    test.libraries = vec![StdLibrary::default().into()];
+    let test = build_test!(program_source, libs = [StdLibrary::default()], &[]);
 
     test.expect_stack(&[
         1719755471,
@@ -315,6 +618,73 @@ fn dynexec_with_procref() {
     ]);
 }
 
+#[test]
+fn dynexec_serialized_program() {
+    let program_source = "
+    use.std::math::u64
+
+    proc.foo
+        push.1.2
+        u32wrapping_add
+    end
+
+    begin
+        procref.foo
+        dynexec
+
+        procref.u64::wrapping_add
+        dynexec
+    end";
+
+    let program = build_test!(program_source, libs = [StdLibrary::default()], &[])
+        .compile()
+        .unwrap();
+    let deserialized = vm_core::Program::from_bytes(&program.to_bytes()).unwrap();
+    assert_eq!(program.hash(), deserialized.hash());
+
+    // the deserialized program is executed without re-assembly, including the procedures
+    // invoked dynamically from the code block table
+    let execute = |program| {
+        processor::execute(
+            program,
+            StackInputs::default(),
+            DefaultHost::default(),
+            ExecutionOptions::default(),
+        )
+        .unwrap()
+        .stack_outputs()
+        .clone()
+    };
+    assert_eq!(execute(&program), execute(&deserialized));
+}
+
+#[test]
+fn memory_stats_are_tracked_per_context() {
+    let program_source = "
+        proc.foo
+            # write to one address and read from another one in the new context
+            push.7 mem_store.0
+            mem_load.1 drop
+        end
+
+        begin
+            push.5 mem_store.0
+            push.6 mem_store.1
+
+            # both invocations of foo start a new execution context
+            call.foo
+            procref.foo dyncall dropw
+        end";
+
+    let test = build_test!(program_source, &[]);
+    let trace = test.execute().unwrap();
+    let stats = trace.memory_stats();
+
+    assert_eq!(stats.len(), 3);
+    assert_eq!(stats.get(&u32::from(ContextId::root())), Some(&2));
+    assert!(stats.iter().filter(|(&ctx, _)| ctx != 0).all(|(_, &count)| count == 1));
+}
+
 #[test]
 fn simple_dyncall() {
     let program_source = "
@@ -357,6 +727,8 @@ fn simple_dyncall() {
     let test = Test {
         source: program_source.to_string(),
         kernel: None,
+        named_kernels: Default::default(),
+        active_kernel: None,
         stack_inputs: StackInputs::try_from_ints([
             3,
             // put the hash of foo on the stack
@@ -375,6 +747,9 @@ fn simple_dyncall() {
 
     test.expect_stack(&[6]);
 
+    // foo is entered once via `call` and once via `dyncall`
+    assert_eq!(test.execute().unwrap().context_switches(), 2);
+
     test.prove_and_verify(
         vec![
             3,
@@ -425,8 +800,7 @@ fn procref() {
         procref.foo
     end";
 
-    let mut test = build_test!(source, &[]);
-    test.libraries = vec![StdLibrary::default().into()];
+    let test = build_test!(source, libs = [StdLibrary::default()], &[]);
 
     test.expect_stack(&[
         mast_roots[1][3].as_int(),
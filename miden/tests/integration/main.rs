@@ -1,4 +1,8 @@
-use test_utils::build_test;
+use miden_vm::{
+    verify_batch, verify_completeness_only, verify_public_inputs, verify_with_min_security,
+    ExecutionProof, ProgramInfo, ProvingOptions, PublicInputs, StackOutputs, VerificationError,
+};
+use test_utils::{build_test, prove, rand::seeded_program, DefaultHost, StackInputs};
 
 mod air;
 mod cli;
@@ -19,3 +23,128 @@ fn multi_output_program() {
     let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
     test.prove_and_verify(vec![1, 2, 3], false);
 }
+
+#[test]
+fn random_programs() {
+    let mut seed = 0;
+    for _ in 0..4 {
+        let source = seeded_program(&mut seed, 50);
+        let test = build_test!(&source, &[1, 2, 3, 4]);
+        test.prove_and_verify(vec![1, 2, 3, 4], false);
+    }
+}
+
+#[test]
+fn completeness_only_verification() {
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_program();
+
+    // a valid proof should be accepted
+    let result = verify_completeness_only(
+        program_info.clone(),
+        stack_inputs.clone(),
+        stack_outputs.clone(),
+        proof.clone(),
+    );
+    assert_eq!(result, Ok(proof.security_level()));
+
+    // a valid proof should be rejected against different outputs
+    let wrong_outputs = StackOutputs::try_from_ints(vec![7], vec![]).unwrap();
+    let result = verify_completeness_only(
+        program_info.clone(),
+        stack_inputs.clone(),
+        wrong_outputs,
+        proof.clone(),
+    );
+    assert!(result.is_err());
+
+    // a proof claiming more queries than it contains should be rejected
+    let (hash_fn, mut stark_proof) = proof.into_parts();
+    stark_proof.num_unique_queries += 1;
+    let proof = ExecutionProof::new(stark_proof, hash_fn);
+    let result = verify_completeness_only(program_info, stack_inputs, stack_outputs, proof);
+    assert!(result.is_err());
+}
+
+#[test]
+fn verification_with_min_security_level() {
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_program();
+    let security_level = proof.security_level();
+
+    // a proof meeting the required security level should be accepted
+    let result = verify_with_min_security(
+        program_info.clone(),
+        stack_inputs.clone(),
+        stack_outputs.clone(),
+        proof.clone(),
+        security_level,
+    );
+    assert_eq!(result, Ok(security_level));
+
+    // a valid proof below the required security level should be rejected
+    let result = verify_with_min_security(
+        program_info,
+        stack_inputs,
+        stack_outputs,
+        proof,
+        security_level + 1,
+    );
+    let expected = VerificationError::InsufficientSecurityLevel {
+        actual: security_level,
+        required: security_level + 1,
+    };
+    assert_eq!(result, Err(expected));
+}
+
+#[test]
+fn verification_with_public_inputs() {
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_program();
+    let security_level = proof.security_level();
+
+    let pub_inputs = PublicInputs::new(program_info.clone(), stack_inputs, stack_outputs.clone());
+    assert_eq!(verify_public_inputs(pub_inputs, proof.clone()), Ok(security_level));
+
+    // the proof should not verify against different public inputs
+    let stack_inputs = StackInputs::try_from_ints([3, 2, 1]).unwrap();
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    assert!(verify_public_inputs(pub_inputs, proof).is_err());
+}
+
+#[test]
+fn batch_verification() {
+    let (program_info, stack_inputs, stack_outputs, proof) = prove_program();
+    let security_level = proof.security_level();
+
+    // a batch of valid proofs should be accepted
+    let valid_item = (stack_inputs, stack_outputs.clone(), proof.clone());
+    let items = vec![valid_item.clone(), valid_item.clone()];
+    let result = verify_batch(program_info.clone(), items);
+    assert_eq!(result, Ok(vec![security_level, security_level]));
+
+    // the failures of invalid items should be reported by their indexes
+    let wrong_inputs = StackInputs::try_from_ints([3, 2, 1]).unwrap();
+    let invalid_item = (wrong_inputs, stack_outputs, proof);
+    let items = vec![valid_item.clone(), invalid_item.clone(), valid_item, invalid_item];
+    let err = verify_batch(program_info, items).unwrap_err();
+    let indexes = err.failures().iter().map(|(index, _)| *index).collect::<Vec<_>>();
+    assert_eq!(indexes, [1, 3]);
+    assert_eq!(err.first_failure().0, 1);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Proves the execution of a small program against the inputs [1, 2, 3], and returns the info of
+/// the program, the stack inputs and outputs of the execution, and the proof.
+fn prove_program() -> (ProgramInfo, StackInputs, StackOutputs, ExecutionProof) {
+    let test = build_test!("begin mul movup.2 drop end", &[1, 2, 3]);
+    let program = test.compile().unwrap();
+    let stack_inputs = StackInputs::try_from_ints([1, 2, 3]).unwrap();
+    let (stack_outputs, proof) = prove(
+        &program,
+        stack_inputs.clone(),
+        DefaultHost::default(),
+        ProvingOptions::default(),
+    )
+    .unwrap();
+    (ProgramInfo::from(program), stack_inputs, stack_outputs, proof)
+}
@@ -1,8 +1,11 @@
+use processor::{DefaultHost, ExecutionError, ExecutionOptions, MemAdviceProvider};
 use test_utils::{
-    build_expected_hash, build_expected_perm, build_op_test,
-    crypto::{init_merkle_leaf, init_merkle_store, MerkleTree, NodeIndex},
+    build_expected_hash, build_expected_perm, build_op_test, build_test,
+    crypto::{
+        init_merkle_leaf, init_merkle_leaves, init_merkle_store, MerkleError, MerkleTree, NodeIndex,
+    },
     rand::rand_vector,
-    Felt,
+    AdviceInputs, Felt, TestError,
 };
 
 // TESTS
@@ -133,6 +136,78 @@ fn mtree_get() {
     test.expect_stack(&final_stack);
 }
 
+#[test]
+fn mtree_get_from_inserted_tree() {
+    let asm_op = "mtree_get";
+
+    // the three leaves are padded with an empty leaf, resulting in a tree of depth 2
+    let index = 2usize;
+    let leaves = init_merkle_leaves(&[1, 2, 3]);
+    let mut advice_inputs = AdviceInputs::default();
+    let root = advice_inputs.insert_merkle_tree(leaves.clone());
+
+    let stack_inputs = [
+        root[0].as_int(),
+        root[1].as_int(),
+        root[2].as_int(),
+        root[3].as_int(),
+        index as u64,
+        2,
+    ];
+
+    let final_stack = [
+        leaves[index][3].as_int(),
+        leaves[index][2].as_int(),
+        leaves[index][1].as_int(),
+        leaves[index][0].as_int(),
+        root[3].as_int(),
+        root[2].as_int(),
+        root[1].as_int(),
+        root[0].as_int(),
+    ];
+
+    let mut test = build_op_test!(asm_op, &stack_inputs);
+    test.advice_inputs = advice_inputs;
+    test.expect_stack(&final_stack);
+}
+
+#[test]
+fn mtree_set_root_updates() {
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves.clone()).unwrap();
+
+    // update the leaves at indexes 2 and 5 of the tree one after another
+    let mut new_leaves = leaves.clone();
+    new_leaves[2] = init_merkle_leaf(9);
+    let new_tree = MerkleTree::new(new_leaves.clone()).unwrap();
+    new_leaves[5] = init_merkle_leaf(10);
+    let final_tree = MerkleTree::new(new_leaves).unwrap();
+
+    let source = "
+        begin
+            push.9.0.0.0 swapw push.2.3 mtree_set dropw
+            push.10.0.0.0 swapw push.5.3 mtree_set dropw
+        end";
+    let stack_inputs = tree.root().iter().map(|value| value.as_int()).collect::<Vec<_>>();
+    let test = build_test!(source, &stack_inputs, &[], store);
+    let program = test.compile().unwrap();
+
+    // the updated roots are recorded only when enabled
+    let trace = test.execute().unwrap();
+    assert!(trace.merkle_root_updates().is_empty());
+
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let options = ExecutionOptions::default().with_merkle_root_updates();
+    let trace = processor::execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+    assert_eq!(
+        trace.merkle_root_updates(),
+        &[
+            (tree.root().into(), new_tree.root().into()),
+            (new_tree.root().into(), final_tree.root().into())
+        ]
+    );
+}
+
 #[test]
 fn mtree_verify() {
     let asm_op = "mtree_verify";
@@ -211,6 +286,67 @@ fn mtree_verify_negative() {
     test.expect_stack(&final_stack);
 }
 
+#[test]
+fn mtree_assert_root() {
+    let asm_op = "mtree_assert_root";
+
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves).unwrap();
+
+    let stack_inputs = [
+        9,
+        tree.root()[0].as_int(),
+        tree.root()[1].as_int(),
+        tree.root()[2].as_int(),
+        tree.root()[3].as_int(),
+    ];
+
+    // the root is removed from the stack if the tree is present in the advice provider
+    let test = build_op_test!(asm_op, &stack_inputs, &[], store);
+    test.expect_stack(&[9]);
+}
+
+#[test]
+fn mtree_assert_root_negative() {
+    let asm_op = "mtree_assert_root";
+
+    let (_, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let (other_leaves, _) = init_merkle_store(&[9, 10, 11, 12]);
+    let other_tree = MerkleTree::new(other_leaves).unwrap();
+
+    let stack_inputs = [
+        other_tree.root()[0].as_int(),
+        other_tree.root()[1].as_int(),
+        other_tree.root()[2].as_int(),
+        other_tree.root()[3].as_int(),
+    ];
+
+    let test = build_op_test!(asm_op, &stack_inputs, &[], store);
+    test.expect_error(TestError::ExecutionError(ExecutionError::MerkleStoreLookupFailed(
+        MerkleError::RootNotInStore(other_tree.root()),
+    )));
+}
+
+#[test]
+fn adv_push_has_mtree() {
+    let asm_op = "adv.push_has_mtree dropw adv_push.1";
+
+    let (leaves, store) = init_merkle_store(&[1, 2, 3, 4, 5, 6, 7, 8]);
+    let tree = MerkleTree::new(leaves).unwrap();
+    let (other_leaves, _) = init_merkle_store(&[9, 10, 11, 12]);
+    let other_tree = MerkleTree::new(other_leaves).unwrap();
+
+    // ONE is pushed onto the advice stack if the tree is present in the advice provider
+    let stack_inputs = tree.root().iter().map(|e| e.as_int()).collect::<Vec<_>>();
+    let test = build_op_test!(asm_op, &stack_inputs, &[], store.clone());
+    test.expect_stack(&[1]);
+
+    // ZERO is pushed onto the advice stack otherwise
+    let stack_inputs = other_tree.root().iter().map(|e| e.as_int()).collect::<Vec<_>>();
+    let test = build_op_test!(asm_op, &stack_inputs, &[], store);
+    test.expect_stack(&[0]);
+}
+
 #[test]
 fn mtree_update() {
     let index = 5usize;
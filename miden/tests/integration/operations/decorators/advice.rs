@@ -1,8 +1,9 @@
 use test_utils::{
     build_test,
-    crypto::{MerkleStore, RpoDigest},
+    crypto::{MerkleStore, Rpo256, RpoDigest},
     rand::rand_value,
-    Felt,
+    DefaultHost, ExecutionError, ExecutionOptions, Felt, MemAdviceProvider, TestError, ToElements,
+    Word,
 };
 
 // ADVICE INJECTION
@@ -179,6 +180,32 @@ fn advice_insert_mem() {
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
 
+#[test]
+fn advice_push_mem() {
+    let source = "begin
+    # stack: [1, 2, 3, 4, 5, 6, 7, 8]
+
+    # write both words to memory
+    mem_storew.2 dropw mem_storew.3 dropw
+    # State Transition:
+    # mem[2]: [4, 3, 2, 1]
+    # mem[3]: [8, 7, 6, 5]
+
+    # copy the memory range [2, 4) onto the advice stack
+    push.4.2 adv.push_mem drop drop
+    # State Transition:
+    # advice_stack: [4, 3, 2, 1, 8, 7, 6, 5]
+
+    # move the values from the advice stack onto the operand stack one by one
+    adv_push.8
+    # State Transition:
+    # stack: [5, 6, 7, 8, 1, 2, 3, 4]
+    end";
+    let stack_inputs = [8, 7, 6, 5, 4, 3, 2, 1];
+    let test = build_test!(source, &stack_inputs);
+    test.expect_stack(&[5, 6, 7, 8, 1, 2, 3, 4]);
+}
+
 #[test]
 fn advice_push_mapval() {
     // --- test simple adv.mapval ---------------------------------------------
@@ -278,6 +305,71 @@ fn advice_push_mapval() {
     test.expect_stack(&[15, 14, 13, 12, 11, 5]);
 }
 
+#[test]
+fn advice_push_mapval_len() {
+    // --- test simple adv.push_mapval_len ------------------------------------
+    let source: &str = "begin
+    # stack: [4, 3, 2, 1, ...]
+
+    # load the advice stack with the number of elements stored in the advice map under the key
+    # and drop the key
+    adv.push_mapval_len
+    dropw
+
+    # move the number of elements from the advice stack to the operand stack
+    adv_push.1
+
+    end";
+
+    let stack_inputs = [1, 2, 3, 4];
+    let adv_map = [(
+        RpoDigest::try_from(stack_inputs).unwrap(),
+        vec![Felt::new(11), Felt::new(12), Felt::new(13), Felt::new(14), Felt::new(15)],
+    )];
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map.clone());
+    test.expect_stack(&[5]);
+
+    // --- the values themselves are not pushed onto the advice stack ---------
+    let source: &str = "begin
+    adv.push_mapval_len
+    dropw
+    adv_push.2
+    end";
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map.clone());
+    assert!(matches!(test.execute(), Err(ExecutionError::AdviceStackReadFailed(_))));
+
+    // --- test adv.push_mapval_len with offset -------------------------------
+    let source: &str = "begin
+    # stack: [4, 3, 2, 1, ...]
+
+    # shift the key on the stack by 2 slots
+    push.0 push.0
+
+    # load the advice stack with the number of elements and drop the key
+    adv.push_mapval_len.2
+    dropw drop drop
+
+    # move the number of elements from the advice stack to the operand stack
+    adv_push.1
+
+    end";
+
+    let test = build_test!(source, &stack_inputs, [], MerkleStore::default(), adv_map);
+    test.expect_stack(&[5]);
+
+    // --- missing keys result in an error ------------------------------------
+    let source: &str = "begin
+    adv.push_mapval_len
+    dropw
+    end";
+
+    let test = build_test!(source, &stack_inputs);
+    let key = stack_inputs.map(Felt::new);
+    test.expect_error(TestError::ExecutionError(ExecutionError::AdviceMapKeyNotFound(key)));
+}
+
 #[test]
 fn advice_insert_hdword() {
     // --- test hashing without domain ----------------------------------------
@@ -329,3 +421,67 @@ fn advice_insert_hdword() {
     let test = build_test!(source, &stack_inputs);
     test.expect_stack(&[1, 2, 3, 4, 5, 6, 7, 8]);
 }
+
+#[test]
+fn advice_insert_hdword_default_domain() {
+    // the key is computed manually with domain 3, and thus reading the values back succeeds only
+    // if the default domain of the executor is used
+    let source: &str = "begin
+    adv.insert_hdword
+    push.0.3.0.0
+    swapw.2 swapw
+    hperm
+    dropw swapw dropw
+    adv.push_mapval
+    dropw
+    adv_push.8
+    end";
+    let stack_inputs = [8, 7, 6, 5, 4, 3, 2, 1];
+    let test = build_test!(source, &stack_inputs);
+    let program = test.compile().unwrap();
+
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let options = ExecutionOptions::default().with_default_hdword_domain(3);
+    let trace = processor::execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+    let expected = [1, 2, 3, 4, 5, 6, 7, 8].map(Felt::new);
+    assert_eq!(trace.stack_outputs().stack_truncated(8), expected);
+
+    // without the default domain, the values are inserted under a different key
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let result = processor::execute(&program, test.stack_inputs.clone(), host, Default::default());
+    assert!(matches!(result, Err(ExecutionError::AdviceMapKeyNotFound(_))));
+
+    // an explicitly specified domain takes precedence over the default one
+    let source: &str = "begin adv.insert_hdword.0 hmerge adv.push_mapval dropw adv_push.8 end";
+    let program = build_test!(source, &stack_inputs).compile().unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::default());
+    let trace = processor::execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+    assert_eq!(trace.stack_outputs().stack_truncated(8), expected);
+}
+
+#[test]
+fn advice_insert_hperm_final_state() {
+    // stack: [B, A, C, ...], where A = [1, 2, 3, 4], B = [5, 6, 7, 8], and C = [0, 0, 0, 0]
+    let source = "begin adv.insert_hperm adv_push.1 end";
+    let stack_inputs = [0, 0, 0, 0, 1, 2, 3, 4, 5, 6, 7, 8];
+    let test = build_test!(source, &stack_inputs, &[9, 10]);
+    let program = test.compile().unwrap();
+
+    // the key is the hash of A and B, as the domain in C is 0
+    let a: Word = [1_u64, 2, 3, 4].to_elements().try_into().unwrap();
+    let b: Word = [5_u64, 6, 7, 8].to_elements().try_into().unwrap();
+    let key = Rpo256::merge(&[a.into(), b.into()]);
+
+    // the final advice state is recorded only when enabled
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let options = ExecutionOptions::default();
+    let trace = processor::execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+    assert!(trace.advice_state().is_none());
+
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let options = ExecutionOptions::default().with_advice_state();
+    let trace = processor::execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+    let advice_state = trace.advice_state().unwrap();
+    assert_eq!(advice_state.stack(), &[Felt::new(10)]);
+    assert_eq!(advice_state.mapped_values(&key), Some([a, b].concat().as_slice()));
+}
@@ -1,6 +1,7 @@
 use super::TestHost;
 use assembly::Assembler;
-use processor::ExecutionOptions;
+use processor::{ContextId, ExecutionOptions};
+use vm_core::{Felt, Operation, ZERO};
 
 #[test]
 fn test_event_handling() {
@@ -52,3 +53,118 @@ fn test_trace_handling() {
     let expected = vec![1, 2];
     assert_eq!(host.trace_handler, expected);
 }
+
+#[test]
+fn test_step_handling() {
+    let source = "\
+    begin
+        push.3
+        push.4
+        add
+    end";
+
+    // compile and execute program
+    let program = Assembler::default().compile(source).unwrap();
+    let mut host = TestHost::default();
+    processor::execute(&program, Default::default(), &mut host, Default::default()).unwrap();
+
+    // the handler is invoked once per cycle, after the clock has been advanced
+    let clks = host.step_handler.iter().map(|(clk, ..)| *clk).collect::<Vec<_>>();
+    assert_eq!(clks, (1..=clks.len() as u32).collect::<Vec<_>>());
+
+    // the handler observes the state of the stack after each operation
+    let steps = host
+        .step_handler
+        .into_iter()
+        .filter(|(_, op, _)| matches!(op, Operation::Push(_) | Operation::Add))
+        .map(|(_, op, top)| (op, top))
+        .collect::<Vec<_>>();
+    let expected = vec![
+        (Operation::Push(Felt::new(3)), Felt::new(3)),
+        (Operation::Push(Felt::new(4)), Felt::new(4)),
+        (Operation::Add, Felt::new(7)),
+    ];
+    assert_eq!(steps, expected);
+}
+
+#[test]
+fn test_mem_write_handling() {
+    let source = "\
+    proc.foo
+        push.9 mem_store.0
+    end
+
+    begin
+        push.1.2.3.4 mem_storew.0 dropw
+        push.5 mem_store.0
+        call.foo
+    end";
+
+    // compile program
+    let program = Assembler::default().compile(source).unwrap();
+    let mut host = TestHost::default();
+
+    // execute program with disabled memory write events
+    processor::execute(&program, Default::default(), &mut host, Default::default()).unwrap();
+    assert!(host.mem_write_handler.is_empty());
+
+    // execute program with enabled memory write events
+    let options = ExecutionOptions::default().with_memory_write_events();
+    processor::execute(&program, Default::default(), &mut host, options).unwrap();
+    let writes = host.mem_write_handler;
+    assert_eq!(writes.len(), 3);
+
+    let word = |values: [u64; 4]| values.map(Felt::new);
+    assert_eq!(writes[0].ctx, ContextId::root());
+    assert_eq!(writes[0].old_value, [ZERO; 4]);
+    assert_eq!(writes[0].new_value, word([1, 2, 3, 4]));
+    assert_eq!(writes[1].ctx, ContextId::root());
+    assert_eq!(writes[1].old_value, word([1, 2, 3, 4]));
+    assert_eq!(writes[1].new_value, word([5, 2, 3, 4]));
+    assert!(writes[0].clk < writes[1].clk);
+
+    // the procedure invoked via call writes to the memory of a new context
+    assert_ne!(writes[2].ctx, ContextId::root());
+    assert_eq!(writes[2].addr, 0);
+    assert_eq!(writes[2].old_value, [ZERO; 4]);
+    assert_eq!(writes[2].new_value, word([9, 0, 0, 0]));
+}
+
+#[test]
+fn test_checkpoint_recording() {
+    let source = "\
+    begin
+        push.1
+        debug.checkpoint.7
+        repeat.2
+            push.2
+            debug.checkpoint.8
+        end
+    end";
+
+    // checkpoints are not recorded for programs compiled without debug mode
+    let program = Assembler::default().compile(source).unwrap();
+    let trace =
+        processor::execute(&program, Default::default(), TestHost::default(), Default::default())
+            .unwrap();
+    assert!(trace.checkpoints().is_empty());
+
+    // checkpoints are recorded every time they are executed
+    let program = Assembler::default().with_debug_mode(true).compile(source).unwrap();
+    let trace =
+        processor::execute(&program, Default::default(), TestHost::default(), Default::default())
+            .unwrap();
+    let checkpoints = trace.checkpoints();
+    assert_eq!(checkpoints.iter().map(|(label, _)| *label).collect::<Vec<_>>(), vec![7, 8, 8]);
+
+    let stack = |values: &[u64]| {
+        let mut state = [ZERO; 16];
+        for (item, value) in state.iter_mut().zip(values) {
+            *item = Felt::new(*value);
+        }
+        state
+    };
+    assert_eq!(checkpoints[0].1, stack(&[1]));
+    assert_eq!(checkpoints[1].1, stack(&[2, 1]));
+    assert_eq!(checkpoints[2].1, stack(&[2, 2, 1]));
+}
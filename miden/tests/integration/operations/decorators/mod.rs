@@ -1,8 +1,8 @@
 use processor::{
     AdviceExtractor, AdviceProvider, ExecutionError, Host, HostResponse, MemAdviceProvider,
-    ProcessState,
+    MemoryWriteEvent, ProcessState,
 };
-use vm_core::AdviceInjector;
+use vm_core::{AdviceInjector, Felt, Operation};
 
 mod advice;
 mod asmop;
@@ -14,6 +14,8 @@ pub struct TestHost<A> {
     pub adv_provider: A,
     pub event_handler: Vec<u32>,
     pub trace_handler: Vec<u32>,
+    pub step_handler: Vec<(u32, Operation, Felt)>,
+    pub mem_write_handler: Vec<MemoryWriteEvent>,
 }
 
 impl Default for TestHost<MemAdviceProvider> {
@@ -22,6 +24,8 @@ impl Default for TestHost<MemAdviceProvider> {
             adv_provider: MemAdviceProvider::default(),
             event_handler: Vec::new(),
             trace_handler: Vec::new(),
+            step_handler: Vec::new(),
+            mem_write_handler: Vec::new(),
         }
     }
 }
@@ -60,4 +64,18 @@ impl<A: AdviceProvider> Host for TestHost<A> {
         self.trace_handler.push(trace_id);
         Ok(HostResponse::None)
     }
+
+    fn on_step<S: ProcessState>(
+        &mut self,
+        process: &S,
+        op: Operation,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.step_handler.push((process.clk(), op, process.get_stack_item(0)));
+        Ok(HostResponse::None)
+    }
+
+    fn on_mem_write(&mut self, event: MemoryWriteEvent) -> Result<(), ExecutionError> {
+        self.mem_write_handler.push(event);
+        Ok(())
+    }
 }
@@ -1,7 +1,9 @@
 use super::{build_op_test, build_test, TestError};
-use processor::ExecutionError;
 use processor::ExecutionError::AdviceStackReadFailed;
-use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt};
+use processor::{
+    AdviceInputs, DefaultHost, ExecutionError, ExecutionOptions, MemAdviceProvider, StackInputs,
+};
+use vm_core::{chiplets::hasher::apply_permutation, utils::ToElements, Felt, ZERO};
 
 // PUSHING VALUES ONTO THE STACK (PUSH)
 // ================================================================================================
@@ -34,6 +36,26 @@ fn adv_push_invalid() {
     test.expect_error(TestError::ExecutionError(ExecutionError::AdviceStackReadFailed(1)));
 }
 
+#[test]
+fn adv_push_with_stack_default() {
+    let program = build_test!("begin adv_push.1 adv_push.2 end").compile().unwrap();
+    let advice_inputs = AdviceInputs::default().with_stack_values([5]).unwrap();
+
+    // by default, the execution fails on the first read from the exhausted advice stack
+    let host = DefaultHost::new(MemAdviceProvider::from(advice_inputs.clone()));
+    let result =
+        processor::execute(&program, StackInputs::default(), host, ExecutionOptions::default());
+    assert_eq!(result.err(), Some(AdviceStackReadFailed(2)));
+
+    // with the default value set, reads from the exhausted advice stack return the default value
+    let provider = MemAdviceProvider::from(advice_inputs).with_stack_default(ZERO);
+    let host = DefaultHost::new(provider);
+    let trace =
+        processor::execute(&program, StackInputs::default(), host, ExecutionOptions::default())
+            .unwrap();
+    assert_eq!(trace.stack_outputs().stack_truncated(3), &[ZERO, ZERO, Felt::new(5)]);
+}
+
 // OVERWRITING VALUES ON THE STACK (LOAD)
 // ================================================================================================
 
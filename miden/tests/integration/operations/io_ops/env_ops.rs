@@ -26,6 +26,26 @@ fn sdepth() {
     test.expect_stack(&[18, 1, 1, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4, 3]);
 }
 
+#[test]
+fn overflow_depth() {
+    let test_op = "overflow_depth";
+
+    // --- empty stack ----------------------------------------------------------------------------
+    let test = build_op_test!(test_op);
+    test.expect_stack(&[0]);
+
+    // --- overflowed stack -----------------------------------------------------------------------
+    // push 2 values to increase the lenth of the stack beyond 16
+    let source = format!("begin push.1 push.1 {test_op} end");
+    let test = build_test!(&source, &[0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
+    test.expect_stack(&[2, 1, 1, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4, 3]);
+
+    // --- overflow items can be dropped to restore the minimum stack depth -----------------------
+    let source = format!("begin push.1 push.1 {test_op} drop drop drop sdepth end");
+    let test = build_test!(&source, &[0, 1, 2, 3, 4, 5, 6, 7, 0, 1, 2, 3, 4, 5, 6, 7]);
+    test.expect_stack(&[STACK_TOP_SIZE as u64, 7, 6, 5, 4, 3, 2, 1, 0, 7, 6, 5, 4, 3, 2, 1]);
+}
+
 // LOCADDR INSTRUCTION
 // ================================================================================================
 
@@ -146,6 +166,8 @@ fn caller() {
     let test = Test {
         source: program_source.to_string(),
         kernel: Some(kernel_source.to_string()),
+        named_kernels: Default::default(),
+        active_kernel: None,
         stack_inputs: StackInputs::try_from_ints([1, 2, 3, 4, 5]).unwrap(),
         advice_inputs: AdviceInputs::default(),
         in_debug_mode: false,
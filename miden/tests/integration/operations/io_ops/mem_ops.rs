@@ -1,4 +1,7 @@
 use super::{apply_permutation, build_op_test, build_test, Felt, ToElements};
+use processor::execute_with_initial_memory;
+use std::collections::BTreeMap;
+use test_utils::{DefaultHost, ExecutionOptions, StackInputs};
 
 // LOADING SINGLE ELEMENT ONTO THE STACK (MLOAD)
 // ================================================================================================
@@ -213,3 +216,34 @@ fn read_after_write() {
     let test = build_op_test!("mem_storew.0 dropw mem_loadw.0", &[1, 2, 3, 4, 5, 6, 7, 8]);
     test.expect_stack(&[8, 7, 6, 5]);
 }
+
+// INITIAL MEMORY
+// ================================================================================================
+
+#[test]
+fn mem_load_from_initial_memory() {
+    let source = "
+        begin
+            padw mem_loadw.1
+            mem_load.0
+            mem_load.2
+        end";
+    let program = build_test!(source).compile().unwrap();
+
+    let memory = BTreeMap::from([
+        (0, [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)]),
+        (1, [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    ]);
+    let outputs = execute_with_initial_memory(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+        memory,
+    )
+    .unwrap();
+
+    // address 2 was not seeded, and thus is still initialized to ZERO
+    let expected = [0, 5, 4, 3, 2, 1].map(Felt::new);
+    assert_eq!(outputs.stack_truncated(6), expected);
+}
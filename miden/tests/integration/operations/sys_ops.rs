@@ -1,5 +1,8 @@
-use processor::ExecutionError;
-use test_utils::{build_op_test, TestError};
+use processor::{
+    execute_with_assert_handler, AssertAction, DefaultHost, ExecutionError, ExecutionOptions, Felt,
+    StackInputs, ZERO,
+};
+use test_utils::{build_op_test, build_test, TestError};
 
 // SYSTEM OPS ASSERTIONS - MANUAL TESTS
 // ================================================================================================
@@ -28,6 +31,30 @@ fn assert_with_code() {
     }));
 }
 
+#[test]
+fn assert_with_registered_error_message() {
+    let asm_op = "assert.err=123";
+    let program = build_op_test!(asm_op).compile().unwrap();
+    let host =
+        DefaultHost::default().with_error_messages([(123, "value must be non-zero".to_string())]);
+
+    // the description registered for the error code is included into the error
+    let result = processor::execute(
+        &program,
+        StackInputs::try_from_ints([0]).unwrap(),
+        host,
+        ExecutionOptions::default(),
+    );
+    assert_eq!(
+        result.err(),
+        Some(ExecutionError::FailedAssertion {
+            clk: 1,
+            err_code: 123,
+            err_msg: Some("value must be non-zero".to_string()),
+        })
+    );
+}
+
 #[test]
 fn assert_fail() {
     let asm_op = "assert";
@@ -69,3 +96,76 @@ fn assert_eq_fail() {
         err_msg: None,
     }));
 }
+
+#[test]
+fn assertions_checked() {
+    // assert_eqw is executed as four assertions, and only the taken branch is counted
+    let source = "
+        begin
+            assert
+            assert_eq
+            if.true
+                assertz
+            else
+                padw padw assert_eqw assert
+            end
+        end";
+
+    let test = build_test!(source, &[0, 1, 2, 2, 1]);
+    let trace = test.execute().unwrap();
+    assert_eq!(trace.assertions_checked(), 3);
+
+    let test = build_test!(source, &[1, 0, 2, 2, 1]);
+    let process = test.execute_process().unwrap();
+    assert_eq!(process.assertions_checked(), 7);
+}
+
+#[test]
+fn assert_handler() {
+    let source = "
+        proc.foo
+            push.0 assert.err=7
+            push.9
+        end
+
+        begin
+            push.3 exec.foo
+        end";
+
+    let program = build_test!(source, &[]).compile().unwrap();
+    let mut failures = Vec::new();
+
+    // the default action aborts the execution with the same error as without a handler
+    let result = execute_with_assert_handler(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+        |err_code, clk| {
+            failures.push((err_code, clk));
+            AssertAction::default()
+        },
+    );
+    let Err(ExecutionError::FailedAssertion {
+        clk, err_code: 7, ..
+    }) = result
+    else {
+        panic!("expected a failed assertion, but got {result:?}");
+    };
+    assert_eq!(failures, [(7, clk)]);
+
+    // halting returns the stack at the failed assertion, and the rest of the program is skipped
+    let outputs = execute_with_assert_handler(
+        &program,
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+        |err_code, clk| {
+            failures.push((err_code, clk));
+            AssertAction::Halt
+        },
+    )
+    .unwrap();
+    assert_eq!(failures, [(7, clk), (7, clk)]);
+    assert_eq!(outputs.stack_truncated(2), [ZERO, Felt::new(3)]);
+}
@@ -124,6 +124,15 @@ impl Memory {
         }
     }
 
+    /// Returns the number of addresses which have been written to at least once in each execution
+    /// context which accessed memory, keyed by the execution context ID.
+    pub fn get_stats(&self) -> BTreeMap<u32, usize> {
+        self.trace
+            .iter()
+            .map(|(&ctx, segment)| (ctx.into(), segment.num_written_addresses()))
+            .collect()
+    }
+
     // STATE ACCESSORS AND MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -62,6 +62,16 @@ impl MemorySegmentTrace {
         result
     }
 
+    /// Returns the number of addresses in this segment which have been written to at least once.
+    pub fn num_written_addresses(&self) -> usize {
+        self.0
+            .values()
+            .filter(|addr_trace| {
+                addr_trace.iter().any(|access| access.op == MemoryOperation::Write)
+            })
+            .count()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
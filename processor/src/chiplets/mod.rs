@@ -4,7 +4,7 @@ use super::{
     crypto::MerklePath, utils, ChipletsTrace, ExecutionError, Felt, FieldElement, RangeChecker,
     TraceFragment, Word, CHIPLETS_WIDTH, EMPTY_WORD, ONE, ZERO,
 };
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use miden_air::trace::chiplets::hasher::{Digest, HasherState};
 use vm_core::{code_blocks::OpBatch, Kernel};
 
@@ -346,6 +346,12 @@ impl Chiplets {
         self.memory.get_state_at(ctx, clk)
     }
 
+    /// Returns the number of addresses which have been written to at least once in each execution
+    /// context which accessed memory, keyed by the execution context ID.
+    pub fn get_mem_stats(&self) -> BTreeMap<u32, usize> {
+        self.memory.get_stats()
+    }
+
     /// Returns current size of the memory (in words) across all execution contexts.
     #[cfg(test)]
     pub fn get_mem_size(&self) -> usize {
@@ -2,10 +2,11 @@ use crate::{
     range::RangeChecker, system::ContextId, Chiplets, ChipletsLengths, Decoder, ExecutionError,
     Felt, Host, Process, Stack, System, TraceLenSummary,
 };
+use alloc::collections::BTreeSet;
 use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::fmt;
-use vm_core::{AssemblyOp, Operation, StackOutputs, Word};
+use vm_core::{AssemblyOp, Operation, SourceLocation, StackOutputs, StackTopState, Word};
 
 /// VmState holds a current process state information at a specific clock cycle.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -172,6 +173,38 @@ impl VmStateIterator {
         result
     }
 
+    /// Advances the iterator until the first cycle of an instruction located on a line with a
+    /// breakpoint in the provided registry, and returns the state at that cycle after invoking
+    /// `on_break` with it.
+    ///
+    /// Calling this method again resumes the iteration from the cycle following the one at which
+    /// the iteration was paused. Returns `None` if the execution completed without reaching any
+    /// of the breakpoints, or an error if the execution failed before reaching any of them.
+    ///
+    /// Breakpoints are matched against the source locations of the executed instructions, and
+    /// thus can be hit only for programs compiled in debug mode from sources with location
+    /// information (e.g., libraries read via `MaslLibrary::read_from_dir` with `locations` set).
+    pub fn resume<F>(
+        &mut self,
+        breakpoints: &Breakpoints,
+        mut on_break: F,
+    ) -> Option<Result<VmState, ExecutionError>>
+    where
+        F: FnMut(&VmState),
+    {
+        for state in self.by_ref() {
+            match state {
+                Ok(state) if breakpoints.is_hit(&state) => {
+                    on_break(&state);
+                    return Some(Ok(state));
+                }
+                Ok(_) => (),
+                Err(err) => return Some(Err(err)),
+            }
+        }
+        None
+    }
+
     pub fn into_parts(self) -> (System, Decoder, Stack, Chiplets, Option<ExecutionError>) {
         (self.system, self.decoder, self.stack, self.chiplets, self.error)
     }
@@ -243,6 +276,111 @@ impl Iterator for VmStateIterator {
     }
 }
 
+// INSTRUCTION LOG
+// ================================================================================================
+
+/// An entry of the instruction log, which describes the execution of a single operation.
+///
+/// The log is recorded only when it is enabled via [ExecutionOptions::with_instruction_log()].
+///
+/// [ExecutionOptions::with_instruction_log()]: crate::ExecutionOptions::with_instruction_log
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TraceEntry {
+    /// Clock cycle at which the operation was executed.
+    pub clk: u32,
+    /// The executed operation.
+    pub op: Operation,
+    /// Top 16 items of the stack before the operation was executed.
+    pub stack_before: StackTopState,
+    /// Top 16 items of the stack after the operation was executed.
+    pub stack_after: StackTopState,
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stack_before: Vec<u64> = self.stack_before.iter().map(|x| x.as_int()).collect();
+        let stack_after: Vec<u64> = self.stack_after.iter().map(|x| x.as_int()).collect();
+        write!(
+            f,
+            "clk={}, op={}, stack_before={stack_before:?}, stack_after={stack_after:?}",
+            self.clk, self.op
+        )
+    }
+}
+
+// MEMORY WRITE EVENT
+// ================================================================================================
+
+/// Describes a single write to memory, as observed by the host.
+///
+/// The host is notified about memory writes only when this is enabled via
+/// [ExecutionOptions::with_memory_write_events()].
+///
+/// [ExecutionOptions::with_memory_write_events()]: crate::ExecutionOptions::with_memory_write_events
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MemoryWriteEvent {
+    /// Execution context in which the write happened.
+    pub ctx: ContextId,
+    /// Address of the written word.
+    pub addr: u32,
+    /// The word stored at the address before the write; ZEROs if the address was not written to.
+    pub old_value: Word,
+    /// The word stored at the address after the write.
+    pub new_value: Word,
+    /// Clock cycle at which the write happened.
+    pub clk: u32,
+}
+
+// BREAKPOINTS
+// ================================================================================================
+
+/// A registry of breakpoints keyed by the source line of an instruction.
+///
+/// Source locations do not identify the source file of an instruction, and thus a breakpoint
+/// is hit by instructions located on the specified line of any module of the program.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Breakpoints {
+    lines: BTreeSet<u32>,
+}
+
+impl Breakpoints {
+    /// Returns a new empty [Breakpoints] registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a breakpoint on the specified line; returns `false` if the breakpoint was already set.
+    pub fn insert(&mut self, line: u32) -> bool {
+        self.lines.insert(line)
+    }
+
+    /// Removes a breakpoint from the specified line; returns `false` if no breakpoint was set.
+    pub fn remove(&mut self, line: u32) -> bool {
+        self.lines.remove(&line)
+    }
+
+    /// Returns `true` if a breakpoint is set on the specified line.
+    pub fn contains(&self, line: u32) -> bool {
+        self.lines.contains(&line)
+    }
+
+    /// Returns `true` if no breakpoints are set.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// Returns `true` if the provided state is the first cycle of an instruction located on a
+    /// line with a breakpoint.
+    pub fn is_hit(&self, state: &VmState) -> bool {
+        match &state.asmop {
+            Some(asmop) if asmop.cycle_idx() == 1 => {
+                asmop.location().map(|location| self.contains(location.line())).unwrap_or(false)
+            }
+            _ => false,
+        }
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 fn word_to_ints(word: &Word) -> [u64; 4] {
@@ -300,6 +438,11 @@ impl AsmOpInfo {
         self.cycle_idx
     }
 
+    /// Returns the location of the assembly instruction in the source code, if known.
+    pub const fn location(&self) -> Option<&SourceLocation> {
+        self.asmop.location()
+    }
+
     /// Returns `true` if the debug should break for this line.
     pub const fn should_break(&self) -> bool {
         self.asmop.should_break()
@@ -190,6 +190,7 @@ where
             self.system.start_call(fn_hash);
             self.decoder.start_call(fn_hash, addr, ctx_info);
         }
+        self.context_switches += 1;
 
         // the rest of the VM state does not change
         self.execute_op(Operation::Noop)
@@ -200,7 +201,12 @@ where
         // when a CALL block ends, stack depth must be exactly 16
         let stack_depth = self.stack.depth();
         if stack_depth > STACK_TOP_SIZE {
-            return Err(ExecutionError::InvalidStackDepthOnReturn(stack_depth));
+            let overflow_values = self.stack.get_overflow_values();
+            return Err(ExecutionError::InvalidStackDepthOnReturn(
+                stack_depth,
+                overflow_values,
+                None,
+            ));
         }
 
         // this appends a row with END operation to the decoder trace; the returned value contains
@@ -3,9 +3,9 @@ use super::{
     system::{FMP_MAX, FMP_MIN},
     CodeBlock, Digest, Felt, QuadFelt, Word,
 };
-use alloc::string::String;
+use alloc::{string::String, vec::Vec};
 use core::fmt::{Display, Formatter};
-use vm_core::{stack::STACK_TOP_SIZE, utils::to_hex};
+use vm_core::{stack::STACK_TOP_SIZE, utils::to_hex, SourceLocation};
 use winter_prover::{math::FieldElement, ProverError};
 
 #[cfg(feature = "std")]
@@ -31,6 +31,7 @@ pub enum ExecutionError {
         err_msg: Option<String>,
     },
     FailedSignatureGeneration(&'static str),
+    InstructionLogWriteFailed(String),
     InvalidFmpValue(Felt, Felt),
     InvalidFriDomainSegment(u64),
     InvalidFriLayerFolding(QuadFelt, QuadFelt),
@@ -38,7 +39,7 @@ pub enum ExecutionError {
         start_addr: u64,
         end_addr: u64,
     },
-    InvalidStackDepthOnReturn(usize),
+    InvalidStackDepthOnReturn(usize, Vec<Felt>, Option<SourceLocation>),
     InvalidStackWordOffset(usize),
     InvalidTreeDepth {
         depth: Felt,
@@ -49,7 +50,7 @@ pub enum ExecutionError {
     },
     LogArgumentZero(u32),
     MalformedSignatureKey(&'static str),
-    MemoryAddressOutOfBounds(u64),
+    MemoryAddressOutOfBounds(u64, Option<SourceLocation>),
     MerklePathVerificationFailed {
         value: Word,
         index: Felt,
@@ -58,13 +59,34 @@ pub enum ExecutionError {
     MerkleStoreLookupFailed(MerkleError),
     MerkleStoreMergeFailed(MerkleError),
     MerkleStoreUpdateFailed(MerkleError),
+    NestedSyscall(Digest),
     NotBinaryValue(Felt),
     NotU32Value(Felt, Felt),
     ProverError(ProverError),
     SmtNodeNotFound(Word),
     SmtNodePreImageNotValid(Word, usize),
+    StackDepthLimitExceeded {
+        depth: usize,
+        limit: usize,
+    },
     SyscallTargetNotInKernel(Digest),
     UnexecutableCodeBlock(CodeBlock),
+    UnexpectedOverflow(u32),
+}
+
+impl ExecutionError {
+    /// Attaches the provided source location to this error if the error can carry a location and
+    /// does not have one yet; otherwise, returns the error unchanged.
+    pub(crate) fn with_location(self, location: Option<SourceLocation>) -> Self {
+        use ExecutionError::*;
+        match self {
+            InvalidStackDepthOnReturn(depth, overflow_values, None) => {
+                InvalidStackDepthOnReturn(depth, overflow_values, location)
+            }
+            MemoryAddressOutOfBounds(addr, None) => MemoryAddressOutOfBounds(addr, location),
+            err => err,
+        }
+    }
 }
 
 impl Display for ExecutionError {
@@ -117,6 +139,9 @@ impl Display for ExecutionError {
             FailedSignatureGeneration(signature) => {
                 write!(f, "Failed to generate signature: {signature}")
             }
+            InstructionLogWriteFailed(error) => {
+                write!(f, "Failed to write an instruction log entry: {error}")
+            }
             InvalidFmpValue(old, new) => {
                 write!(f, "Updating FMP register from {old} to {new} failed because {new} is outside of {FMP_MIN}..{FMP_MAX}")
             }
@@ -132,8 +157,11 @@ impl Display for ExecutionError {
             } => {
                 write!(f, "Memory range start address cannot exceed end address, but was ({start_addr}, {end_addr})")
             }
-            InvalidStackDepthOnReturn(depth) => {
-                write!(f, "When returning from a call, stack depth must be {STACK_TOP_SIZE}, but was {depth}")
+            InvalidStackDepthOnReturn(depth, overflow_values, location) => {
+                write!(f, "When returning from a call, stack depth must be {STACK_TOP_SIZE}, but was {depth}")?;
+                let values = overflow_values.iter().map(|value| value.as_int()).collect::<Vec<_>>();
+                write!(f, "; values remaining in the overflow table: {values:?}")?;
+                write_location(f, location)
             }
             InvalidStackWordOffset(offset) => {
                 write!(f, "Stack word offset cannot exceed 12, but was {offset}")
@@ -151,8 +179,9 @@ impl Display for ExecutionError {
                 )
             }
             MalformedSignatureKey(signature) => write!(f, "Malformed signature key: {signature}"),
-            MemoryAddressOutOfBounds(addr) => {
-                write!(f, "Memory address cannot exceed 2^32 but was {addr}")
+            MemoryAddressOutOfBounds(addr, location) => {
+                write!(f, "Memory address cannot exceed 2^32 but was {addr}")?;
+                write_location(f, location)
             }
             MerklePathVerificationFailed { value, index, root } => {
                 let value = to_hex(Felt::elements_as_bytes(value))?;
@@ -168,6 +197,13 @@ impl Display for ExecutionError {
             MerkleStoreUpdateFailed(reason) => {
                 write!(f, "Advice provider Merkle store backend update failed: {reason}")
             }
+            NestedSyscall(proc) => {
+                let hex = to_hex(&proc.as_bytes())?;
+                write!(
+                    f,
+                    "Syscall to procedure with root {hex} failed: already executing a syscall"
+                )
+            }
             NotBinaryValue(v) => {
                 write!(f, "An operation expected a binary value, but received {v}")
             }
@@ -186,6 +222,9 @@ impl Display for ExecutionError {
                 write!(f, "Invalid pre-image for node {node_hex}. Expected pre-image length to be a multiple of 8, but was {preimage_len}")
             }
             ProverError(error) => write!(f, "Proof generation failed: {error}"),
+            StackDepthLimitExceeded { depth, limit } => {
+                write!(f, "Stack depth of {depth} exceeds the allowed limit of {limit} items")
+            }
             SyscallTargetNotInKernel(proc) => {
                 let hex = to_hex(&proc.as_bytes())?;
                 write!(f, "Syscall failed: procedure with root {hex} was not found in the kernel")
@@ -193,6 +232,9 @@ impl Display for ExecutionError {
             UnexecutableCodeBlock(block) => {
                 write!(f, "Execution reached unexecutable code block {block:?}")
             }
+            UnexpectedOverflow(clk) => {
+                write!(f, "Stack overflowed its top {STACK_TOP_SIZE} items at clock cycle {clk}, but a fixed stack depth was required")
+            }
         }
     }
 }
@@ -200,6 +242,17 @@ impl Display for ExecutionError {
 #[cfg(feature = "std")]
 impl Error for ExecutionError {}
 
+/// Writes the source location of an error, if known.
+fn write_location(
+    f: &mut Formatter<'_>,
+    location: &Option<SourceLocation>,
+) -> Result<(), core::fmt::Error> {
+    match location {
+        Some(location) => write!(f, " at {location}"),
+        None => Ok(()),
+    }
+}
+
 impl From<Ext2InttError> for ExecutionError {
     fn from(value: Ext2InttError) -> Self {
         Self::Ext2InttError(value)
@@ -162,7 +162,7 @@ pub(crate) fn merge_merkle_nodes<S: ProcessState, A: AdviceProvider>(
 
 /// Reads (start_addr, end_addr) tuple from the specified elements of the operand stack (
 /// without modifying the state of the stack), and verifies that memory range is valid.
-fn get_mem_addr_range<S: ProcessState>(
+pub(super) fn get_mem_addr_range<S: ProcessState>(
     process: &S,
     start_idx: usize,
     end_idx: usize,
@@ -171,10 +171,10 @@ fn get_mem_addr_range<S: ProcessState>(
     let end_addr = process.get_stack_item(end_idx).as_int();
 
     if start_addr > u32::MAX as u64 {
-        return Err(ExecutionError::MemoryAddressOutOfBounds(start_addr));
+        return Err(ExecutionError::MemoryAddressOutOfBounds(start_addr, None));
     }
     if end_addr > u32::MAX as u64 {
-        return Err(ExecutionError::MemoryAddressOutOfBounds(end_addr));
+        return Err(ExecutionError::MemoryAddressOutOfBounds(end_addr, None));
     }
 
     if start_addr > end_addr {
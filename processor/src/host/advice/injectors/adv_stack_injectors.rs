@@ -1,7 +1,10 @@
-use super::super::{AdviceSource, ExecutionError, Felt, HostResponse};
+use super::{
+    super::{AdviceSource, ExecutionError, Felt, HostResponse},
+    adv_map_injectors::get_mem_addr_range,
+};
 use crate::{AdviceProvider, Ext2InttError, FieldElement, ProcessState, ZERO};
 use alloc::vec::Vec;
-use vm_core::{QuadExtension, SignatureKind};
+use vm_core::{QuadExtension, SignatureKind, EMPTY_WORD};
 use winter_prover::math::fft;
 
 // TYPE ALIASES
@@ -57,6 +60,27 @@ pub(crate) fn copy_merkle_node_to_adv_stack<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes ONE onto the advice stack if a Merkle tree with the root specified by the word at the
+/// top of the operand stack is present in the advice provider, and ZERO otherwise.
+///
+/// Inputs:
+///   Operand stack: [ROOT, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [ROOT, ...]
+///   Advice stack: [has_root, ...]
+pub(crate) fn push_has_merkle_root<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let root = process.get_stack_word(0);
+    let has_root = advice_provider.contains_merkle_root(root);
+    advice_provider.push_stack(AdviceSource::Value(Felt::from(has_root)))?;
+
+    Ok(HostResponse::None)
+}
+
 /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
 /// map using the specified word from the operand stack as the key. If `include_len` is set to
 /// true, the number of elements in the value is also pushed onto the advice stack.
@@ -102,6 +126,87 @@ pub(crate) fn copy_map_value_to_adv_stack<S: ProcessState, A: AdviceProvider>(
     Ok(HostResponse::None)
 }
 
+/// Pushes the number of elements in a list of field elements onto the advice stack. The list
+/// is looked up in the advice map using the specified word from the operand stack as the key;
+/// the elements of the list themselves are not pushed.
+///
+/// Inputs:
+///   Operand stack: [..., KEY, ...]
+///   Advice stack: [...]
+///   Advice map: {KEY: values}
+///
+/// Outputs:
+///   Operand stack: [..., KEY, ...]
+///   Advice stack: [values_len, ...]
+///   Advice map: {KEY: values}
+///
+/// The `key_offset` value specifies the location of the `KEY` on the stack in the same way as
+/// for the map value injector, i.e., the valid values of `key_offset` are 0 through 12
+/// (inclusive).
+///
+/// # Errors
+/// Returns an error if the required key was not found in the key-value map or if stack offset
+/// is greater than 12.
+pub(crate) fn copy_map_value_len_to_adv_stack<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+    key_offset: usize,
+) -> Result<HostResponse, ExecutionError> {
+    if key_offset > 12 {
+        return Err(ExecutionError::InvalidStackWordOffset(key_offset));
+    }
+
+    let key = [
+        process.get_stack_item(key_offset + 3),
+        process.get_stack_item(key_offset + 2),
+        process.get_stack_item(key_offset + 1),
+        process.get_stack_item(key_offset),
+    ];
+    let num_values = advice_provider
+        .get_mapped_values(&key.into())
+        .ok_or(ExecutionError::AdviceMapKeyNotFound(key))?
+        .len();
+    let num_values = Felt::try_from(num_values as u64).expect("value length too big");
+    advice_provider.push_stack(AdviceSource::Value(num_values))?;
+
+    Ok(HostResponse::None)
+}
+
+/// Reads words from memory at the specified range and pushes their elements onto the advice
+/// stack.
+///
+/// Inputs:
+///   Operand stack: [start_addr, end_addr, ...]
+///   Advice stack: [...]
+///
+/// Outputs:
+///   Operand stack: [start_addr, end_addr, ...]
+///   Advice stack: [values, ...]
+///
+/// Where `values` are the elements located in memory[start_addr..end_addr], placed such that the
+/// first element of the word at `start_addr` is read from the advice stack first.
+///
+/// # Errors
+/// Returns an error:
+/// - `start_addr` is greater than or equal to 2^32.
+/// - `end_addr` is greater than or equal to 2^32.
+/// - `start_addr` > `end_addr`.
+pub(crate) fn copy_mem_values_to_adv_stack<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let (start_addr, end_addr) = get_mem_addr_range(process, 0, 1)?;
+    let ctx = process.ctx();
+
+    // push the words in the reverse order, so that the word at start_addr is read first
+    for addr in (start_addr..end_addr).rev() {
+        let mem_value = process.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD);
+        advice_provider.push_stack(AdviceSource::Word(mem_value))?;
+    }
+
+    Ok(HostResponse::None)
+}
+
 /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
 /// stack.
 ///
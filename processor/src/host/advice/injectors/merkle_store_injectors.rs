@@ -1,4 +1,5 @@
 use super::super::{AdviceProvider, ExecutionError, HostResponse, ProcessState};
+use vm_core::crypto::merkle::MerkleError;
 
 pub(crate) fn update_operand_stack_merkle_node<S: ProcessState, A: AdviceProvider>(
     advice_provider: &mut A,
@@ -21,3 +22,16 @@ pub(crate) fn update_operand_stack_merkle_node<S: ProcessState, A: AdviceProvide
     let (path, _) = advice_provider.update_merkle_node(old_root, &depth, &index, new_node)?;
     Ok(HostResponse::MerklePath(path))
 }
+
+pub(crate) fn check_merkle_root<S: ProcessState, A: AdviceProvider>(
+    advice_provider: &mut A,
+    process: &S,
+) -> Result<HostResponse, ExecutionError> {
+    let root = process.get_stack_word(0);
+    if !advice_provider.contains_merkle_root(root) {
+        return Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::RootNotInStore(
+            root.into(),
+        )));
+    }
+    Ok(HostResponse::None)
+}
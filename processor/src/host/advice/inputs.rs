@@ -1,6 +1,10 @@
-use super::{AdviceMap, Felt, InnerNodeInfo, InputError, MerkleStore};
+use super::{AdviceMap, Felt, InnerNodeInfo, InputError, MerkleStore, Word};
 use alloc::vec::Vec;
-use vm_core::crypto::hash::RpoDigest;
+use vm_core::{
+    crypto::{hash::RpoDigest, merkle::MerkleTree},
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    EMPTY_WORD,
+};
 
 // ADVICE INPUTS
 // ================================================================================================
@@ -17,7 +21,7 @@ use vm_core::crypto::hash::RpoDigest;
 /// 3. Merkle store, which is used to provide nondeterministic inputs for instructions that
 ///    operates with Merkle trees.
 #[cfg(not(feature = "internals"))]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AdviceInputs {
     stack: Vec<Felt>,
     map: AdviceMap,
@@ -86,6 +90,45 @@ impl AdviceInputs {
         self.map.extend(iter);
     }
 
+    /// Inserts the provided values into the map under the key specified by its 32-byte
+    /// little-endian encoding, replacing previously inserted values.
+    ///
+    /// The key bytes are interpreted as four 8-byte chunks, each of which encodes a single
+    /// element of the key word as a little-endian integer, starting with the first element of
+    /// the word. This is the same layout as the one produced by [RpoDigest::as_bytes()], and thus
+    /// the values are retrievable via the equivalent word (e.g., via `adv.push_mapval`).
+    ///
+    /// # Errors
+    /// Returns an error if any of the chunks encodes an integer which is not a valid field
+    /// element; in this case the map is not modified.
+    pub fn insert_map_entry_le(
+        &mut self,
+        key_bytes: [u8; 32],
+        values: Vec<Felt>,
+    ) -> Result<(), InputError> {
+        let mut key = [Felt::default(); 4];
+        for (element, chunk) in key.iter_mut().zip(key_bytes.chunks_exact(8)) {
+            let value = u64::from_le_bytes(chunk.try_into().expect("chunk is 8 bytes long"));
+            *element = Felt::try_from(value).map_err(|e| InputError::NotFieldElement(value, e))?;
+        }
+        self.map.insert(RpoDigest::from(key), values);
+        Ok(())
+    }
+
+    /// Builds a Merkle tree from the provided leaves, adds all of its nodes to the [MerkleStore],
+    /// and returns the root of the tree.
+    ///
+    /// The tree is balanced: if the number of leaves is not a power of two (or is smaller than
+    /// two), the leaves are padded with empty words up to the next power of two. Thus, the depth
+    /// of the tree is the base 2 logarithm of the padded number of leaves.
+    pub fn insert_merkle_tree(&mut self, mut leaves: Vec<Word>) -> RpoDigest {
+        let num_leaves = leaves.len().next_power_of_two().max(2);
+        leaves.resize(num_leaves, EMPTY_WORD);
+        let tree = MerkleTree::new(leaves).expect("number of leaves is a power of two");
+        self.store.extend(tree.inner_nodes());
+        tree.root()
+    }
+
     /// Extends the [MerkleStore] with the given nodes.
     pub fn extend_merkle_store<I>(&mut self, iter: I)
     where
@@ -130,11 +173,38 @@ impl AdviceInputs {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+/// The serialized form of the advice inputs is canonical: the advice stack is written in order,
+/// while both the advice map and the Merkle store are backed by ordered maps, and thus their
+/// entries are written sorted by key. Equal inputs are therefore always serialized into the same
+/// bytes.
+impl Serializable for AdviceInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let Self { stack, map, store } = self;
+        target.write_usize(stack.len());
+        target.write_many(stack);
+        map.write_into(target);
+        store.write_into(target);
+    }
+}
+
+impl Deserializable for AdviceInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let stack_len = source.read_usize()?;
+        let stack = source.read_many::<Felt>(stack_len)?;
+        let map = AdviceMap::read_from(source)?;
+        let store = MerkleStore::read_from(source)?;
+        Ok(Self { stack, map, store })
+    }
+}
+
 // INTERNALS
 // ================================================================================================
 
 #[cfg(feature = "internals")]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AdviceInputs {
     pub stack: Vec<Felt>,
     pub map: AdviceMap,
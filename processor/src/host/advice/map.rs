@@ -2,7 +2,10 @@ use super::Felt;
 use alloc::collections::btree_map::IntoIter;
 use alloc::collections::BTreeMap;
 use alloc::vec::Vec;
-use vm_core::crypto::hash::RpoDigest;
+use vm_core::{
+    crypto::hash::RpoDigest,
+    utils::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
 
 // ADVICE MAP
 // ================================================================================================
@@ -12,7 +15,7 @@ use vm_core::crypto::hash::RpoDigest;
 /// Each key maps to one or more field element. To access the elements, the VM can move the values
 /// associated with a given key onto the advice stack using `adv.push_mapval` instruction. The VM
 /// can also insert new values into the advice map during execution.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct AdviceMap(BTreeMap<RpoDigest, Vec<Felt>>);
 
 impl AdviceMap {
@@ -57,3 +60,31 @@ impl Extend<(RpoDigest, Vec<Felt>)> for AdviceMap {
         self.0.extend(iter)
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AdviceMap {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_usize(self.0.len());
+        for (key, values) in self.0.iter() {
+            key.write_into(target);
+            target.write_usize(values.len());
+            target.write_many(values);
+        }
+    }
+}
+
+impl Deserializable for AdviceMap {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let mut map = BTreeMap::new();
+        let count = source.read_usize()?;
+        for _ in 0..count {
+            let key = RpoDigest::read_from(source)?;
+            let values_len = source.read_usize()?;
+            let values = source.read_many::<Felt>(values_len)?;
+            map.insert(key, values);
+        }
+        Ok(Self(map))
+    }
+}
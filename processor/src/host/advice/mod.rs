@@ -1,13 +1,13 @@
 use super::HostResponse;
 use crate::{ExecutionError, Felt, InputError, ProcessState, Word};
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 use core::borrow::Borrow;
 use vm_core::{
     crypto::{
         hash::RpoDigest,
-        merkle::{InnerNodeInfo, MerklePath, MerkleStore, NodeIndex, StoreNode},
+        merkle::{InnerNodeInfo, MerkleError, MerklePath, MerkleStore, NodeIndex, StoreNode},
     },
-    AdviceInjector, SignatureKind,
+    AdviceInjector, SignatureKind, ZERO,
 };
 
 mod extractors;
@@ -27,6 +27,19 @@ pub use source::AdviceSource;
 mod map;
 pub use map::AdviceMap;
 
+#[cfg(test)]
+mod tests;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The maximum depth of a Merkle tree node which can be addressed by a [NodeIndex].
+const MAX_NODE_DEPTH: u32 = u64::BITS;
+
+/// The maximum depth of a Merkle tree whose leaves can be listed via
+/// [AdviceProvider::get_tree_leaves()].
+const MAX_LISTED_TREE_DEPTH: u32 = 16;
+
 // ADVICE PROVIDER
 // ================================================================================================
 
@@ -57,11 +70,17 @@ pub trait AdviceProvider: Sized {
     ) -> Result<HostResponse, ExecutionError> {
         match advice_injector {
             AdviceInjector::MerkleNodeMerge => self.merge_merkle_nodes(process),
+            AdviceInjector::MerkleRootCheck => self.check_merkle_root(process),
             AdviceInjector::MerkleNodeToStack => self.copy_merkle_node_to_adv_stack(process),
+            AdviceInjector::HasMerkleRoot => self.push_has_merkle_root(process),
             AdviceInjector::MapValueToStack {
                 include_len,
                 key_offset,
             } => self.copy_map_value_to_adv_stack(process, *include_len, *key_offset),
+            AdviceInjector::MapValueLenToStack { key_offset } => {
+                self.copy_map_value_len_to_adv_stack(process, *key_offset)
+            }
+            AdviceInjector::MemToStack => self.copy_mem_values_to_adv_stack(process),
             AdviceInjector::UpdateMerkleNode => self.update_operand_stack_merkle_node(process),
             AdviceInjector::U64Div => self.push_u64_div_result(process),
             AdviceInjector::Ext2Inv => self.push_ext2_inv_result(process),
@@ -79,6 +98,9 @@ pub trait AdviceProvider: Sized {
             AdviceInjector::HdwordToMap { domain } => {
                 self.insert_hdword_into_adv_map(process, *domain)
             }
+            // the process resolves the default domain from its execution options before invoking
+            // the host, and thus this is reached only when the host is invoked directly
+            AdviceInjector::HdwordToMapDefault => self.insert_hdword_into_adv_map(process, ZERO),
             AdviceInjector::HpermToMap => self.insert_hperm_into_adv_map(process),
             AdviceInjector::SigToStack { kind } => self.push_signature(process, *kind),
         }
@@ -191,6 +213,27 @@ pub trait AdviceProvider: Sized {
         injectors::adv_map_injectors::merge_merkle_nodes(self, process)
     }
 
+    /// Checks that a Merkle tree with the root specified by the word at the top of the operand
+    /// stack is present in the advice provider.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Merkle store: {ROOT}
+    ///
+    /// Outputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Merkle store: {ROOT}
+    ///
+    /// # Errors
+    /// Returns an error if a Merkle tree for the specified root cannot be found in the advice
+    /// provider.
+    fn check_merkle_root<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::merkle_store_injectors::check_merkle_root(self, process)
+    }
+
     // DEFAULT ADVICE STACK INJECTORS
     // --------------------------------------------------------------------------------------------
 
@@ -220,6 +263,23 @@ pub trait AdviceProvider: Sized {
         injectors::adv_stack_injectors::copy_merkle_node_to_adv_stack(self, process)
     }
 
+    /// Pushes ONE onto the advice stack if a Merkle tree with the root specified by the word at
+    /// the top of the operand stack is present in the advice provider, and ZERO otherwise.
+    ///
+    /// Inputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [ROOT, ...]
+    ///   Advice stack: [has_root, ...]
+    fn push_has_merkle_root<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::push_has_merkle_root(self, process)
+    }
+
     /// Pushes a list of field elements onto the advice stack. The list is looked up in the advice
     /// map using the specified word from the operand stack as the key. If `include_len` is set to
     /// true, the number of elements in the value is also pushed onto the advice stack.
@@ -258,6 +318,61 @@ pub trait AdviceProvider: Sized {
         )
     }
 
+    /// Pushes the number of elements in a list of field elements onto the advice stack. The list
+    /// is looked up in the advice map using the specified word from the operand stack as the key;
+    /// the elements of the list themselves are not pushed.
+    ///
+    /// Inputs:
+    ///   Operand stack: [..., KEY, ...]
+    ///   Advice stack: [...]
+    ///   Advice map: {KEY: values}
+    ///
+    /// Outputs:
+    ///   Operand stack: [..., KEY, ...]
+    ///   Advice stack: [values_len, ...]
+    ///   Advice map: {KEY: values}
+    ///
+    /// The `key_offset` value specifies the location of the `KEY` on the stack in the same way as
+    /// for the map value injector, i.e., the valid values of `key_offset` are 0 through 12
+    /// (inclusive).
+    ///
+    /// # Errors
+    /// Returns an error if the required key was not found in the key-value map or if stack offset
+    /// is greater than 12.
+    fn copy_map_value_len_to_adv_stack<S: ProcessState>(
+        &mut self,
+        process: &S,
+        key_offset: usize,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::copy_map_value_len_to_adv_stack(self, process, key_offset)
+    }
+
+    /// Reads words from memory at the specified range and pushes their elements onto the advice
+    /// stack.
+    ///
+    /// Inputs:
+    ///   Operand stack: [start_addr, end_addr, ...]
+    ///   Advice stack: [...]
+    ///
+    /// Outputs:
+    ///   Operand stack: [start_addr, end_addr, ...]
+    ///   Advice stack: [values, ...]
+    ///
+    /// Where `values` are the elements located in memory[start_addr..end_addr], placed such that
+    /// the first element of the word at `start_addr` is read from the advice stack first.
+    ///
+    /// # Errors
+    /// Returns an error:
+    /// - `start_addr` is greater than or equal to 2^32.
+    /// - `end_addr` is greater than or equal to 2^32.
+    /// - `start_addr` > `end_addr`.
+    fn copy_mem_values_to_adv_stack<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<HostResponse, ExecutionError> {
+        injectors::adv_stack_injectors::copy_mem_values_to_adv_stack(self, process)
+    }
+
     /// Pushes the result of [u64] division (both the quotient and the remainder) onto the advice
     /// stack.
     ///
@@ -567,6 +682,16 @@ pub trait AdviceProvider: Sized {
         self
     }
 
+    /// Returns the current state of this advice provider as [AdviceInputs], i.e., the values
+    /// remaining on the advice stack, the advice map, and the Merkle store.
+    ///
+    /// Executing a program against the returned inputs resumes from the current state of this
+    /// provider. Returns `None` if this advice provider does not support exporting its state,
+    /// which is the case by default.
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        None
+    }
+
     // REQUIRED METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -595,18 +720,52 @@ pub trait AdviceProvider: Sized {
     /// two words: `[h, g, f,e ], [d, c, b, a]`.
     ///
     /// # Errors
-    /// Returns an error if the advice stack does not contain two words.
+    /// Returns an error if the advice stack does not contain two words; in this case, the advice
+    /// stack is left unchanged.
     fn pop_stack_dword<S: ProcessState>(
         &mut self,
         process: &S,
     ) -> Result<[Word; 2], ExecutionError>;
 
+    /// Pops a word (4 elements) from the advice stack and returns its elements in the order in
+    /// which they are stored on the stack, i.e., with the top element of the stack last.
+    ///
+    /// This is the reverse of [AdviceProvider::pop_stack_word()]: a `[d, c, b, a, ...]` stack
+    /// (i.e., `d` is at the top of the stack) will yield `[a, b, c, d]`. A word written via
+    /// [AdviceProvider::push_stack_word_raw()] is read back unchanged by this method.
+    ///
+    /// # Errors
+    /// Returns an error if the advice stack does not contain a full word.
+    fn pop_stack_word_raw<S: ProcessState>(&mut self, process: &S) -> Result<Word, ExecutionError> {
+        let [d, c, b, a] = self.pop_stack_word(process)?;
+        Ok([a, b, c, d])
+    }
+
+    /// Pushes the elements of the provided word onto the advice stack in storage order, i.e., the
+    /// last element of the word ends up at the top of the stack.
+    ///
+    /// This is the reverse of pushing [AdviceSource::Word]: pushing `[a, b, c, d]` onto a
+    /// `[e, ...]` stack results in `[d, c, b, a, e, ...]` stack.
+    fn push_stack_word_raw(&mut self, word: Word) -> Result<(), ExecutionError> {
+        let [a, b, c, d] = word;
+        self.push_stack(AdviceSource::Word([d, c, b, a]))
+    }
+
     /// Pushes the value(s) specified by the source onto the advice stack.
     ///
+    /// The values are placed at the top of the advice stack, and thus will be read before any of
+    /// the values already on the advice stack. For example, pushing `a` onto a `[b, c, ...]`
+    /// stack results in `[a, b, c, ...]` stack, and the next value popped from the stack is `a`.
+    ///
     /// # Errors
     /// Returns an error if the value specified by the advice source cannot be obtained.
     fn push_stack(&mut self, source: AdviceSource) -> Result<(), ExecutionError>;
 
+    /// Removes all values from the advice stack.
+    ///
+    /// The advice map and the Merkle store of this advice provider are not affected.
+    fn clear_stack(&mut self);
+
     // ADVICE MAP
     // --------------------------------------------------------------------------------------------
 
@@ -633,6 +792,40 @@ pub trait AdviceProvider: Sized {
     // MERKLE STORE
     // --------------------------------------------------------------------------------------------
 
+    /// Returns true if a Merkle tree with the specified root is present in this advice provider.
+    fn contains_merkle_root(&self, root: Word) -> bool;
+
+    /// Returns the depth of the Merkle tree with the specified root, or `None` if a Merkle tree
+    /// with this root is not present in this advice provider.
+    ///
+    /// The depth is determined by following the leftmost path of the tree from its root until a
+    /// node which is not an internal node of any tree known to this advice provider is reached.
+    /// Thus, if the leftmost leaf of the tree is itself the root of a known tree, the depth of
+    /// that tree is included in the result.
+    ///
+    /// `None` is also returned if the depth of the tree exceeds 64, as nodes deeper than that
+    /// cannot be addressed.
+    fn get_root_depth(&self, root: Word) -> Option<u32> {
+        if !self.contains_merkle_root(root) {
+            return None;
+        }
+        let mut depth = 0;
+        while depth < MAX_NODE_DEPTH
+            && self.get_tree_node(root, &Felt::from(depth + 1), &Felt::from(0_u32)).is_ok()
+        {
+            depth += 1;
+        }
+
+        // the leftmost node at the maximum depth may have children only if the tree is deeper
+        if depth == MAX_NODE_DEPTH {
+            let node = self.get_tree_node(root, &Felt::from(depth), &Felt::from(0_u32)).ok()?;
+            if self.get_tree_node(node, &Felt::from(1_u32), &Felt::from(0_u32)).is_ok() {
+                return None;
+            }
+        }
+        Some(depth)
+    }
+
     /// Returns a node at the specified depth and index in a Merkle tree with the given root.
     ///
     /// # Errors
@@ -644,6 +837,40 @@ pub trait AdviceProvider: Sized {
     fn get_tree_node(&self, root: Word, depth: &Felt, index: &Felt)
         -> Result<Word, ExecutionError>;
 
+    /// Returns all leaves of the Merkle tree with the specified root as `(index, leaf)` pairs,
+    /// ordered by index.
+    ///
+    /// Leaves are the nodes at the depth of the tree as reported by
+    /// [AdviceProvider::get_root_depth]. All `2^depth` leaves are returned, and thus this method
+    /// is meant for trees of small depth only (e.g., for debugging or taking snapshots); trees
+    /// deeper than 16 are rejected.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A Merkle tree for the specified root cannot be found in this advice provider.
+    /// - The depth of the tree is greater than 16.
+    /// - Value of any of the leaves is not known to this advice provider.
+    fn get_tree_leaves(&self, root: Word) -> Result<Vec<(u64, Word)>, ExecutionError> {
+        let depth = self.get_root_depth(root).ok_or_else(|| {
+            ExecutionError::MerkleStoreLookupFailed(MerkleError::RootNotInStore(root.into()))
+        })?;
+        if depth == 0 {
+            return Ok(vec![(0, root)]);
+        }
+        if depth > MAX_LISTED_TREE_DEPTH {
+            return Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::DepthTooBig(
+                depth as u64,
+            )));
+        }
+        let depth_felt = Felt::from(depth);
+        (0..1_u64 << depth)
+            .map(|index| {
+                let leaf = self.get_tree_node(root, &depth_felt, &Felt::new(index))?;
+                Ok((index, leaf))
+            })
+            .collect()
+    }
+
     /// Returns a path to a node at the specified depth and index in a Merkle tree with the
     /// specified root.
     ///
@@ -681,6 +908,11 @@ pub trait AdviceProvider: Sized {
     /// The tree is cloned prior to the update. Thus, the advice provider retains the original and
     /// the updated tree.
     ///
+    /// Since the nodes of the trees are addressed by their hashes, a tree is fully determined by
+    /// its root. Thus, if the new root is already present in the advice provider (e.g., because
+    /// the same update was applied before), the updated tree is identical to the existing one, and
+    /// no other tree is overwritten by the update.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - A Merkle tree for the specified root cannot be found in this advice provider.
@@ -706,6 +938,21 @@ pub trait AdviceProvider: Sized {
     /// this advice provider.
     fn merge_roots(&mut self, lhs: Word, rhs: Word) -> Result<Word, ExecutionError>;
 
+    /// Adds all Merkle trees from the provided sets to this advice provider.
+    ///
+    /// The sets are keyed by the root of the Merkle tree they contain, and each set is expected to
+    /// contain the nodes of the tree with that root (e.g., as built by separate workers). Since
+    /// Merkle store nodes are addressed by their hash, trees already present in this advice
+    /// provider are merged with the new ones rather than overwritten.
+    ///
+    /// The sets are validated before any of them is added; thus, if an error is returned, this
+    /// advice provider is left unchanged.
+    ///
+    /// # Errors
+    /// Returns an error if a root used as a key is not the root of a tree in its set, i.e., the
+    /// key collides with a different tree than the one it was supposed to identify.
+    fn merge_sets(&mut self, sets: BTreeMap<RpoDigest, MerkleStore>) -> Result<(), ExecutionError>;
+
     /// Returns a subset of this Merkle store such that the returned Merkle store contains all
     /// nodes which are descendants of the specified roots.
     ///
@@ -739,6 +986,10 @@ where
         T::push_stack(self, source)
     }
 
+    fn clear_stack(&mut self) {
+        T::clear_stack(self)
+    }
+
     fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
         T::insert_into_map(self, key, values)
     }
@@ -756,6 +1007,10 @@ where
         T::get_mapped_values(self, key)
     }
 
+    fn contains_merkle_root(&self, root: Word) -> bool {
+        T::contains_merkle_root(self, root)
+    }
+
     fn get_tree_node(
         &self,
         root: Word,
@@ -797,6 +1052,10 @@ where
         T::merge_roots(self, lhs, rhs)
     }
 
+    fn merge_sets(&mut self, sets: BTreeMap<RpoDigest, MerkleStore>) -> Result<(), ExecutionError> {
+        T::merge_sets(self, sets)
+    }
+
     fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
     where
         I: Iterator<Item = R>,
@@ -804,4 +1063,8 @@ where
     {
         T::get_store_subset(self, roots)
     }
+
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        T::advice_state(self)
+    }
 }
@@ -33,6 +33,9 @@ where
     stack: Vec<Felt>,
     map: M,
     store: MerkleStore<S>,
+    /// The value read from the advice stack once it is exhausted; if not set, reading from an
+    /// exhausted advice stack results in an error.
+    stack_default: Option<Felt>,
 }
 
 impl<M, S> From<AdviceInputs> for BaseAdviceProvider<M, S>
@@ -47,10 +50,51 @@ where
             stack,
             map: map.into_iter().collect(),
             store: store.inner_nodes().collect(),
+            stack_default: None,
         }
     }
 }
 
+impl<M, S> BaseAdviceProvider<M, S>
+where
+    M: KvMap<RpoDigest, Vec<Felt>>,
+    S: KvMap<RpoDigest, StoreNode>,
+{
+    /// Sets the value returned by reads from the advice stack once the stack is exhausted.
+    ///
+    /// By default, reading from an exhausted advice stack results in an error.
+    pub fn with_stack_default(mut self, value: Felt) -> Self {
+        self.stack_default = Some(value);
+        self
+    }
+
+    /// Returns an iterator over all key-value pairs in the advice map, ordered by key.
+    pub fn map_iter(&self) -> impl Iterator<Item = (Word, &[Felt])> {
+        self.map.iter().map(|(key, values)| (Word::from(*key), values.as_slice()))
+    }
+
+    /// Makes sure that the advice stack contains at least `num_values` values.
+    ///
+    /// If the default value for reads from an exhausted stack is set, the missing values are
+    /// added to the bottom of the stack; otherwise, an error is returned and the stack is left
+    /// unchanged.
+    fn ensure_stack_len<P: ProcessState>(
+        &mut self,
+        num_values: usize,
+        process: &P,
+    ) -> Result<(), ExecutionError> {
+        if self.stack.len() >= num_values {
+            return Ok(());
+        }
+
+        let default =
+            self.stack_default.ok_or(ExecutionError::AdviceStackReadFailed(process.clk()))?;
+        let num_missing = num_values - self.stack.len();
+        self.stack.splice(0..0, core::iter::repeat(default).take(num_missing));
+        Ok(())
+    }
+}
+
 impl<M, S> AdviceProvider for BaseAdviceProvider<M, S>
 where
     M: KvMap<RpoDigest, Vec<Felt>>,
@@ -60,13 +104,12 @@ where
     // --------------------------------------------------------------------------------------------
 
     fn pop_stack<P: ProcessState>(&mut self, process: &P) -> Result<Felt, ExecutionError> {
-        self.stack.pop().ok_or(ExecutionError::AdviceStackReadFailed(process.clk()))
+        self.ensure_stack_len(1, process)?;
+        Ok(self.stack.pop().expect("advice stack is empty"))
     }
 
     fn pop_stack_word<P: ProcessState>(&mut self, process: &P) -> Result<Word, ExecutionError> {
-        if self.stack.len() < 4 {
-            return Err(ExecutionError::AdviceStackReadFailed(process.clk()));
-        }
+        self.ensure_stack_len(4, process)?;
 
         let idx = self.stack.len() - 4;
         let result =
@@ -81,6 +124,9 @@ where
         &mut self,
         process: &P,
     ) -> Result<[Word; 2], ExecutionError> {
+        // check the length up front so that a failed read leaves the advice stack unchanged
+        self.ensure_stack_len(8, process)?;
+
         let word0 = self.pop_stack_word(process)?;
         let word1 = self.pop_stack_word(process)?;
 
@@ -110,6 +156,10 @@ where
         Ok(())
     }
 
+    fn clear_stack(&mut self) {
+        self.stack.clear();
+    }
+
     fn get_signature(
         &self,
         kind: SignatureKind,
@@ -141,6 +191,10 @@ where
     // MERKLE STORE
     // --------------------------------------------------------------------------------------------
 
+    fn contains_merkle_root(&self, root: Word) -> bool {
+        self.store.get_node(root.into(), NodeIndex::root()).is_ok()
+    }
+
     fn get_tree_node(
         &self,
         root: Word,
@@ -216,6 +270,17 @@ where
             .map_err(ExecutionError::MerkleStoreMergeFailed)
     }
 
+    fn merge_sets(&mut self, sets: BTreeMap<RpoDigest, MerkleStore>) -> Result<(), ExecutionError> {
+        for (root, set) in sets.iter() {
+            set.get_node(*root, NodeIndex::root())
+                .map_err(ExecutionError::MerkleStoreMergeFailed)?;
+        }
+        for set in sets.values() {
+            self.store.extend(set.inner_nodes());
+        }
+        Ok(())
+    }
+
     fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
     where
         I: Iterator<Item = R>,
@@ -223,6 +288,17 @@ where
     {
         self.store.subset(roots).into_inner().into_iter().collect()
     }
+
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        let mut stack = self.stack.clone();
+        stack.reverse();
+        let map = self.map.iter().map(|(key, values)| (*key, values.clone()));
+        let inputs = AdviceInputs::default()
+            .with_stack(stack)
+            .with_map(map)
+            .with_merkle_store(self.store.inner_nodes().collect());
+        Some(inputs)
+    }
 }
 
 // MEMORY ADVICE PROVIDER
@@ -241,6 +317,21 @@ impl From<AdviceInputs> for MemAdviceProvider {
     }
 }
 
+impl MemAdviceProvider {
+    /// Sets the value returned by reads from the advice stack once the stack is exhausted.
+    ///
+    /// By default, reading from an exhausted advice stack results in an error.
+    pub fn with_stack_default(self, value: Felt) -> Self {
+        let provider = self.provider.with_stack_default(value);
+        Self { provider }
+    }
+
+    /// Returns an iterator over all key-value pairs in the advice map, ordered by key.
+    pub fn map_iter(&self) -> impl Iterator<Item = (Word, &[Felt])> {
+        self.provider.map_iter()
+    }
+}
+
 /// Accessors to internal data structures of the provider used for testing purposes.
 #[cfg(any(test, feature = "internals"))]
 impl MemAdviceProvider {
@@ -286,6 +377,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.push_stack(source)
     }
 
+    fn clear_stack(&mut self) {
+        self.provider.clear_stack()
+    }
+
     fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
         self.provider.insert_into_map(key, values)
     }
@@ -298,6 +393,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.get_mapped_values(key)
     }
 
+    fn contains_merkle_root(&self, root: Word) -> bool {
+        self.provider.contains_merkle_root(root)
+    }
+
     fn get_tree_node(&self, root: Word, depth: &Felt, index: &Felt) -> Result<Word, ExecutionError> {
         self.provider.get_tree_node(root, depth, index)
     }
@@ -318,6 +417,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.merge_roots(lhs, rhs)
     }
 
+    fn merge_sets(&mut self, sets: BTreeMap<RpoDigest, MerkleStore>) -> Result<(), ExecutionError> {
+        self.provider.merge_sets(sets)
+    }
+
     fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
         where
             I: Iterator<Item = R>,
@@ -325,6 +428,10 @@ impl AdviceProvider for MemAdviceProvider {
         self.provider.get_store_subset(roots)
     }
 
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        self.provider.advice_state()
+    }
+
 }
 
 impl MemAdviceProvider {
@@ -333,7 +440,9 @@ impl MemAdviceProvider {
     /// Consumes the [MemAdviceProvider] and returns a (Vec<Felt>, SimpleAdviceMap, MerkleStore),
     /// containing the stack, map, store respectively, of the advice provider.
     pub fn into_parts(self) -> (Vec<Felt>, SimpleAdviceMap, MerkleStore) {
-        let BaseAdviceProvider { stack, map, store } = self.provider;
+        let BaseAdviceProvider {
+            stack, map, store, ..
+        } = self.provider;
         (stack, map, store)
     }
 }
@@ -362,6 +471,27 @@ impl From<AdviceInputs> for RecAdviceProvider {
     }
 }
 
+impl RecAdviceProvider {
+    /// Sets the value returned by reads from the advice stack once the stack is exhausted.
+    ///
+    /// By default, reading from an exhausted advice stack results in an error. The default
+    /// values are not recorded, and thus the [AdviceInputs] returned by [Self::finalize()] can be
+    /// used to re-execute the program only with a provider configured with the same default.
+    pub fn with_stack_default(self, value: Felt) -> Self {
+        Self {
+            provider: self.provider.with_stack_default(value),
+            ..self
+        }
+    }
+
+    /// Returns an iterator over all key-value pairs in the advice map, ordered by key.
+    ///
+    /// Iterating over the map is not recorded as an access to its entries.
+    pub fn map_iter(&self) -> impl Iterator<Item = (Word, &[Felt])> {
+        self.provider.map_iter()
+    }
+}
+
 /// Accessors to internal data structures of the provider used for testing purposes.
 #[cfg(any(test, feature = "internals"))]
 impl RecAdviceProvider {
@@ -407,6 +537,10 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.push_stack(source)
     }
 
+    fn clear_stack(&mut self) {
+        self.provider.clear_stack()
+    }
+
     fn insert_into_map(&mut self, key: Word, values: Vec<Felt>) -> Result<(), ExecutionError> {
         self.provider.insert_into_map(key, values)
     }
@@ -419,6 +553,10 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.get_mapped_values(key)
     }
 
+    fn contains_merkle_root(&self, root: Word) -> bool {
+        self.provider.contains_merkle_root(root)
+    }
+
     fn get_tree_node(&self, root: Word, depth: &Felt, index: &Felt) -> Result<Word, ExecutionError> {
         self.provider.get_tree_node(root, depth, index)
     }
@@ -439,12 +577,20 @@ impl AdviceProvider for RecAdviceProvider {
         self.provider.merge_roots(lhs, rhs)
     }
 
+    fn merge_sets(&mut self, sets: BTreeMap<RpoDigest, MerkleStore>) -> Result<(), ExecutionError> {
+        self.provider.merge_sets(sets)
+    }
+
     fn get_store_subset<I, R>(&self, roots: I) -> MerkleStore
         where
             I: Iterator<Item = R>,
             R: core::borrow::Borrow<RpoDigest> {
         self.provider.get_store_subset(roots)
     }
+
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        self.provider.advice_state()
+    }
 }
 
 impl RecAdviceProvider {
@@ -465,7 +611,9 @@ impl RecAdviceProvider {
             provider,
             init_stack,
         } = self;
-        let BaseAdviceProvider { stack, map, store } = provider;
+        let BaseAdviceProvider {
+            stack, map, store, ..
+        } = provider;
 
         let (map, map_proof) = map.finalize();
         let (store, store_proof) = store.into_inner().finalize();
@@ -10,6 +10,10 @@ pub enum AdviceSource {
     Value(Felt),
 
     /// Puts a word (4 elements) onto the stack.
+    ///
+    /// The elements are pushed such that reading a word from the advice stack right after
+    /// (e.g., via [AdviceProvider::pop_stack_word()](super::AdviceProvider::pop_stack_word))
+    /// returns exactly the same word.
     Word(Word),
 
     /// Fetches a list of elements under the specified key from the advice map and pushes them onto
@@ -21,6 +25,14 @@ pub enum AdviceSource {
     /// Note: this operation doesn't consume the map element so it can be called multiple times
     /// for the same key.
     ///
+    /// The key is looked up by its elements, and thus keys inserted via their byte encoding have
+    /// to use the 32-byte little-endian layout of [RpoDigest::as_bytes()], where every element of
+    /// the word is encoded as an 8-byte little-endian integer, starting with the first element
+    /// (see [AdviceInputs::insert_map_entry_le()]).
+    ///
+    /// [RpoDigest::as_bytes()]: vm_core::crypto::hash::RpoDigest::as_bytes
+    /// [AdviceInputs::insert_map_entry_le()]: super::AdviceInputs::insert_map_entry_le
+    ///
     /// # Example
     /// Given an advice stack `[a, b, c, ...]`, and a map `x |-> [d, e, f]`:
     ///
@@ -0,0 +1,437 @@
+use super::{
+    AdviceInputs, AdviceProvider, AdviceSource, ExecutionError, MemAdviceProvider, MerkleStore,
+    RpoDigest,
+};
+use crate::{DefaultHost, ExecutionOptions, Kernel, Process, StackInputs};
+use alloc::{collections::BTreeMap, vec::Vec};
+use vm_core::{
+    crypto::merkle::{EmptySubtreeRoots, MerkleError, MerkleTree},
+    utils::{Deserializable, Serializable},
+    Felt, Word, ONE, ZERO,
+};
+
+// ADVICE STACK
+// ================================================================================================
+
+#[test]
+fn push_stack_interleaved_with_pops() {
+    let process = new_process();
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2, 3]).unwrap();
+    let mut provider = MemAdviceProvider::from(advice_inputs);
+
+    // a pushed value is read before the values already on the advice stack
+    provider.push_stack(AdviceSource::Value(Felt::new(4))).unwrap();
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(4)));
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(1)));
+
+    // values pushed one after another are read in the reverse order
+    provider.push_stack(AdviceSource::Value(Felt::new(5))).unwrap();
+    provider.push_stack(AdviceSource::Value(Felt::new(6))).unwrap();
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(6)));
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(5)));
+
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(2)));
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(3)));
+    assert_eq!(provider.pop_stack(&process), Err(ExecutionError::AdviceStackReadFailed(0)));
+}
+
+#[test]
+fn push_stack_word_round_trip() {
+    let process = new_process();
+    let mut provider = MemAdviceProvider::default();
+
+    let words: [Word; 3] = [
+        [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)],
+        [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)],
+        [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)],
+    ];
+
+    // a word is read back exactly as it was written
+    for word in words {
+        provider.push_stack(AdviceSource::Word(word)).unwrap();
+        assert_eq!(provider.pop_stack_word(&process), Ok(word));
+    }
+
+    // words written back-to-back are read in the reverse order
+    for word in words {
+        provider.push_stack(AdviceSource::Word(word)).unwrap();
+    }
+    for word in words.iter().rev() {
+        assert_eq!(provider.pop_stack_word(&process), Ok(*word));
+    }
+
+    // reading elements one-by-one yields the elements of a word in their original order
+    provider.push_stack(AdviceSource::Word(words[0])).unwrap();
+    for element in words[0] {
+        assert_eq!(provider.pop_stack(&process), Ok(element));
+    }
+}
+
+#[test]
+fn push_stack_word_raw_round_trip() {
+    let process = new_process();
+    let mut provider = MemAdviceProvider::default();
+    let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    // a word written in storage order is read back unchanged in storage order
+    provider.push_stack_word_raw(word).unwrap();
+    assert_eq!(provider.pop_stack_word_raw(&process), Ok(word));
+
+    // the last element of a word written in storage order is at the top of the stack
+    provider.push_stack_word_raw(word).unwrap();
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(4)));
+    provider.push_stack(AdviceSource::Value(Felt::new(4))).unwrap();
+
+    // the raw and regular variants read the elements of a word in the opposite orders
+    assert_eq!(
+        provider.pop_stack_word(&process),
+        Ok([Felt::new(4), Felt::new(3), Felt::new(2), Felt::new(1)])
+    );
+    provider.push_stack(AdviceSource::Word(word)).unwrap();
+    assert_eq!(
+        provider.pop_stack_word_raw(&process),
+        Ok([Felt::new(4), Felt::new(3), Felt::new(2), Felt::new(1)])
+    );
+}
+
+#[test]
+fn push_stack_map_values_in_order() {
+    let process = new_process();
+    let key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let values = (0..1000).map(Felt::new).collect::<Vec<_>>();
+    let advice_inputs = AdviceInputs::default().with_map([(key.into(), values.clone())]);
+    let mut provider = MemAdviceProvider::from(advice_inputs);
+
+    // the values are read from the advice stack in the order in which they are stored in the map,
+    // and this holds for repeated reads of the same value
+    for _ in 0..2 {
+        provider
+            .push_stack(AdviceSource::Map {
+                key,
+                include_len: true,
+            })
+            .unwrap();
+        assert_eq!(provider.pop_stack(&process), Ok(Felt::new(values.len() as u64)));
+        for value in values.iter() {
+            assert_eq!(provider.pop_stack(&process), Ok(*value));
+        }
+    }
+    assert!(provider.pop_stack(&process).is_err());
+}
+
+#[test]
+fn pop_stack_dword_is_atomic() {
+    let process = new_process();
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2, 3, 4]).unwrap();
+    let mut provider = MemAdviceProvider::from(advice_inputs);
+
+    // a failed read of a double word does not consume the word present on the advice stack
+    assert_eq!(
+        provider.pop_stack_dword(&process),
+        Err(ExecutionError::AdviceStackReadFailed(0))
+    );
+    assert_eq!(
+        provider.pop_stack_word(&process),
+        Ok([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)])
+    );
+}
+
+#[test]
+fn pop_stack_with_default_never_fails() {
+    let process = new_process();
+    let advice_inputs = AdviceInputs::default().with_stack_values([1, 2]).unwrap();
+    let mut provider = MemAdviceProvider::from(advice_inputs).with_stack_default(ZERO);
+
+    // values missing at the bottom of the advice stack are read as the default value
+    assert_eq!(provider.pop_stack_word(&process), Ok([Felt::new(1), Felt::new(2), ZERO, ZERO]));
+    assert_eq!(provider.pop_stack(&process), Ok(ZERO));
+    assert_eq!(provider.pop_stack_dword(&process), Ok([[ZERO; 4], [ZERO; 4]]));
+
+    // values pushed onto the advice stack are still read first
+    provider.push_stack(AdviceSource::Value(Felt::new(3))).unwrap();
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(3)));
+    assert_eq!(provider.pop_stack(&process), Ok(ZERO));
+}
+
+#[test]
+fn clear_stack_keeps_map_and_store() {
+    let process = new_process();
+    let tree = build_tree(0);
+    let key = build_tree(100).root();
+    let advice_inputs = AdviceInputs::default()
+        .with_stack_values([1, 2, 3])
+        .unwrap()
+        .with_map([(key, vec![Felt::new(7)])])
+        .with_merkle_store(MerkleStore::from(&tree));
+    let mut provider = MemAdviceProvider::from(advice_inputs);
+
+    provider.clear_stack();
+    assert_eq!(provider.pop_stack(&process), Err(ExecutionError::AdviceStackReadFailed(0)));
+
+    // the advice map and the Merkle store are not affected
+    assert_eq!(provider.get_mapped_values(&key), Some(&[Felt::new(7)][..]));
+    assert!(provider.contains_merkle_root(tree.root().into()));
+}
+
+// ADVICE MAP
+// ================================================================================================
+
+#[test]
+fn map_iter_is_ordered_by_key() {
+    let keys = [build_tree(0).root(), build_tree(100).root(), build_tree(200).root()];
+    let advice_inputs = AdviceInputs::default()
+        .with_map(keys.iter().enumerate().map(|(i, key)| (*key, vec![Felt::new(i as u64)])));
+    let provider = MemAdviceProvider::from(advice_inputs);
+
+    let mut expected = keys
+        .iter()
+        .enumerate()
+        .map(|(i, key)| (Word::from(*key), vec![Felt::new(i as u64)]))
+        .collect::<Vec<_>>();
+    expected.sort_by_key(|(key, _)| RpoDigest::from(*key));
+
+    let entries = provider
+        .map_iter()
+        .map(|(key, values)| (key, values.to_vec()))
+        .collect::<Vec<_>>();
+    assert_eq!(entries, expected);
+}
+
+// MERKLE TREE DEPTH
+// ================================================================================================
+
+#[test]
+fn get_root_depth_of_known_trees() {
+    let tree_a = build_tree(0);
+    let tree_b = MerkleTree::new((0..32).map(leaf).collect::<Vec<_>>()).unwrap();
+
+    let mut store = MerkleStore::from(&tree_a);
+    store.extend(tree_b.inner_nodes());
+    let provider = MemAdviceProvider::from(AdviceInputs::default().with_merkle_store(store));
+
+    assert!(provider.contains_merkle_root(tree_a.root().into()));
+    assert_eq!(provider.get_root_depth(tree_a.root().into()), Some(2));
+    assert_eq!(provider.get_root_depth(tree_b.root().into()), Some(5));
+
+    // roots of unknown trees and leaves have no depth
+    assert_eq!(provider.get_root_depth(build_tree(100).root().into()), None);
+    assert_eq!(provider.get_root_depth(leaf(1)), None);
+
+    // the depth of trees is bounded by the maximum depth of an addressable node
+    let empty_root = EmptySubtreeRoots::entry(64, 0);
+    assert_eq!(provider.get_root_depth((*empty_root).into()), Some(64));
+    let empty_root = EmptySubtreeRoots::entry(65, 0);
+    assert_eq!(provider.get_root_depth((*empty_root).into()), None);
+}
+
+#[test]
+fn insert_merkle_tree_pads_leaves() {
+    let mut advice_inputs = AdviceInputs::default();
+    let root = advice_inputs.insert_merkle_tree((0..5).map(leaf).collect());
+    let provider = MemAdviceProvider::from(advice_inputs);
+
+    // five leaves are padded with empty words up to eight leaves
+    let leaves = (0..5).map(leaf).chain([[ZERO; 4]; 3]).collect::<Vec<_>>();
+    assert_eq!(root, MerkleTree::new(leaves).unwrap().root());
+    assert_eq!(provider.get_root_depth(root.into()), Some(3));
+    assert_eq!(provider.get_tree_node(root.into(), &Felt::new(3), &Felt::new(4)), Ok(leaf(4)));
+
+    // a single leaf results in a tree of depth 1
+    let mut advice_inputs = AdviceInputs::default();
+    let root = advice_inputs.insert_merkle_tree(vec![leaf(7)]);
+    assert_eq!(root, MerkleTree::new([leaf(7), [ZERO; 4]]).unwrap().root());
+}
+
+#[test]
+fn get_tree_leaves_after_update() {
+    let tree = build_tree(0);
+    let root: Word = tree.root().into();
+    let mut provider =
+        MemAdviceProvider::from(AdviceInputs::default().with_merkle_store((&tree).into()));
+
+    let leaves = provider.get_tree_leaves(root).unwrap();
+    assert_eq!(leaves, (0..4).map(|i| (i, leaf(i))).collect::<Vec<_>>());
+
+    // the leaves of the updated tree reflect the update, while the original tree is retained
+    let (_, new_root) = provider
+        .update_merkle_node(root, &Felt::new(2), &Felt::new(1), leaf(100))
+        .unwrap();
+    let leaves = provider.get_tree_leaves(new_root).unwrap();
+    assert_eq!(leaves, vec![(0, leaf(0)), (1, leaf(100)), (2, leaf(2)), (3, leaf(3))]);
+    assert_eq!(provider.get_tree_leaves(root).unwrap()[1], (1, leaf(1)));
+
+    // unknown roots are rejected
+    let unknown = build_tree(100).root();
+    assert_eq!(
+        provider.get_tree_leaves(unknown.into()),
+        Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::RootNotInStore(unknown)))
+    );
+
+    // leaves of deep trees are not listed
+    let empty_root = *EmptySubtreeRoots::entry(64, 0);
+    assert_eq!(
+        provider.get_tree_leaves(empty_root.into()),
+        Err(ExecutionError::MerkleStoreLookupFailed(MerkleError::DepthTooBig(64)))
+    );
+}
+
+// UPDATING MERKLE TREES
+// ================================================================================================
+
+#[test]
+fn update_merkle_node_onto_existing_root() {
+    let tree_a = build_tree(0);
+    let tree_b = MerkleTree::new(vec![leaf(9), leaf(1), leaf(2), leaf(3)]).unwrap();
+
+    let mut store = MerkleStore::from(&tree_a);
+    store.extend(tree_b.inner_nodes());
+    let mut provider = MemAdviceProvider::from(AdviceInputs::default().with_merkle_store(store));
+
+    // updating the first leaf of tree_a yields the root of the already present tree_b
+    let (depth, index) = (Felt::from(2_u32), ZERO);
+    let (_, root) = provider
+        .update_merkle_node(tree_a.root().into(), &depth, &index, leaf(9))
+        .unwrap();
+    assert_eq!(RpoDigest::from(root), tree_b.root());
+
+    // repeating the update yields the same root again, and neither tree is clobbered
+    let (_, repeated) = provider
+        .update_merkle_node(tree_a.root().into(), &depth, &index, leaf(9))
+        .unwrap();
+    assert_eq!(repeated, root);
+    for (tree, first_leaf) in [(&tree_a, 0), (&tree_b, 9)] {
+        assert!(provider.has_merkle_root(tree.root()));
+        let node = provider.get_tree_node(tree.root().into(), &depth, &index);
+        assert_eq!(node, Ok(leaf(first_leaf)));
+        let node = provider.get_tree_node(tree.root().into(), &depth, &Felt::from(3_u32));
+        assert_eq!(node, Ok(leaf(3)));
+    }
+}
+
+// MERGING ADVICE SETS
+// ================================================================================================
+
+#[test]
+fn merge_disjoint_sets() {
+    let tree_a = build_tree(0);
+    let tree_b = build_tree(100);
+    let tree_c = build_tree(200);
+
+    let mut provider = MemAdviceProvider::from(
+        AdviceInputs::default().with_merkle_store(MerkleStore::from(&tree_a)),
+    );
+
+    // sets built by separate workers can be merged into the same provider
+    let mut sets = BTreeMap::new();
+    sets.insert(tree_b.root(), MerkleStore::from(&tree_b));
+    sets.insert(tree_c.root(), MerkleStore::from(&tree_c));
+    provider.merge_sets(sets).unwrap();
+
+    let depth = Felt::from(2_u32);
+    for (tree, offset) in [(&tree_a, 0), (&tree_b, 100), (&tree_c, 200)] {
+        assert!(provider.has_merkle_root(tree.root()));
+        let node = provider.get_tree_node(tree.root().into(), &depth, &Felt::from(3_u32));
+        assert_eq!(node, Ok(leaf(offset + 3)));
+    }
+}
+
+#[test]
+fn merge_sets_with_mismatched_root() {
+    let tree_a = build_tree(0);
+    let tree_b = build_tree(100);
+    let tree_c = build_tree(200);
+
+    let mut provider = MemAdviceProvider::default();
+
+    // the root of tree_c is used as a key for a set which does not contain it
+    let mut sets = BTreeMap::new();
+    sets.insert(tree_a.root(), MerkleStore::from(&tree_a));
+    sets.insert(tree_c.root(), MerkleStore::from(&tree_b));
+
+    let expected =
+        ExecutionError::MerkleStoreMergeFailed(MerkleError::RootNotInStore(tree_c.root()));
+    assert_eq!(provider.merge_sets(sets), Err(expected));
+
+    // none of the sets should have been added
+    assert!(!provider.has_merkle_root(tree_a.root()));
+    assert!(!provider.has_merkle_root(tree_b.root()));
+}
+
+#[test]
+fn insert_map_entry_le_matches_word_key() {
+    let key: Word = [Felt::new(1), Felt::new(2), Felt::new(u32::MAX as u64 + 3), Felt::new(4)];
+    let mut key_bytes = [0_u8; 32];
+    for (chunk, element) in key_bytes.chunks_exact_mut(8).zip(key) {
+        chunk.copy_from_slice(&element.as_int().to_le_bytes());
+    }
+    assert_eq!(key_bytes, RpoDigest::from(key).as_bytes());
+
+    let mut inputs = AdviceInputs::default();
+    inputs.insert_map_entry_le(key_bytes, vec![Felt::new(7), Felt::new(8)]).unwrap();
+    let values = [Felt::new(7), Felt::new(8)];
+    assert_eq!(inputs.mapped_values(&RpoDigest::from(key)), Some(&values[..]));
+
+    // the values are retrievable by the equivalent word from the advice provider
+    let mut provider = MemAdviceProvider::from(inputs);
+    assert_eq!(provider.get_mapped_values(&RpoDigest::from(key)), Some(&values[..]));
+    let process = new_process();
+    provider
+        .push_stack(AdviceSource::Map {
+            key,
+            include_len: false,
+        })
+        .unwrap();
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(7)));
+
+    // keys with chunks which are not valid field elements are rejected
+    let mut inputs = AdviceInputs::default();
+    assert!(inputs.insert_map_entry_le([u8::MAX; 32], vec![ONE]).is_err());
+    assert_eq!(inputs, AdviceInputs::default());
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+#[test]
+fn advice_inputs_serialization_round_trip() {
+    let tree = build_tree(0);
+    let key = tree.root();
+    let inputs = AdviceInputs::default()
+        .with_stack_values([1, 2, 3])
+        .unwrap()
+        .with_map([(key, vec![Felt::new(7), Felt::new(8)]), (build_tree(100).root(), vec![])])
+        .with_merkle_store(MerkleStore::from(&tree));
+
+    let bytes = inputs.to_bytes();
+    let decoded = AdviceInputs::read_from_bytes(&bytes).unwrap();
+    assert_eq!(decoded, inputs);
+    assert_eq!(decoded.to_bytes(), bytes);
+
+    // the decoded inputs initialize an equivalent provider
+    let process = new_process();
+    let mut provider = MemAdviceProvider::from(decoded);
+    assert_eq!(provider.pop_stack(&process), Ok(Felt::new(1)));
+    assert_eq!(provider.get_mapped_values(&key), Some(&[Felt::new(7), Felt::new(8)][..]));
+    let node = provider.get_tree_node(key.into(), &Felt::from(2_u32), &Felt::from(1_u32));
+    assert_eq!(node, Ok(leaf(1)));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn new_process() -> Process<DefaultHost<MemAdviceProvider>> {
+    Process::new(
+        Kernel::default(),
+        StackInputs::default(),
+        DefaultHost::default(),
+        ExecutionOptions::default(),
+    )
+}
+
+fn leaf(value: u64) -> Word {
+    [Felt::new(value), ZERO, ZERO, ZERO]
+}
+
+fn build_tree(offset: u64) -> MerkleTree {
+    MerkleTree::new((0..4).map(|i| leaf(offset + i)).collect::<alloc::vec::Vec<_>>()).unwrap()
+}
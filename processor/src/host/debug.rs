@@ -22,11 +22,16 @@ pub fn print_debug_info<S: ProcessState>(process: &S, options: &DebugOptions) {
             printer.print_mem_all(process);
         }
         DebugOptions::MemInterval(n, m) => {
-            printer.print_mem_interval(process, *n, *m);
+            printer.print_mem_interval(process, printer.ctx, *n, *m);
+        }
+        DebugOptions::MemIntervalCtx(ctx, n, m) => {
+            printer.print_mem_interval(process, ContextId::from(*ctx), *n, *m);
         }
         DebugOptions::LocalInterval(n, m, num_locals) => {
             printer.print_local_interval(process, (*n as u32, *m as u32), *num_locals as u32);
         }
+        // checkpoints are recorded by the processor rather than printed
+        DebugOptions::Checkpoint(_) => (),
     }
 }
 
@@ -92,22 +97,24 @@ impl Printer {
         }
     }
 
-    /// Prints memory values in the provided addresses interval.
-    fn print_mem_interval<S: ProcessState>(&self, process: &S, n: u32, m: u32) {
+    /// Prints memory values of the context `ctx` in the provided addresses interval.
+    fn print_mem_interval<S: ProcessState>(&self, process: &S, ctx: ContextId, n: u32, m: u32) {
         let mut mem_interval = Vec::new();
         for addr in n..m + 1 {
-            mem_interval.push((addr, process.get_mem_value(self.ctx, addr)));
+            mem_interval.push((addr, process.get_mem_value(ctx, addr)));
         }
 
+        // the current context is labeled as such to distinguish it from other contexts
+        let current = if ctx == self.ctx { " (current)" } else { "" };
         if n == m {
             println!(
-                "Memory state before step {} for the context {} at address {}:",
-                self.clk, self.ctx, n
+                "Memory state before step {} for the context {}{} at address {}:",
+                self.clk, ctx, current, n
             )
         } else {
             println!(
-                "Memory state before step {} for the context {} in the interval [{}, {}]:",
-                self.clk, self.ctx, n, m
+                "Memory state before step {} for the context {}{} in the interval [{}, {}]:",
+                self.clk, ctx, current, n, m
             )
         };
 
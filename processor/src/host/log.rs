@@ -0,0 +1,106 @@
+use super::{
+    AdviceExtractor, AdviceInputs, ExecutionError, Host, HostResponse, MemoryWriteEvent,
+    ProcessState, TraceEntry,
+};
+use alloc::string::ToString;
+use std::io::Write;
+use vm_core::{AdviceInjector, DebugOptions, Operation};
+
+// INSTRUCTION LOG WRITER
+// ================================================================================================
+
+/// A host which writes the entries of the instruction log to the provided sink, rather than
+/// buffering them in the process.
+///
+/// Every entry is written as a single line; all other requests are forwarded to the wrapped host.
+/// Writing an entry fails the execution with [ExecutionError::InstructionLogWriteFailed].
+pub struct InstructionLogWriter<H, W> {
+    host: H,
+    writer: W,
+}
+
+impl<H: Host, W: Write> InstructionLogWriter<H, W> {
+    /// Returns a new host which forwards all requests to the provided host, and writes the
+    /// entries of the instruction log into the provided writer.
+    pub fn new(host: H, writer: W) -> Self {
+        Self { host, writer }
+    }
+
+    /// Returns a reference to the wrapped host.
+    pub fn host(&self) -> &H {
+        &self.host
+    }
+
+    /// Returns the wrapped host and the writer.
+    pub fn into_parts(self) -> (H, W) {
+        (self.host, self.writer)
+    }
+}
+
+impl<H: Host, W: Write> Host for InstructionLogWriter<H, W> {
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.get_advice(process, extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.set_advice(process, injector)
+    }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_debug(process, options)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_event(process, event_id)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_step<S: ProcessState>(
+        &mut self,
+        process: &S,
+        op: Operation,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_step(process, op)
+    }
+
+    fn on_log_entry(&mut self, entry: TraceEntry) -> Result<Option<TraceEntry>, ExecutionError> {
+        writeln!(self.writer, "{entry}")
+            .map_err(|err| ExecutionError::InstructionLogWriteFailed(err.to_string()))?;
+        Ok(None)
+    }
+
+    fn on_mem_write(&mut self, event: MemoryWriteEvent) -> Result<(), ExecutionError> {
+        self.host.on_mem_write(event)
+    }
+
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        self.host.advice_state()
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        self.host.on_assert_failed(process, err_code)
+    }
+}
@@ -1,13 +1,19 @@
-use super::{ExecutionError, Felt, ProcessState};
+use super::{ExecutionError, Felt, MemoryWriteEvent, ProcessState, TraceEntry};
 use crate::MemAdviceProvider;
-use vm_core::{crypto::merkle::MerklePath, AdviceInjector, DebugOptions, Word};
+use alloc::{collections::BTreeMap, string::String};
+use vm_core::{crypto::merkle::MerklePath, AdviceInjector, DebugOptions, Operation, Word};
 
 pub(super) mod advice;
-use advice::{AdviceExtractor, AdviceProvider};
+use advice::{AdviceExtractor, AdviceInputs, AdviceProvider};
 
 #[cfg(feature = "std")]
 mod debug;
 
+#[cfg(feature = "std")]
+mod log;
+#[cfg(feature = "std")]
+pub use log::InstructionLogWriter;
+
 // HOST TRAIT
 // ================================================================================================
 
@@ -98,6 +104,56 @@ pub trait Host {
         Ok(HostResponse::None)
     }
 
+    /// Handles the completion of a single VM cycle.
+    ///
+    /// This is invoked after every executed operation, once the clock of the VM has been
+    /// advanced. Thus, the provided process state reflects the effects of the specified operation,
+    /// and `process.clk()` returns the clock cycle following the one at which the operation was
+    /// executed. This can be used to drive a stepping debugger or to collect execution
+    /// statistics. Returning an error aborts the execution of the program.
+    fn on_step<S: ProcessState>(
+        &mut self,
+        _process: &S,
+        _op: Operation,
+    ) -> Result<HostResponse, ExecutionError> {
+        Ok(HostResponse::None)
+    }
+
+    /// Handles an entry of the instruction log recorded by the VM.
+    ///
+    /// This is invoked after every executed operation when the instruction log is enabled via
+    /// [ExecutionOptions::with_instruction_log()](crate::ExecutionOptions::with_instruction_log).
+    /// The returned entry, if any, is appended to the instruction log of the process; the host
+    /// can return `None` to consume the entry instead (e.g., when the entry was written out).
+    /// Returning an error aborts the execution of the program.
+    fn on_log_entry(&mut self, entry: TraceEntry) -> Result<Option<TraceEntry>, ExecutionError> {
+        Ok(Some(entry))
+    }
+
+    /// Handles a write to memory performed by the VM.
+    ///
+    /// This is invoked after every `MSTORE` and `MSTOREW` operation when memory write events are
+    /// enabled via [ExecutionOptions::with_memory_write_events()]. Returning an error aborts the
+    /// execution of the program.
+    ///
+    /// [ExecutionOptions::with_memory_write_events()]: crate::ExecutionOptions::with_memory_write_events
+    fn on_mem_write(&mut self, event: MemoryWriteEvent) -> Result<(), ExecutionError> {
+        let _ = event;
+        Ok(())
+    }
+
+    /// Returns the current state of the advice provider of this host, i.e., the values remaining
+    /// on the advice stack, the advice map, and the Merkle store.
+    ///
+    /// This is invoked once the program has been executed when recording of the final advice
+    /// state is enabled via [ExecutionOptions::with_advice_state()]. Returns `None` if the host
+    /// does not expose the state of its advice provider, which is the case by default.
+    ///
+    /// [ExecutionOptions::with_advice_state()]: crate::ExecutionOptions::with_advice_state
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        None
+    }
+
     /// Handles the failure of the assertion instruction.
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         ExecutionError::FailedAssertion {
@@ -206,6 +262,26 @@ where
         H::on_trace(self, process, trace_id)
     }
 
+    fn on_step<S: ProcessState>(
+        &mut self,
+        process: &S,
+        op: Operation,
+    ) -> Result<HostResponse, ExecutionError> {
+        H::on_step(self, process, op)
+    }
+
+    fn on_log_entry(&mut self, entry: TraceEntry) -> Result<Option<TraceEntry>, ExecutionError> {
+        H::on_log_entry(self, entry)
+    }
+
+    fn on_mem_write(&mut self, event: MemoryWriteEvent) -> Result<(), ExecutionError> {
+        H::on_mem_write(self, event)
+    }
+
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        H::advice_state(self)
+    }
+
     fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
         H::on_assert_failed(self, process, err_code)
     }
@@ -264,21 +340,45 @@ impl From<HostResponse> for Felt {
 // ================================================================================================
 
 /// A default [Host] implementation that provides the essential functionality required by the VM.
+///
+/// The host can be provided with descriptions of the error codes used by the assertions of the
+/// executed program, in which case the descriptions are included into the errors reported on
+/// failed assertions.
 pub struct DefaultHost<A> {
     adv_provider: A,
+    error_messages: BTreeMap<u32, String>,
 }
 
 impl Default for DefaultHost<MemAdviceProvider> {
     fn default() -> Self {
         Self {
             adv_provider: MemAdviceProvider::default(),
+            error_messages: BTreeMap::new(),
         }
     }
 }
 
 impl<A: AdviceProvider> DefaultHost<A> {
     pub fn new(adv_provider: A) -> Self {
-        Self { adv_provider }
+        Self {
+            adv_provider,
+            error_messages: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the provided descriptions of assertion error codes with this host, replacing the
+    /// descriptions previously registered for the same codes.
+    pub fn with_error_messages<I>(mut self, messages: I) -> Self
+    where
+        I: IntoIterator<Item = (u32, String)>,
+    {
+        self.error_messages.extend(messages);
+        self
+    }
+
+    /// Returns the description registered for the specified assertion error code, if any.
+    pub fn error_message(&self, err_code: u32) -> Option<&str> {
+        self.error_messages.get(&err_code).map(String::as_str)
     }
 
     #[cfg(any(test, feature = "internals"))]
@@ -312,4 +412,16 @@ impl<A: AdviceProvider> Host for DefaultHost<A> {
     ) -> Result<HostResponse, ExecutionError> {
         self.adv_provider.set_advice(process, &injector)
     }
+
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        self.adv_provider.advice_state()
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        ExecutionError::FailedAssertion {
+            clk: process.clk(),
+            err_code,
+            err_msg: self.error_messages.get(&err_code).cloned(),
+        }
+    }
 }
@@ -6,7 +6,10 @@ extern crate std;
 #[macro_use]
 extern crate alloc;
 
-use alloc::vec::Vec;
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 use core::cell::RefCell;
 
 use miden_air::trace::{
@@ -23,7 +26,8 @@ use vm_core::{
     code_blocks::{
         Call, CodeBlock, Dyn, Join, Loop, OpBatch, Span, Split, OP_BATCH_SIZE, OP_GROUP_SIZE,
     },
-    CodeBlockTable, Decorator, DecoratorIterator, FieldElement, StackTopState,
+    CodeBlockTable, DebugOptions, Decorator, DecoratorIterator, FieldElement, SourceLocation,
+    StackTopState,
 };
 
 pub use winter_prover::matrix::ColMatrix;
@@ -44,6 +48,8 @@ mod range;
 use range::RangeChecker;
 
 mod host;
+#[cfg(feature = "std")]
+pub use host::InstructionLogWriter;
 pub use host::{
     advice::{
         AdviceExtractor, AdviceInputs, AdviceMap, AdviceProvider, AdviceSource, MemAdviceProvider,
@@ -65,7 +71,7 @@ pub use errors::{ExecutionError, Ext2InttError};
 pub mod utils;
 
 mod debug;
-pub use debug::{AsmOpInfo, VmState, VmStateIterator};
+pub use debug::{AsmOpInfo, Breakpoints, MemoryWriteEvent, TraceEntry, VmState, VmStateIterator};
 
 // RE-EXPORTS
 // ================================================================================================
@@ -135,6 +141,91 @@ where
     Ok(trace)
 }
 
+/// Returns an execution trace resulting from executing the provided program against the provided
+/// inputs, where the provided code blocks can be invoked dynamically (i.e., via `dynexec` or
+/// `dyncall`) by their hashes, in addition to the code blocks in the code block table of the
+/// program.
+///
+/// This is useful for invoking code which is known ahead of time, but which is not referenced from
+/// the program directly.
+pub fn execute_with_code_blocks<H, I>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+    blocks: I,
+) -> Result<ExecutionTrace, ExecutionError>
+where
+    H: Host,
+    I: IntoIterator<Item = CodeBlock>,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options)
+        .with_code_blocks(blocks);
+    let stack_outputs = process.execute(program)?;
+    let trace = ExecutionTrace::new(process, stack_outputs);
+    assert_eq!(&program.hash(), trace.program_hash(), "inconsistent program hash");
+    Ok(trace)
+}
+
+/// Executes the provided program against the provided inputs, invoking the provided handler
+/// whenever an assertion fails, and returns the outputs of the program.
+///
+/// The handler is called with the error code of the failed assertion and the clock cycle at which
+/// it failed, and decides how the execution proceeds:
+/// - [AssertAction::Abort] stops the execution with an [ExecutionError], as is the case when no
+///   handler is registered.
+/// - [AssertAction::Halt] stops the execution gracefully, and the state of the stack at the
+///   failed assertion (i.e., with the failed condition on top of the stack) is returned as the
+///   outputs of the program.
+///
+/// An execution trace is not built, since the trace of a halted execution cannot be proven.
+pub fn execute_with_assert_handler<H, F>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+    handler: F,
+) -> Result<StackOutputs, ExecutionError>
+where
+    H: Host,
+    F: FnMut(u32, u32) -> AssertAction,
+{
+    let host = AssertHandlerHost {
+        host,
+        handler,
+        halted: false,
+    };
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options);
+
+    // a halted execution unwinds with the error of the failed assertion
+    match process.execute(program) {
+        Err(_) if process.host.borrow().halted => Ok(process.stack.build_stack_outputs()),
+        result => result,
+    }
+}
+
+/// Executes the provided program against the provided inputs, with the memory of the root
+/// context seeded with the provided words, and returns the outputs of the program.
+///
+/// Reading from any of the seeded addresses returns the seeded word rather than ZEROs. Memory
+/// seeded this way is not constrained by the AIR, and thus an execution trace is not built, since
+/// it could not be proven. This is intended for testing procedures which read from fixed memory
+/// addresses.
+pub fn execute_with_initial_memory<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    options: ExecutionOptions,
+    memory: BTreeMap<u32, Word>,
+) -> Result<StackOutputs, ExecutionError>
+where
+    H: Host,
+{
+    let mut process = Process::new(program.kernel().clone(), stack_inputs, host, options)
+        .with_initial_memory(memory);
+    process.execute(program)
+}
+
 /// Returns an iterator which allows callers to step through the execution and inspect VM state at
 /// each execution step.
 pub fn execute_iter<H>(program: &Program, stack_inputs: StackInputs, host: H) -> VmStateIterator
@@ -153,6 +244,130 @@ where
     VmStateIterator::new(process, result)
 }
 
+/// Returns the source location of the assembly instruction executed at the specified clock cycle
+/// when executing the provided program against the provided inputs.
+///
+/// The clock cycle at which an instruction is executed depends on the inputs (e.g., via branches
+/// and loops), and thus the program is stepped through via [execute_iter()] to find the
+/// instruction.
+///
+/// Returns `None` if no instruction was executed at the specified cycle, or if its location is
+/// unknown, e.g., because the program was not compiled in debug mode.
+///
+/// # Errors
+/// Returns an error if the execution fails before the operation at the specified cycle completes;
+/// this includes the cycle of the failed operation itself.
+pub fn location_at<H>(
+    program: &Program,
+    stack_inputs: StackInputs,
+    host: H,
+    clk: u32,
+) -> Result<Option<SourceLocation>, ExecutionError>
+where
+    H: Host,
+{
+    // every state describes the operation executed in the preceding cycle
+    for state in execute_iter(program, stack_inputs, host) {
+        let state = state?;
+        if state.clk == clk.saturating_add(1) {
+            return Ok(state.asmop.as_ref().and_then(AsmOpInfo::location).copied());
+        }
+    }
+    Ok(None)
+}
+
+// ASSERT ACTION
+// ================================================================================================
+
+/// Specifies how the execution proceeds after an assertion fails.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum AssertAction {
+    /// Stop the execution with an [ExecutionError].
+    #[default]
+    Abort,
+    /// Stop the execution gracefully, returning the current state of the stack as the outputs.
+    Halt,
+}
+
+/// A host which invokes an assertion handler before handling a failed assertion by the wrapped
+/// host, and records whether the handler decided to halt the execution.
+struct AssertHandlerHost<H, F> {
+    host: H,
+    handler: F,
+    halted: bool,
+}
+
+impl<H, F> Host for AssertHandlerHost<H, F>
+where
+    H: Host,
+    F: FnMut(u32, u32) -> AssertAction,
+{
+    fn get_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        extractor: AdviceExtractor,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.get_advice(process, extractor)
+    }
+
+    fn set_advice<S: ProcessState>(
+        &mut self,
+        process: &S,
+        injector: AdviceInjector,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.set_advice(process, injector)
+    }
+
+    fn on_debug<S: ProcessState>(
+        &mut self,
+        process: &S,
+        options: &DebugOptions,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_debug(process, options)
+    }
+
+    fn on_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_event(process, event_id)
+    }
+
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_trace(process, trace_id)
+    }
+
+    fn on_step<S: ProcessState>(
+        &mut self,
+        process: &S,
+        op: Operation,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.host.on_step(process, op)
+    }
+
+    fn on_log_entry(&mut self, entry: TraceEntry) -> Result<Option<TraceEntry>, ExecutionError> {
+        self.host.on_log_entry(entry)
+    }
+
+    fn on_mem_write(&mut self, event: MemoryWriteEvent) -> Result<(), ExecutionError> {
+        self.host.on_mem_write(event)
+    }
+
+    fn advice_state(&self) -> Option<AdviceInputs> {
+        self.host.advice_state()
+    }
+
+    fn on_assert_failed<S: ProcessState>(&mut self, process: &S, err_code: u32) -> ExecutionError {
+        self.halted = (self.handler)(err_code, process.clk()) == AssertAction::Halt;
+        self.host.on_assert_failed(process, err_code)
+    }
+}
+
 // PROCESS
 // ================================================================================================
 
@@ -168,7 +383,20 @@ where
     chiplets: Chiplets,
     host: RefCell<H>,
     max_cycles: u32,
+    max_stack_depth: usize,
+    fixed_stack_depth: bool,
     enable_tracing: bool,
+    location: Option<SourceLocation>,
+    covered_locations: BTreeSet<SourceLocation>,
+    assertions_checked: u64,
+    context_switches: u64,
+    instruction_log: Option<Vec<TraceEntry>>,
+    enable_memory_write_events: bool,
+    enable_advice_state: bool,
+    default_hdword_domain: Felt,
+    checkpoints: Vec<(u32, StackTopState)>,
+    merkle_root_updates: Option<Vec<(Word, Word)>>,
+    extra_code_blocks: CodeBlockTable,
 }
 
 impl<H> Process<H>
@@ -197,6 +425,37 @@ where
         )
     }
 
+    /// Writes the provided words into the memory of the root context before the program is
+    /// executed, such that reading from any of the specified addresses returns the seeded word
+    /// rather than ZEROs.
+    ///
+    /// Memory seeded this way is not constrained by the AIR, and thus the execution of a program
+    /// against seeded memory cannot be proven. This is intended for testing procedures which read
+    /// from fixed memory addresses.
+    pub fn with_initial_memory(mut self, memory: BTreeMap<u32, Word>) -> Self {
+        assert_eq!(self.system.clk(), 0, "a program has already been executed in this process");
+        for (addr, word) in memory {
+            self.chiplets.write_mem(ContextId::root(), addr, word);
+        }
+        self
+    }
+
+    /// Registers the provided code blocks with this process, such that they can be invoked
+    /// dynamically (i.e., via `dynexec` or `dyncall`) by their hashes, in addition to the code
+    /// blocks in the code block table of the executed program.
+    ///
+    /// This is useful for invoking code which is known ahead of time, but which is not referenced
+    /// from the program directly.
+    pub fn with_code_blocks<I>(mut self, blocks: I) -> Self
+    where
+        I: IntoIterator<Item = CodeBlock>,
+    {
+        for block in blocks {
+            self.extra_code_blocks.insert(block);
+        }
+        self
+    }
+
     fn initialize(
         kernel: Kernel,
         stack: StackInputs,
@@ -212,7 +471,20 @@ where
             chiplets: Chiplets::new(kernel),
             host: RefCell::new(host),
             max_cycles: execution_options.max_cycles(),
+            max_stack_depth: execution_options.max_stack_depth(),
+            fixed_stack_depth: execution_options.fixed_stack_depth(),
             enable_tracing: execution_options.enable_tracing(),
+            location: None,
+            covered_locations: BTreeSet::new(),
+            assertions_checked: 0,
+            context_switches: 0,
+            instruction_log: execution_options.enable_instruction_log().then(Vec::new),
+            enable_memory_write_events: execution_options.enable_memory_write_events(),
+            enable_advice_state: execution_options.enable_advice_state(),
+            default_hdword_domain: Felt::from(execution_options.default_hdword_domain()),
+            checkpoints: Vec::new(),
+            merkle_root_updates: execution_options.enable_merkle_root_updates().then(Vec::new),
+            extra_code_blocks: CodeBlockTable::default(),
         }
     }
 
@@ -222,9 +494,17 @@ where
     /// Executes the provided [Program] in this process.
     pub fn execute(&mut self, program: &Program) -> Result<StackOutputs, ExecutionError> {
         assert_eq!(self.system.clk(), 0, "a program has already been executed in this process");
-        self.execute_code_block(program.root(), program.cb_table())?;
 
-        Ok(self.stack.build_stack_outputs())
+        let result = if self.extra_code_blocks.is_empty() {
+            self.execute_code_block(program.root(), program.cb_table())
+        } else {
+            // merge the pre-registered code blocks into the code block table of the program
+            let mut cb_table = program.cb_table().clone();
+            cb_table.extend(core::mem::take(&mut self.extra_code_blocks));
+            self.execute_code_block(program.root(), &cb_table)
+        };
+
+        result.map(|_| self.stack.build_stack_outputs())
     }
 
     // CODE BLOCK EXECUTORS
@@ -330,8 +610,13 @@ where
         block: &Call,
         cb_table: &CodeBlockTable,
     ) -> Result<(), ExecutionError> {
-        // if this is a syscall, make sure the call target exists in the kernel
+        // if this is a syscall, make sure the call target exists in the kernel and that we are not
+        // already executing a syscall; kernel procedures cannot issue syscalls directly, but they
+        // could still do so by dynamically executing a procedure which issues one.
         if block.is_syscall() {
+            if self.system.in_syscall() {
+                return Err(ExecutionError::NestedSyscall(block.fn_hash()));
+            }
             self.chiplets.access_kernel_proc(block.fn_hash())?;
         }
 
@@ -348,7 +633,7 @@ where
             self.execute_code_block(fn_body, cb_table)?;
         }
 
-        self.end_call_block(block)
+        self.end_call_block(block).map_err(|err| err.with_location(self.location))
     }
 
     /// Executes the specified [Dyn] block.
@@ -401,7 +686,8 @@ where
         // are executed after SPAN block is closed to make sure the VM clock cycle advances beyond
         // the last clock cycle of the SPAN block ops.
         for decorator in decorators {
-            self.execute_decorator(decorator)?;
+            self.execute_decorator(decorator)
+                .map_err(|err| err.with_location(self.location))?;
         }
 
         Ok(())
@@ -433,12 +719,13 @@ where
         // execute operations in the batch one by one
         for (i, &op) in batch.ops().iter().enumerate() {
             while let Some(decorator) = decorators.next_filtered(i + op_offset) {
-                self.execute_decorator(decorator)?;
+                self.execute_decorator(decorator)
+                    .map_err(|err| err.with_location(self.location))?;
             }
 
             // decode and execute the operation
             self.decoder.execute_user_op(op, op_idx);
-            self.execute_op(op)?;
+            self.execute_op(op).map_err(|err| err.with_location(self.location))?;
 
             // if the operation carries an immediate value, the value is stored at the next group
             // pointer; so, we advance the pointer to the following group
@@ -497,13 +784,26 @@ where
     /// Executes the specified decorator
     fn execute_decorator(&mut self, decorator: &Decorator) -> Result<(), ExecutionError> {
         match decorator {
+            Decorator::Advice(AdviceInjector::HdwordToMapDefault) => {
+                let injector = AdviceInjector::HdwordToMap {
+                    domain: self.default_hdword_domain,
+                };
+                self.host.borrow_mut().set_advice(self, injector)?;
+            }
             Decorator::Advice(injector) => {
                 self.host.borrow_mut().set_advice(self, *injector)?;
             }
+            Decorator::Debug(DebugOptions::Checkpoint(label)) => {
+                self.checkpoints.push((*label, self.stack.trace_state()));
+            }
             Decorator::Debug(options) => {
                 self.host.borrow_mut().on_debug(self, options)?;
             }
             Decorator::AsmOp(assembly_op) => {
+                self.location = assembly_op.location().copied();
+                if let Some(location) = self.location {
+                    self.covered_locations.insert(location);
+                }
                 if self.decoder.in_debug_mode() {
                     self.decoder.append_asmop(self.system.clk(), assembly_op.clone());
                 }
@@ -527,6 +827,22 @@ where
         self.chiplets.kernel()
     }
 
+    /// Returns the number of assertions which were executed and satisfied by this process so far.
+    ///
+    /// Every `ASSERT` operation is counted, and thus instructions which compile to several
+    /// assertions (e.g., `assert_eqw`) are counted once per assertion.
+    pub fn assertions_checked(&self) -> u64 {
+        self.assertions_checked
+    }
+
+    /// Returns the number of times this process entered a new execution context so far.
+    ///
+    /// Every `call`, `syscall`, and `dyncall` is counted, regardless of whether the callee was
+    /// executed in the root context (as is the case for kernel procedures).
+    pub fn context_switches(&self) -> u64 {
+        self.context_switches
+    }
+
     pub fn into_parts(self) -> (System, Decoder, Stack, RangeChecker, Chiplets, H) {
         (
             self.system,
@@ -633,5 +949,18 @@ where
     pub chiplets: Chiplets,
     pub host: RefCell<H>,
     pub max_cycles: u32,
+    pub max_stack_depth: usize,
+    pub fixed_stack_depth: bool,
     pub enable_tracing: bool,
+    pub location: Option<SourceLocation>,
+    pub covered_locations: BTreeSet<SourceLocation>,
+    pub assertions_checked: u64,
+    pub context_switches: u64,
+    pub instruction_log: Option<Vec<TraceEntry>>,
+    pub enable_memory_write_events: bool,
+    pub enable_advice_state: bool,
+    pub default_hdword_domain: Felt,
+    pub checkpoints: Vec<(u32, StackTopState)>,
+    pub merkle_root_updates: Option<Vec<(Word, Word)>>,
+    pub extra_code_blocks: CodeBlockTable,
 }
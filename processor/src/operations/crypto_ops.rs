@@ -166,11 +166,16 @@ where
             .set_user_op_helpers(Operation::MrUpdate, &[merkle_tree_update.get_address()]);
 
         // Replace the old node value with computed new root; everything else remains the same.
-        for (i, &value) in merkle_tree_update.get_new_root().iter().rev().enumerate() {
+        let new_root = merkle_tree_update.get_new_root();
+        for (i, &value) in new_root.iter().rev().enumerate() {
             self.stack.set(i, value);
         }
         self.stack.copy_state(4);
 
+        if let Some(updates) = self.merkle_root_updates.as_mut() {
+            updates.push((old_root, new_root));
+        }
+
         Ok(())
     }
 }
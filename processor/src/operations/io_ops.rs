@@ -1,5 +1,5 @@
 use super::{ExecutionError, Felt, Host, Operation, Process};
-use crate::Word;
+use crate::{system::ContextId, MemoryWriteEvent, Word, EMPTY_WORD};
 
 // INPUT / OUTPUT OPERATIONS
 // ================================================================================================
@@ -132,8 +132,14 @@ where
         // build the word in memory order (reverse of stack order)
         let word = [self.stack.get(4), self.stack.get(3), self.stack.get(2), self.stack.get(1)];
 
-        // write the word to memory and get the previous word
-        self.chiplets.write_mem(ctx, addr, word);
+        // write the word to memory, notifying the host about the overwritten word if requested
+        if self.enable_memory_write_events {
+            let old_word = self.chiplets.get_mem_value(ctx, addr).unwrap_or(EMPTY_WORD);
+            self.chiplets.write_mem(ctx, addr, word);
+            self.notify_mem_write(ctx, addr, old_word, word)?;
+        } else {
+            self.chiplets.write_mem(ctx, addr, word);
+        }
 
         // reverse the order of the memory word & update the stack state
         for (i, &value) in word.iter().rev().enumerate() {
@@ -165,6 +171,10 @@ where
 
         // write the value to the memory and get the previous word
         let mut old_word = self.chiplets.write_mem_element(ctx, addr, value);
+        if self.enable_memory_write_events {
+            let new_word = [value, old_word[1], old_word[2], old_word[3]];
+            self.notify_mem_write(ctx, addr, old_word, new_word)?;
+        }
         // put the retrieved word into stack order
         old_word.reverse();
 
@@ -259,10 +269,29 @@ where
     fn get_valid_address(addr: Felt) -> Result<u32, ExecutionError> {
         let addr = addr.as_int();
         if addr > u32::MAX as u64 {
-            return Err(ExecutionError::MemoryAddressOutOfBounds(addr));
+            return Err(ExecutionError::MemoryAddressOutOfBounds(addr, None));
         }
         Ok(addr as u32)
     }
+
+    /// Notifies the host about a write of `new_word` over `old_word` at the specified memory
+    /// address in the specified context.
+    fn notify_mem_write(
+        &mut self,
+        ctx: ContextId,
+        addr: u32,
+        old_word: Word,
+        new_word: Word,
+    ) -> Result<(), ExecutionError> {
+        let event = MemoryWriteEvent {
+            ctx,
+            addr,
+            old_value: old_word,
+            new_value: new_word,
+            clk: self.system.clk(),
+        };
+        self.host.borrow_mut().on_mem_write(event)
+    }
 }
 
 // TESTS
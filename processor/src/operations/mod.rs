@@ -1,4 +1,4 @@
-use super::{ExecutionError, Felt, FieldElement, Host, Operation, Process};
+use super::{ExecutionError, Felt, FieldElement, Host, Operation, Process, TraceEntry};
 use vm_core::stack::STACK_TOP_SIZE;
 
 mod comb_ops;
@@ -27,6 +27,10 @@ where
         // make sure there is enough memory allocated to hold the execution trace
         self.ensure_trace_capacity();
 
+        // record the state of the stack before the operation if the instruction log is enabled
+        let stack_before = self.instruction_log.is_some().then(|| self.stack.trace_state());
+        let clk = self.system.clk();
+
         // execute the operation
         match op {
             // ----- system operations ------------------------------------------------------------
@@ -153,12 +157,48 @@ where
         }
 
         self.advance_clock()?;
+        self.host.borrow_mut().on_step(self, op)?;
+
+        if let Some(stack_before) = stack_before {
+            self.record_log_entry(TraceEntry {
+                clk,
+                op,
+                stack_before,
+                stack_after: self.stack.trace_state(),
+            })?;
+        }
+
+        Ok(())
+    }
 
+    /// Passes the provided entry to the host, and appends it to the instruction log of this
+    /// process unless the host consumed it.
+    fn record_log_entry(&mut self, entry: TraceEntry) -> Result<(), ExecutionError> {
+        let entry = self.host.borrow_mut().on_log_entry(entry)?;
+        if let (Some(entry), Some(log)) = (entry, self.instruction_log.as_mut()) {
+            log.push(entry);
+        }
         Ok(())
     }
 
     /// Increments the clock cycle for all components of the process.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The cycle limit or the stack depth limit of the process was exceeded.
+    /// - The process requires a fixed stack depth, and the stack overflowed its top 16 items.
     fn advance_clock(&mut self) -> Result<(), ExecutionError> {
+        let depth = self.stack.full_depth();
+        if self.fixed_stack_depth && depth > STACK_TOP_SIZE {
+            return Err(ExecutionError::UnexpectedOverflow(self.system.clk()));
+        }
+        if depth > self.max_stack_depth {
+            return Err(ExecutionError::StackDepthLimitExceeded {
+                depth,
+                limit: self.max_stack_depth,
+            });
+        }
+
         self.system.advance_clock(self.max_cycles)?;
         self.stack.advance_clock();
         self.chiplets.advance_clock();
@@ -21,6 +21,7 @@ where
         if self.stack.get(0) != ONE {
             return Err(self.host.borrow_mut().on_assert_failed(self, err_code));
         }
+        self.assertions_checked += 1;
         self.stack.shift_left(1);
         Ok(())
     }
@@ -129,6 +130,7 @@ mod tests {
         process.execute_op(Operation::Drop).unwrap();
 
         assert!(process.execute_op(Operation::Assert(0)).is_ok());
+        assert_eq!(process.assertions_checked(), 1);
     }
 
     #[test]
@@ -8,11 +8,18 @@ use miden_air::trace::main_trace::MainTrace;
 
 /// Describes how to construct execution traces of stack-related auxiliary trace segment columns
 /// (used in multiset checks).
+///
+/// Rows of the overflow table are identified by their address, which is the clock cycle at which
+/// they were added to the table. This relies on the invariant that an operation shifts the stack
+/// at most once per cycle, and thus at most one row is added to (on a right shift) or removed
+/// from (on a left shift) the overflow table in any given cycle. Rows added before the execution
+/// begins use distinct "negative" (mod p) clock cycles as their addresses.
+///
+/// All rows added to and removed from the overflow table during execution are read from the main
+/// trace, and thus only the rows in the table when execution begins are kept by the builder.
 pub struct AuxTraceBuilder {
-    /// A list of all rows that were added to and then removed from the overflow table.
-    pub(super) overflow_table_rows: Vec<OverflowTableRow>,
-    /// The number of rows in the overflow table when execution begins.
-    pub(super) num_init_rows: usize,
+    /// The rows in the overflow table when execution begins.
+    pub(super) init_rows: Vec<OverflowTableRow>,
 }
 
 impl AuxTraceBuilder {
@@ -32,14 +39,17 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for AuxTraceBuilder
     /// Initializes the overflow stack auxiliary column.
     fn init_responses(&self, _main_trace: &MainTrace, alphas: &[E]) -> E {
         let mut initial_column_value = E::ONE;
-        for row in self.overflow_table_rows.iter().take(self.num_init_rows) {
-            let value = (*row).to_value(alphas);
+        for row in self.init_rows.iter() {
+            let value = row.to_value(alphas);
             initial_column_value *= value;
         }
         initial_column_value
     }
 
     /// Removes a row from the stack overflow table.
+    ///
+    /// At most one row is removed per cycle, and the removed row is identified by its address
+    /// `b1`, which is the clock cycle at which it was added.
     fn get_requests_at(&self, main_trace: &MainTrace, alphas: &[E], i: usize) -> E {
         let is_left_shift = main_trace.is_left_shift(i);
         let is_non_empty_overflow = main_trace.is_non_empty_overflow(i);
@@ -57,6 +67,9 @@ impl<E: FieldElement<BaseField = Felt>> AuxColumnBuilder<E> for AuxTraceBuilder
     }
 
     /// Adds a row to the stack overflow table.
+    ///
+    /// At most one row is added per cycle, and thus the clock cycle of the right shift is used as
+    /// the address of the added row.
     fn get_responses_at(&self, main_trace: &MainTrace, alphas: &[E], i: usize) -> E {
         let is_right_shift = main_trace.is_right_shift(i);
 
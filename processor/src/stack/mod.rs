@@ -102,6 +102,14 @@ impl Stack {
         self.active_depth
     }
 
+    /// Returns depth of the stack across all execution contexts at the current clock cycle.
+    ///
+    /// This includes the items of the stack in the current context as well as the items of the
+    /// overflow table which belong to the suspended contexts.
+    pub fn full_depth(&self) -> usize {
+        self.full_depth
+    }
+
     /// Returns the current clock cycle of the execution trace.
     pub fn current_clk(&self) -> u32 {
         self.clk
@@ -137,6 +145,18 @@ impl Stack {
         result
     }
 
+    /// Returns the values in the overflow table which belong to the current execution context, in
+    /// order from the top of the stack.
+    ///
+    /// Values of the overflow table which belong to the suspended execution contexts are not
+    /// included.
+    pub fn get_overflow_values(&self) -> Vec<Felt> {
+        let mut result = Vec::with_capacity(self.full_depth - STACK_TOP_SIZE);
+        self.overflow.append_into(&mut result);
+        result.truncate(self.active_depth - STACK_TOP_SIZE);
+        result
+    }
+
     /// Returns [StackOutputs] consisting of all values on the stack and all addresses in the
     /// overflow table that are required to rebuild the rows in the overflow table.
     pub fn build_stack_outputs(&self) -> StackOutputs {
@@ -330,7 +350,6 @@ impl Stack {
 
     /// Returns state of stack item columns at the current clock cycle. This does not include stack
     /// values in the overflow table.
-    #[cfg(any(test, feature = "internals"))]
     pub fn trace_state(&self) -> [Felt; STACK_TOP_SIZE] {
         self.trace.get_stack_state_at(self.clk)
     }
@@ -10,14 +10,18 @@ use vm_core::{utils::uninit_vector, StarkField};
 /// construction of the auxiliary trace column describing the state of the overflow table at every
 /// VM cycle.
 ///
+/// Rows are dropped from the table as soon as they are removed from it, and thus the memory used
+/// by the table is proportional to the current depth of the stack rather than to the number of
+/// stack shifts executed so far. This is sufficient for building the auxiliary trace column, as
+/// the column is computed from the main trace, except for its initial value.
+///
 /// When `trace_enabled` is set to true, we also record all changes to the table so that we can
 /// reconstruct the overflow table at any clock cycle. This can be used for debugging purposes.
 pub struct OverflowTable {
-    /// A list of all rows that were added to and then removed from the overflow table.
-    all_rows: Vec<OverflowTableRow>,
-    /// A list of indices into the `all_rows` vector which describes the rows currently in the
-    /// overflow table.
-    active_rows: Vec<usize>,
+    /// The rows currently in the overflow table; the row at the top of the table is the last one.
+    active_rows: Vec<OverflowTableRow>,
+    /// The rows which were in the overflow table when execution began.
+    init_rows: Vec<OverflowTableRow>,
     /// A map which records the full state of the overflow table at every cycle during which an
     /// update happened. This map is populated only when `trace_enabled` = true.
     trace: BTreeMap<u64, Vec<Felt>>,
@@ -25,8 +29,6 @@ pub struct OverflowTable {
     /// whenever an update happens. This is set to true only when executing programs for debug
     /// purposes.
     trace_enabled: bool,
-    /// The number of rows in the overflow table when execution begins.
-    num_init_rows: usize,
     /// Holds the address (the clock cycle) of the row at to top of the overflow table. When
     /// entering new execution context, this value is set to ZERO, and thus, will differ from the
     /// row address actually at the top of the table.
@@ -39,11 +41,10 @@ impl OverflowTable {
     /// Returns a new [OverflowTable]. The returned table is empty.
     pub fn new(enable_trace: bool) -> Self {
         Self {
-            all_rows: Vec::new(),
             active_rows: Vec::new(),
+            init_rows: Vec::new(),
             trace: BTreeMap::new(),
             trace_enabled: enable_trace,
-            num_init_rows: 0,
             last_row_addr: ZERO,
         }
     }
@@ -56,13 +57,13 @@ impl OverflowTable {
     /// by one. Thus, the first item in the list will become the deepest item in the stack.
     pub fn new_with_inputs(enable_trace: bool, init_values: &[Felt]) -> Self {
         let mut overflow_table = Self::new(enable_trace);
-        overflow_table.num_init_rows = init_values.len();
 
         let mut clk = Felt::MODULUS - init_values.len() as u64;
         for &val in init_values.iter().rev() {
             overflow_table.push(val, Felt::new(clk));
             clk += 1;
         }
+        overflow_table.init_rows = overflow_table.active_rows.clone();
 
         overflow_table
     }
@@ -80,11 +81,18 @@ impl OverflowTable {
         // and thus, no operation can shift the stack to the right at clk = 0.
         debug_assert_ne!(clk, ZERO, "cannot add value to overflow at clk=0");
 
-        // create and record the new row, and also put it at the top of the overflow table
-        let row_idx = self.all_rows.len() as u32;
+        // two rows with the same address would be indistinguishable in the multiset check; since
+        // no row can be removed in the cycle in which a row is added, a second row added in the
+        // same cycle would be placed right above the first one.
+        debug_assert_ne!(
+            self.active_rows.last().map(|row| row.clk),
+            Some(clk),
+            "multiple rows were added to the overflow table in the same clock cycle"
+        );
+
+        // create the new row and put it at the top of the overflow table
         let new_row = OverflowTableRow::new(clk, value, self.last_row_addr);
-        self.all_rows.push(new_row);
-        self.active_rows.push(row_idx as usize);
+        self.active_rows.push(new_row);
 
         // set the last row address to the address of the newly added row
         self.last_row_addr = clk;
@@ -104,9 +112,8 @@ impl OverflowTable {
             "overflow table is empty in the current context"
         );
 
-        // remove the top entry from the table and determine which table row corresponds to it
-        let last_row_idx = self.active_rows.pop().expect("overflow table is empty");
-        let last_row = &self.all_rows[last_row_idx];
+        // remove the top row from the table
+        let last_row = self.active_rows.pop().expect("overflow table is empty");
 
         // get the value from the last row and also update the last row address to point to the
         // row currently at the top of the table. note that this is context specific. that is,
@@ -134,8 +141,8 @@ impl OverflowTable {
         if last_row_addr != ZERO {
             // if we are not setting the last row address to ZERO, we can set it only to the
             // address of the row actually at the top of the table.
-            let last_row_idx = *self.active_rows.last().expect("overflow table is empty");
-            assert_eq!(self.all_rows[last_row_idx].clk, last_row_addr);
+            let last_row = self.active_rows.last().expect("overflow table is empty");
+            assert_eq!(last_row.clk, last_row_addr);
         }
         self.last_row_addr = last_row_addr;
     }
@@ -153,8 +160,8 @@ impl OverflowTable {
 
     /// Appends the values from the overflow table to the end of the provided vector.
     pub fn append_into(&self, target: &mut Vec<Felt>) {
-        for &idx in self.active_rows.iter().rev() {
-            target.push(self.all_rows[idx].val);
+        for row in self.active_rows.iter().rev() {
+            target.push(row.val);
         }
     }
 
@@ -182,10 +189,10 @@ impl OverflowTable {
 
         let mut addrs = unsafe { uninit_vector(self.active_rows.len() + 1) };
         // add the previous address of the first row in the overflow table.
-        addrs[0] = self.all_rows[self.active_rows[0]].prev;
+        addrs[0] = self.active_rows[0].prev;
         // add the address for all the rows in the overflow table.
-        for (i, &row_idx) in self.active_rows.iter().enumerate() {
-            addrs[i + 1] = self.all_rows[row_idx].clk;
+        for (i, row) in self.active_rows.iter().enumerate() {
+            addrs[i + 1] = row.clk;
         }
 
         addrs
@@ -198,8 +205,7 @@ impl OverflowTable {
     /// the auxiliary trace column describing the state of the overflow table at every cycle.
     pub fn into_aux_builder(self) -> AuxTraceBuilder {
         AuxTraceBuilder {
-            num_init_rows: self.num_init_rows,
-            overflow_table_rows: self.all_rows,
+            init_rows: self.init_rows,
         }
     }
 
@@ -209,7 +215,7 @@ impl OverflowTable {
     /// Saves a copy of the current table state into the trace at the specified clock cycle.
     fn save_current_state(&mut self, clk: u64) {
         debug_assert!(self.trace_enabled, "overflow table trace not enabled");
-        let current_state = self.active_rows.iter().map(|&idx| self.all_rows[idx].val).collect();
+        let current_state = self.active_rows.iter().map(|row| row.val).collect();
         self.trace.insert(clk, current_state);
     }
 
@@ -217,13 +223,13 @@ impl OverflowTable {
     // --------------------------------------------------------------------------------------------
 
     #[cfg(test)]
-    pub fn all_rows(&self) -> &[OverflowTableRow] {
-        &self.all_rows
+    pub fn active_rows(&self) -> &[OverflowTableRow] {
+        &self.active_rows
     }
 
     #[cfg(test)]
-    pub fn active_rows(&self) -> &[usize] {
-        &self.active_rows
+    pub fn init_rows(&self) -> &[OverflowTableRow] {
+        &self.init_rows
     }
 }
 
@@ -235,7 +241,7 @@ impl OverflowTable {
 /// - The clock cycle at which the stack item was pushed into the overflow table.
 /// - The clock cycle of the value which was at the top of the overflow table when this value
 ///   was pushed onto it.
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct OverflowTableRow {
     val: Felt,
     clk: Felt,
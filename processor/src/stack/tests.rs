@@ -57,7 +57,6 @@ fn initialize_overflow() {
         OverflowTableRow::new(Felt::new(init_addr + 1), Felt::new(2), Felt::new(init_addr)),
         OverflowTableRow::new(Felt::new(init_addr + 2), Felt::new(3), Felt::new(init_addr + 1)),
     ];
-    let expected_overflow_active_rows = vec![0, 1, 2];
 
     // Check the stack state.
     assert_eq!(stack.trace_state(), expected_stack);
@@ -66,8 +65,8 @@ fn initialize_overflow() {
     assert_eq!(stack.helpers_state(), expected_helpers);
 
     // Check the overflow table state.
-    assert_eq!(stack.overflow.active_rows(), expected_overflow_active_rows);
-    assert_eq!(stack.overflow.all_rows(), expected_overflow_rows);
+    assert_eq!(stack.overflow.active_rows(), expected_overflow_rows);
+    assert_eq!(stack.overflow.init_rows(), expected_overflow_rows);
 }
 
 // SHIFT LEFT TEST
@@ -162,6 +161,36 @@ fn shift_right() {
     assert_eq!(stack.helpers_state(), expected_helpers);
 }
 
+#[test]
+fn shift_right_left_retains_only_active_rows() {
+    let stack_inputs = (1..=18).collect::<Vec<_>>();
+    let stack_inputs = StackInputs::try_from_ints(stack_inputs).unwrap();
+    let mut stack = Stack::new(&stack_inputs, 4, false);
+
+    // make sure the first right shift is not executed at clk = 0
+    stack.copy_state(0);
+    stack.advance_clock();
+
+    // push and pop values symmetrically over many cycles
+    for _ in 0..1000 {
+        for _ in 0..3 {
+            stack.ensure_trace_capacity();
+            stack.shift_right(0);
+            stack.advance_clock();
+        }
+        for _ in 0..3 {
+            stack.ensure_trace_capacity();
+            stack.shift_left(1);
+            stack.advance_clock();
+        }
+    }
+
+    // removed rows are not retained by the overflow table, but the initial rows are.
+    assert_eq!(stack.overflow.active_rows().len(), 2);
+    assert_eq!(stack.overflow.init_rows(), stack.overflow.active_rows());
+    assert_eq!(stack.trace_state(), build_stack(&(3..=18).rev().collect::<Vec<_>>()));
+}
+
 // CONTEXT MANAGEMENT TEST
 // ================================================================================================
 
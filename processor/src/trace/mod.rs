@@ -2,17 +2,20 @@ use super::{
     chiplets::AuxTraceBuilder as ChipletsAuxTraceBuilder, crypto::RpoRandomCoin,
     decoder::AuxTraceBuilder as DecoderAuxTraceBuilder,
     range::AuxTraceBuilder as RangeCheckerAuxTraceBuilder,
-    stack::AuxTraceBuilder as StackAuxTraceBuilder, ColMatrix, Digest, Felt, FieldElement, Host,
-    Process, StackTopState,
+    stack::AuxTraceBuilder as StackAuxTraceBuilder, AdviceInputs, ColMatrix, Digest, Felt,
+    FieldElement, Host, Process, StackTopState, TraceEntry, Word,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
 };
-use alloc::vec::Vec;
 use miden_air::trace::{
     decoder::{NUM_USER_OP_HELPERS, USER_OP_HELPERS_OFFSET},
     main_trace::MainTrace,
     AUX_TRACE_RAND_ELEMENTS, AUX_TRACE_WIDTH, DECODER_TRACE_OFFSET, MIN_TRACE_LEN,
     STACK_TRACE_OFFSET, TRACE_WIDTH,
 };
-use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, StackOutputs, ZERO};
+use vm_core::{stack::STACK_TOP_SIZE, ProgramInfo, SourceLocation, StackOutputs, ZERO};
 use winter_prover::{crypto::RandomCoin, EvaluationFrame, Trace, TraceLayout};
 
 mod utils;
@@ -54,6 +57,14 @@ pub struct ExecutionTrace {
     program_info: ProgramInfo,
     stack_outputs: StackOutputs,
     trace_len_summary: TraceLenSummary,
+    assertions_checked: u64,
+    context_switches: u64,
+    covered_locations: BTreeSet<SourceLocation>,
+    instruction_log: Vec<TraceEntry>,
+    memory_stats: BTreeMap<u32, usize>,
+    merkle_root_updates: Vec<(Word, Word)>,
+    advice_state: Option<AdviceInputs>,
+    checkpoints: Vec<(u32, StackTopState)>,
 }
 
 impl ExecutionTrace {
@@ -66,7 +77,7 @@ impl ExecutionTrace {
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Builds an execution trace for the provided process.
-    pub(super) fn new<H>(process: Process<H>, stack_outputs: StackOutputs) -> Self
+    pub(super) fn new<H>(mut process: Process<H>, stack_outputs: StackOutputs) -> Self
     where
         H: Host,
     {
@@ -80,6 +91,18 @@ impl ExecutionTrace {
         // create a new program info instance with the underlying kernel
         let kernel = process.kernel().clone();
         let program_info = ProgramInfo::new(program_hash.into(), kernel);
+        let assertions_checked = process.assertions_checked();
+        let context_switches = process.context_switches();
+        let covered_locations = core::mem::take(&mut process.covered_locations);
+        let instruction_log = process.instruction_log.take().unwrap_or_default();
+        let memory_stats = process.chiplets.get_mem_stats();
+        let merkle_root_updates = process.merkle_root_updates.take().unwrap_or_default();
+        let advice_state = if process.enable_advice_state {
+            process.host.borrow().advice_state()
+        } else {
+            None
+        };
+        let checkpoints = core::mem::take(&mut process.checkpoints);
         let (main_trace, aux_trace_hints, trace_len_summary) = finalize_trace(process, rng);
 
         Self {
@@ -90,6 +113,14 @@ impl ExecutionTrace {
             program_info,
             stack_outputs,
             trace_len_summary,
+            assertions_checked,
+            context_switches,
+            covered_locations,
+            instruction_log,
+            memory_stats,
+            merkle_root_updates,
+            advice_state,
+            checkpoints,
         }
     }
 
@@ -111,6 +142,81 @@ impl ExecutionTrace {
         &self.stack_outputs
     }
 
+    /// Returns the number of assertions which were executed and satisfied during the program
+    /// execution which resulted in this execution trace.
+    pub fn assertions_checked(&self) -> u64 {
+        self.assertions_checked
+    }
+
+    /// Returns the number of times a new execution context was entered (i.e., via `call`,
+    /// `syscall`, or `dyncall`) during the program execution which resulted in this execution
+    /// trace.
+    pub fn context_switches(&self) -> u64 {
+        self.context_switches
+    }
+
+    /// Returns the source locations of all instructions executed during the program execution
+    /// which resulted in this execution trace.
+    ///
+    /// Source locations are available only for programs compiled in debug mode; for programs
+    /// compiled without debug information the returned set is always empty.
+    pub fn covered_locations(&self) -> &BTreeSet<SourceLocation> {
+        &self.covered_locations
+    }
+
+    /// Returns the instruction log recorded during the program execution which resulted in this
+    /// execution trace.
+    ///
+    /// The log is empty unless the program was executed with the instruction log enabled, and it
+    /// does not contain the entries which were consumed by the host.
+    pub fn instruction_log(&self) -> &[TraceEntry] {
+        &self.instruction_log
+    }
+
+    /// Returns the number of memory addresses written to during the program execution which
+    /// resulted in this execution trace, keyed by the ID of the execution context.
+    ///
+    /// Every `call` and `dyncall` starts a new execution context with its own memory, and an entry
+    /// is present for every context which accessed memory, even if it only read from it.
+    pub fn memory_stats(&self) -> &BTreeMap<u32, usize> {
+        &self.memory_stats
+    }
+
+    /// Returns the roots of Merkle trees updated via `mtree_set` during the program execution
+    /// which resulted in this execution trace, as `(old_root, new_root)` pairs in the order in
+    /// which the updates were executed.
+    ///
+    /// The new root of an update is computed by the hasher chiplet from the Merkle path provided
+    /// by the advice provider, and thus it can be checked against an independently computed root.
+    ///
+    /// The roots are recorded only when enabled via [ExecutionOptions::with_merkle_root_updates()].
+    ///
+    /// [ExecutionOptions::with_merkle_root_updates()]: crate::ExecutionOptions::with_merkle_root_updates
+    pub fn merkle_root_updates(&self) -> &[(Word, Word)] {
+        &self.merkle_root_updates
+    }
+
+    /// Returns the state of the advice provider at the end of the program execution which
+    /// resulted in this execution trace, i.e., the values remaining on the advice stack, the
+    /// advice map, and the Merkle store.
+    ///
+    /// The state is recorded only if the program was executed with the advice state enabled, and
+    /// the host exposes the state of its advice provider.
+    pub fn advice_state(&self) -> Option<&AdviceInputs> {
+        self.advice_state.as_ref()
+    }
+
+    /// Returns the states of the top 16 stack registers recorded by `debug.checkpoint.<label>`
+    /// decorators during the program execution which resulted in this execution trace, as
+    /// `(label, state)` pairs in the order in which the checkpoints were executed.
+    ///
+    /// A label is recorded every time its checkpoint is executed, e.g., in every iteration of a
+    /// loop. Debug decorators are emitted only by the assembler in debug mode, and thus no
+    /// checkpoints are recorded for programs compiled otherwise.
+    pub fn checkpoints(&self) -> &[(u32, StackTopState)] {
+        &self.checkpoints
+    }
+
     /// Returns the initial state of the top 16 stack registers.
     pub fn init_stack_state(&self) -> StackTopState {
         let mut result = [ZERO; STACK_TOP_SIZE];
@@ -95,3 +95,34 @@ fn p1_trace() {
         assert_eq!(ONE, p1[i]);
     }
 }
+
+#[test]
+#[allow(clippy::needless_range_loop)]
+fn p1_trace_with_init_overflow() {
+    let ops = vec![
+        Operation::Drop,   // left shift, clk 1
+        Operation::Pad,    // right shift, clk 2
+        Operation::Drop,   // left shift, clk 3
+        Operation::Drop,   // left shift, clk 4
+        Operation::U32add, // no shift, clk 5
+    ];
+    let init_stack = (1..19).collect::<Vec<_>>();
+    let mut trace = build_trace_from_ops(ops, &init_stack);
+    let alphas = rand_array::<Felt, AUX_TRACE_RAND_ELEMENTS>();
+    let aux_columns = trace.build_aux_segment(&[], &alphas).unwrap();
+    let p1 = aux_columns.get_column(P1_COL_IDX);
+
+    // the column is initialized with the two rows in the overflow table when execution begins
+    assert_ne!(ONE, p1[0]);
+    assert_eq!(p1[0], p1[1]);
+
+    // DROP removes one of the initial rows, and PAD adds a row which DROP then removes again
+    assert_ne!(p1[1], p1[2]);
+    assert_ne!(p1[2], p1[3]);
+    assert_eq!(p1[2], p1[4]);
+
+    // the last DROP removes the other initial row, which leaves the overflow table empty
+    for i in 5..(p1.len() - NUM_RAND_ROWS) {
+        assert_eq!(ONE, p1[i]);
+    }
+}
@@ -25,7 +25,10 @@ mod gpu;
 // EXPORTS
 // ================================================================================================
 
-pub use air::{DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProvingOptions};
+pub use air::{
+    DeserializationError, ExecutionProof, FieldExtension, HashFunction, ProofSizeBreakdown,
+    ProvingOptions,
+};
 pub use processor::{
     crypto, math, utils, AdviceInputs, Digest, ExecutionError, Host, InputError, MemAdviceProvider,
     Program, StackInputs, StackOutputs, Word,
@@ -1,4 +1,5 @@
 use super::*;
+use test_utils::{stack_to_ints, DefaultHost, ExecutionOptions, MemAdviceProvider};
 
 // TEST DATA
 // ================================================================================================
@@ -250,6 +251,42 @@ fn test_set_empty_key_in_non_empty_leaf() {
     build_test!(source, &init_stack, &[], store, advice_map).expect_stack(&final_stack);
 }
 
+/// Tests that values set in the SMT can be read back when the executor is configured with a
+/// non-zero default domain for `adv.insert_hdword`, as the nodes of the tree are always hashed
+/// with the domain 0
+#[test]
+fn test_smt_set_get_with_default_hdword_domain() {
+    let mut smt = Smt::with_entries(LEAVES).unwrap();
+
+    let source = "
+    use.std::collections::smt
+    # Stack: [V, K, R]
+    begin
+        # save K lower on stack
+        dupw.1 movdnw.3
+        # => [V, K, R, K]
+
+        exec.smt::set dropw swapw
+        # => [K, R_new]
+
+        exec.smt::get
+        # => [V, R_new]
+    end
+    ";
+
+    let key = LEAVES[0].0;
+    let value: [Felt; 4] = [42323_u32.into(); 4];
+    let (init_stack, _, store, advice_map) = prepare_insert_or_set(key, value, &mut smt);
+    let expected_output = build_expected_stack(value, smt.root().into());
+
+    let test = build_test!(source, &init_stack, &[], store, advice_map);
+    let program = test.compile().unwrap();
+    let host = DefaultHost::new(MemAdviceProvider::from(test.advice_inputs.clone()));
+    let options = ExecutionOptions::default().with_default_hdword_domain(3);
+    let trace = processor::execute(&program, test.stack_inputs.clone(), host, options).unwrap();
+    assert_eq!(stack_to_ints(trace.stack_outputs().stack_truncated(8)), expected_output);
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -1,5 +1,5 @@
 use processor::ExecutionError;
-use test_utils::{build_expected_hash, build_expected_perm, TestError};
+use test_utils::{build_expected_hash, build_expected_perm, hash_elements, Felt, TestError};
 
 #[test]
 fn test_invalid_end_addr() {
@@ -268,3 +268,40 @@ fn test_state_to_digest() {
 
     build_test!(even_words, &[]).expect_stack(&even_hash);
 }
+
+#[test]
+fn test_verify_chain() {
+    let source = "
+    use.std::crypto::hashes::native
+
+    begin
+        exec.native::verify_chain
+    end
+    ";
+
+    // the stack inputs consist of the target, the starting value, and the length of the chain
+    let start = [1, 2, 3, 4];
+    let target = (0..5).fold(start, |link, _| {
+        let hash = hash_elements(&link.map(Felt::new));
+        <[Felt; 4]>::from(hash).map(|element| element.as_int())
+    });
+    let inputs = |target: [u64; 4], length| {
+        target.into_iter().chain(start).chain([length]).collect::<Vec<_>>()
+    };
+
+    build_test!(source, &inputs(target, 5)).expect_stack(&[]);
+    build_test!(source, &inputs(target, 5)).prove_and_verify(inputs(target, 5), false);
+    build_test!(source, &inputs(start, 0)).expect_stack(&[]);
+
+    // a chain which is shorter or longer than the one leading to the target is rejected
+    for length in [4, 6] {
+        let result = build_test!(source, &inputs(target, length)).execute();
+        assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+    }
+
+    // a wrong target is rejected
+    let mut wrong_target = target;
+    wrong_target[0] += 1;
+    let result = build_test!(source, &inputs(wrong_target, 5)).execute();
+    assert!(matches!(result, Err(ExecutionError::FailedAssertion { .. })));
+}
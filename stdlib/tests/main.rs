@@ -2,9 +2,8 @@
 #[macro_export]
 macro_rules! build_test {
     ($($params:tt)+) => {{
-        let mut test = test_utils::build_test_by_mode!(false, $($params)+);
-        test.libraries = vec![miden_stdlib::StdLibrary::default().into()];
-        test
+        test_utils::build_test_by_mode!(false, $($params)+)
+            .with_library(miden_stdlib::StdLibrary::default())
     }}
 }
 
@@ -13,3 +12,4 @@ mod crypto;
 mod math;
 mod mem;
 mod sys;
+mod utils;
@@ -1,4 +1,5 @@
 pub mod ecgfp5;
 mod secp256k1;
 mod u256_mod;
+mod u32_mod;
 mod u64_mod;
@@ -0,0 +1,78 @@
+use processor::ExecutionError;
+use test_utils::{Felt, StarkField, TestError, U32_BOUND, ZERO};
+
+// ASSERTIONS
+// ------------------------------------------------------------------------------------------------
+
+#[test]
+fn assert_all() {
+    let words = [[0, 1, 2, 3], [u32::MAX as u64, 7, 8, 9], [10, 11, 12, 13]];
+    let source = build_assert_all_source(&words);
+
+    let test = build_test!(&source, &[]);
+    test.expect_stack(&[]);
+    test.prove_and_verify(vec![], false);
+
+    // an empty range is always valid
+    let source = "
+        use.std::math::u32
+        begin
+            push.5.5
+            exec.u32::assert_all
+        end";
+    let test = build_test!(source, &[]);
+    test.expect_stack(&[]);
+}
+
+#[test]
+fn assert_all_fail() {
+    let words = [[0, 1, 2, 3], [4, 5, U32_BOUND, 7], [8, 9, 10, 11]];
+    let source = build_assert_all_source(&words);
+
+    let test = build_test!(&source, &[]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(U32_BOUND),
+        ZERO,
+    )));
+
+    // the addresses must be u32 values, even if the range would otherwise be empty
+    let end_addr = Felt::MODULUS - 1;
+    let source = format!(
+        "
+        use.std::math::u32
+        begin
+            push.{end_addr}.0
+            exec.u32::assert_all
+        end"
+    );
+    let test = build_test!(&source, &[]);
+    test.expect_error(TestError::ExecutionError(ExecutionError::NotU32Value(
+        Felt::new(end_addr),
+        ZERO,
+    )));
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Builds a program which stores the provided words at consecutive memory addresses starting at
+/// address 0, and then checks all of them with `u32::assert_all`.
+fn build_assert_all_source(words: &[[u64; 4]]) -> String {
+    let stores = words
+        .iter()
+        .enumerate()
+        .map(|(addr, [a, b, c, d])| format!("push.{d}.{c}.{b}.{a} mem_storew.{addr} dropw"))
+        .collect::<Vec<_>>()
+        .join("\n            ");
+
+    format!(
+        "
+        use.std::math::u32
+        begin
+            {stores}
+            push.{}.0
+            exec.u32::assert_all
+        end",
+        words.len()
+    )
+}
@@ -396,6 +396,47 @@ fn unchecked_eqz() {
     build_test!(source, &[a0, a1]).expect_stack(&[c]);
 }
 
+#[test]
+fn unchecked_cmp() {
+    let source = "
+        use.std::math::u64
+        begin
+            exec.u64::cmp
+        end";
+
+    // a = 0, b = 0
+    build_test!(source, &[0, 0, 0, 0]).expect_stack(&[1]);
+
+    // a = 0, b = 1
+    build_test!(source, &[0, 0, 1, 0]).expect_stack(&[0]);
+
+    // a = 1, b = 0
+    build_test!(source, &[1, 0, 0, 0]).expect_stack(&[2]);
+
+    // a = u64::MAX, b = u64::MAX
+    let max = U32_BOUND - 1;
+    build_test!(source, &[max, max, max, max]).expect_stack(&[1]);
+
+    // a = u64::MAX - 1, b = u64::MAX
+    build_test!(source, &[max - 1, max, max, max]).expect_stack(&[0]);
+
+    // a = u64::MAX, b = 2^32
+    build_test!(source, &[max, max, 0, 1]).expect_stack(&[2]);
+
+    // randomized test
+    let a: u64 = rand_value();
+    let b: u64 = rand_value();
+    let c = match a.cmp(&b) {
+        cmp::Ordering::Less => 0,
+        cmp::Ordering::Equal => 1,
+        cmp::Ordering::Greater => 2,
+    };
+
+    let (a1, a0) = split_u64(a);
+    let (b1, b0) = split_u64(b);
+    build_test!(source, &[a0, a1, b0, b1]).expect_stack(&[c]);
+}
+
 // DIVISION
 // ------------------------------------------------------------------------------------------------
 
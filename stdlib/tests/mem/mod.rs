@@ -1,7 +1,7 @@
 use processor::{ContextId, DefaultHost, ProcessState};
 use test_utils::{
-    build_expected_hash, build_expected_perm, stack_to_ints, ExecutionOptions, Process,
-    StackInputs, ONE, ZERO,
+    build_expected_hash, build_expected_perm, stack_to_ints, ExecutionOptions, Felt, Process,
+    StackInputs, StarkField, ONE, U32_BOUND, ZERO,
 };
 
 #[test]
@@ -216,3 +216,56 @@ fn test_pipe_preimage_to_memory_invalid_preimage() {
     let res = build_test!(three_words, operand_stack, &advice_stack).execute();
     assert!(res.is_err());
 }
+
+#[test]
+fn test_u32_le_round_trip() {
+    let mem_addr = 1000;
+    let source = format!(
+        "use.std::mem
+
+        begin
+            push.{mem_addr} swap exec.mem::store_u32_le
+            push.{mem_addr} exec.mem::load_u32_le
+        end"
+    );
+
+    for value in [0, 1, 255, 256, 0x1234_5678, 0xfedc_ba98, u32::MAX] {
+        let test = build_test!(&source, &[value as u64]);
+        test.expect_stack(&[value as u64]);
+
+        // the bytes are stored in little-endian order, one byte per memory address
+        let process = test.execute_process().unwrap();
+        for (offset, byte) in value.to_le_bytes().into_iter().enumerate() {
+            let word = process.get_mem_value(ContextId::root(), mem_addr + offset as u32).unwrap();
+            assert_eq!(word[0], Felt::from(byte), "byte {offset} of {value:#x}");
+        }
+    }
+}
+
+#[test]
+fn test_u32_le_invalid_values() {
+    let source = "
+    use.std::mem
+
+    begin
+        push.1000 swap exec.mem::store_u32_le
+    end";
+    let test = build_test!(source, &[U32_BOUND]);
+    assert!(test.execute().is_err());
+
+    // a memory cell holding a value which is not a byte cannot be loaded, including values which
+    // are not u32 values
+    for value in [256, U32_BOUND, Felt::MODULUS - 1] {
+        let source = format!(
+            "
+            use.std::mem
+
+            begin
+                push.{value} mem_store.1001
+                push.1000 exec.mem::load_u32_le
+            end"
+        );
+        let test = build_test!(&source, &[]);
+        assert!(test.execute().is_err());
+    }
+}
@@ -0,0 +1,27 @@
+#[test]
+fn select_word() {
+    let source = "
+    use.std::utils
+    begin
+        exec.utils::select_word
+    end";
+
+    // the stack is [flag, A, B, 20, 21, ...]; the non-selected word must be fully dropped, so
+    // the values below the two words are shifted up by 4 positions
+    let word_a = [1, 2, 3, 4];
+    let word_b = [5, 6, 7, 8];
+    let tail = [20, 21, 22, 23, 24, 25, 26];
+
+    for (flag, selected) in [(1, word_a), (0, word_b)] {
+        let mut inputs = tail.iter().rev().copied().collect::<Vec<u64>>();
+        inputs.extend(word_b.iter().rev());
+        inputs.extend(word_a.iter().rev());
+        inputs.push(flag);
+
+        let mut expected = selected.to_vec();
+        expected.extend(tail);
+        expected.resize(16, 0);
+
+        build_test!(source, &inputs).expect_stack(&expected);
+    }
+}
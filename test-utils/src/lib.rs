@@ -6,7 +6,7 @@ extern crate std;
 #[macro_use]
 extern crate alloc;
 
-use alloc::{string::String, vec::Vec};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 // IMPORTS
 // ================================================================================================
 #[cfg(not(target_family = "wasm"))]
@@ -92,9 +92,15 @@ pub enum TestError {
 /// AssemblyError which contains the specified substring.
 /// - Execution error test: check that running a program compiled from the given source causes
 ///   an ExecutionError which contains the specified substring.
+///
+/// The source is compiled against `kernel`, unless `active_kernel` names one of the kernels in
+/// `named_kernels`, in which case that kernel is used instead. This allows running the same test
+/// against several kernel implementations.
 pub struct Test {
     pub source: String,
     pub kernel: Option<String>,
+    pub named_kernels: BTreeMap<String, String>,
+    pub active_kernel: Option<String>,
     pub stack_inputs: StackInputs,
     pub advice_inputs: AdviceInputs,
     pub in_debug_mode: bool,
@@ -110,6 +116,8 @@ impl Test {
         Test {
             source: String::from(source),
             kernel: None,
+            named_kernels: BTreeMap::new(),
+            active_kernel: None,
             stack_inputs: StackInputs::default(),
             advice_inputs: AdviceInputs::default(),
             in_debug_mode,
@@ -117,6 +125,33 @@ impl Test {
         }
     }
 
+    /// Adds the provided library to the set of libraries the test's source is compiled with.
+    pub fn with_library<L>(mut self, library: L) -> Self
+    where
+        L: Into<MaslLibrary>,
+    {
+        self.libraries.push(library.into());
+        self
+    }
+
+    /// Registers the provided kernel source under the specified name, and makes it the kernel
+    /// against which the test's source is compiled.
+    pub fn with_named_kernel(mut self, name: &str, source: &str) -> Self {
+        self.named_kernels.insert(String::from(name), String::from(source));
+        self.active_kernel = Some(String::from(name));
+        self
+    }
+
+    /// Makes the kernel registered under the specified name the kernel against which the test's
+    /// source is compiled on subsequent runs.
+    ///
+    /// # Panics
+    /// Panics if no kernel was registered under the specified name.
+    pub fn select_kernel(&mut self, name: &str) {
+        assert!(self.named_kernels.contains_key(name), "kernel '{name}' is not registered");
+        self.active_kernel = Some(String::from(name));
+    }
+
     // TEST METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -208,7 +243,16 @@ impl Test {
             .with_libraries(self.libraries.iter())
             .expect("failed to load stdlib");
 
-        match self.kernel.as_ref() {
+        let kernel = match self.active_kernel.as_ref() {
+            Some(name) => Some(
+                self.named_kernels
+                    .get(name)
+                    .unwrap_or_else(|| panic!("kernel '{name}' is not registered")),
+            ),
+            None => self.kernel.as_ref(),
+        };
+
+        match kernel {
             Some(kernel) => assembler.with_kernel(kernel).expect("kernel compilation failed"),
             None => assembler,
         }
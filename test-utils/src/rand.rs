@@ -1,4 +1,5 @@
-use super::{Felt, Word};
+use super::{Felt, StarkField, String, Word, STACK_TOP_SIZE};
+use core::fmt::Write;
 
 pub use rand_utils::*;
 
@@ -18,6 +19,140 @@ pub fn seeded_element(seed: &mut u64) -> Felt {
     Felt::new(num)
 }
 
+// RANDOM PROGRAMS
+// ================================================================================================
+
+/// Maximum nesting depth of control flow blocks in randomly generated programs.
+const MAX_BLOCK_NESTING: usize = 2;
+
+/// Stack depth above which randomly generated programs prefer instructions which shrink the stack.
+const MAX_STACK_DEPTH: usize = STACK_TOP_SIZE + 16;
+
+/// Mutates a seed and generates a random, but valid, Miden assembly program deterministically.
+///
+/// The program consists of at most `max_instructions` instructions (not counting the instructions
+/// needed to balance the stack), and may contain nested `if.true`, `while.true`, and `repeat`
+/// blocks. Each block leaves the stack at the same depth it was entered with, and the program
+/// itself ends with exactly 16 items on the stack. The generated instructions never fail, and
+/// thus the program can be executed and proven with any stack inputs.
+pub fn seeded_program(seed: &mut u64, max_instructions: usize) -> String {
+    let mut generator = ProgramGenerator {
+        seed,
+        remaining: max_instructions,
+        depth: STACK_TOP_SIZE,
+    };
+
+    let mut body = String::new();
+    while generator.remaining > 0 {
+        generator.write_block(&mut body, 0);
+    }
+    generator.restore_depth(&mut body, STACK_TOP_SIZE);
+
+    format!("begin\n{body}end")
+}
+
+/// A generator of random Miden assembly programs which tracks the depth of the stack.
+struct ProgramGenerator<'a> {
+    seed: &'a mut u64,
+    remaining: usize,
+    depth: usize,
+}
+
+impl ProgramGenerator<'_> {
+    /// Writes a random sequence of instructions into the target, such that the depth of the stack
+    /// after the sequence is the same as before it.
+    fn write_block(&mut self, target: &mut String, nesting: usize) {
+        let initial_depth = self.depth;
+        let num_instructions = self.next_u64(8) as usize + 1;
+        for _ in 0..num_instructions {
+            if self.remaining == 0 {
+                break;
+            }
+            self.remaining -= 1;
+
+            if nesting < MAX_BLOCK_NESTING && self.next_u64(8) == 0 {
+                self.write_control_block(target, nesting);
+            } else {
+                self.write_instruction(target);
+            }
+        }
+        self.restore_depth(target, initial_depth);
+    }
+
+    /// Writes a random control flow block into the target.
+    fn write_control_block(&mut self, target: &mut String, nesting: usize) {
+        match self.next_u64(3) {
+            0 => {
+                // the condition is always binary as it is the result of a comparison
+                let value = self.next_u64(4);
+                writeln!(target, "dup eq.{value} if.true").unwrap();
+                self.write_block(target, nesting + 1);
+                writeln!(target, "else").unwrap();
+                self.write_block(target, nesting + 1);
+                writeln!(target, "end").unwrap();
+            }
+            1 => {
+                // the body of the loop is executed exactly once
+                writeln!(target, "push.1 while.true").unwrap();
+                self.write_block(target, nesting + 1);
+                writeln!(target, "push.0 end").unwrap();
+            }
+            _ => {
+                let times = self.next_u64(3) + 1;
+                writeln!(target, "repeat.{times}").unwrap();
+                self.write_block(target, nesting + 1);
+                writeln!(target, "end").unwrap();
+            }
+        }
+    }
+
+    /// Writes a random instruction which cannot fail into the target and updates the tracked
+    /// stack depth.
+    fn write_instruction(&mut self, target: &mut String) {
+        // prefer instructions which shrink the stack if the stack is already deep
+        let num_choices = if self.depth > MAX_STACK_DEPTH { 4 } else { 16 };
+        let (instruction, depth_change) = match self.next_u64(num_choices) {
+            0 => (String::from("add"), -1),
+            1 => (String::from("mul"), -1),
+            2 => (String::from("drop"), -1),
+            3 => (String::from("eq"), -1),
+            4 => (format!("push.{}", self.next_u64(Felt::MODULUS)), 1),
+            5 => (format!("dup.{}", self.next_u64(16)), 1),
+            6 => (String::from("u32split"), 1),
+            7 => (format!("swap.{}", self.next_u64(15) + 1), 0),
+            8 => (format!("movup.{}", self.next_u64(14) + 2), 0),
+            9 => (format!("movdn.{}", self.next_u64(14) + 2), 0),
+            10 => (String::from("neg"), 0),
+            11 => (String::from("add.1"), 0),
+            12 => (format!("swapw.{}", self.next_u64(3) + 1), 0),
+            13 => (String::from("hperm"), 0),
+            14 => (format!("mem_storew.{}", self.next_u64(4)), 0),
+            _ => (format!("mem_loadw.{}", self.next_u64(4)), 0),
+        };
+        writeln!(target, "{instruction}").unwrap();
+        self.depth = (self.depth as isize + depth_change).max(STACK_TOP_SIZE as isize) as usize;
+    }
+
+    /// Writes instructions which bring the depth of the stack to the specified depth into the
+    /// target.
+    fn restore_depth(&mut self, target: &mut String, depth: usize) {
+        while self.depth > depth {
+            writeln!(target, "drop").unwrap();
+            self.depth -= 1;
+        }
+        while self.depth < depth {
+            writeln!(target, "push.0").unwrap();
+            self.depth += 1;
+        }
+    }
+
+    /// Mutates the seed and returns a random value smaller than `bound`.
+    fn next_u64(&mut self, bound: u64) -> u64 {
+        let seed = generate_bytes_seed(self.seed);
+        prng_array::<u64, 1>(seed)[0] % bound
+    }
+}
+
 // HELPERS
 // ================================================================================================
 
@@ -39,6 +39,9 @@ macro_rules! build_op_test {
 /// `merkle_store` are also expected.
 /// * `merkle_store` (optional): the initial merkle set values. When provided, `stack_inputs` and
 /// `advice_stack` are also expected.
+///
+/// Libraries the source is compiled with can be specified right after the `source` as
+/// `libs = [...]`, e.g. `build_test!(source, libs = [StdLibrary::default()], &[1, 2])`.
 #[macro_export]
 macro_rules! build_test {
     ($($params:tt)+) => {{
@@ -59,6 +62,9 @@ macro_rules! build_test {
 /// `merkle_store` are also expected.
 /// * `merkle_store` (optional): the initial merkle set values. When provided, `stack_inputs` and
 /// `advice_stack` are also expected.
+///
+/// Libraries the source is compiled with can be specified right after the `source` as
+/// `libs = [...]`, e.g. `build_debug_test!(source, libs = [StdLibrary::default()], &[1, 2])`.
 #[macro_export]
 macro_rules! build_debug_test {
     ($($params:tt)+) => {{
@@ -77,6 +83,10 @@ macro_rules! build_debug_test {
 /// Instead, the build_test and build_debug_test wrappers should be used.
 #[macro_export]
 macro_rules! build_test_by_mode {
+    ($in_debug_mode:expr, $source:expr, libs = [$($lib:expr),* $(,)?] $(, $($tail:tt)+)?) => {{
+        let test = $crate::build_test_by_mode!($in_debug_mode, $source $(, $($tail)+)?);
+        test$(.with_library($lib))*
+    }};
     ($in_debug_mode:expr, $source:expr) => {{
         $crate::Test::new($source, $in_debug_mode)
     }};
@@ -88,6 +98,8 @@ macro_rules! build_test_by_mode {
         $crate::Test {
             source: String::from($source),
             kernel: None,
+            named_kernels: Default::default(),
+            active_kernel: None,
             stack_inputs,
             advice_inputs,
             in_debug_mode: $in_debug_mode,
@@ -109,6 +121,8 @@ macro_rules! build_test_by_mode {
         $crate::Test {
             source: std::string::String::from($source),
             kernel: None,
+            named_kernels: Default::default(),
+            active_kernel: None,
             stack_inputs,
             advice_inputs,
             in_debug_mode: $in_debug_mode,
@@ -129,6 +143,8 @@ macro_rules! build_test_by_mode {
         $crate::Test {
             source: String::from($source),
             kernel: None,
+            named_kernels: Default::default(),
+            active_kernel: None,
             stack_inputs,
             advice_inputs,
             in_debug_mode: $in_debug_mode,
@@ -148,6 +164,8 @@ macro_rules! build_test_by_mode {
         $crate::Test {
             source: String::from($source),
             kernel: None,
+            named_kernels: Default::default(),
+            active_kernel: None,
             stack_inputs,
             advice_inputs,
             in_debug_mode: $in_debug_mode,
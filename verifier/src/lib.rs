@@ -6,13 +6,25 @@ extern crate std;
 #[macro_use]
 extern crate alloc;
 
-use air::{HashFunction, ProcessorAir, ProvingOptions, PublicInputs};
+use air::{HashFunction, ProcessorAir, ProvingOptions};
+use alloc::{string::ToString, vec::Vec};
 use core::fmt;
-use vm_core::crypto::{
-    hash::{Blake3_192, Blake3_256, Rpo256},
-    random::{RpoRandomCoin, WinterRandomCoin},
+use vm_core::{
+    crypto::{
+        hash::{Blake3_192, Blake3_256, ElementHasher, Rpo256},
+        random::{RandomCoin, RpoRandomCoin, WinterRandomCoin},
+    },
+    utils::DeserializationError,
+    Felt, FieldElement, StarkField, ToElements,
+};
+use winter_verifier::{
+    math::{
+        fields::{CubeExtension, QuadExtension},
+        polynom,
+    },
+    verify as verify_proof, Air, AuxTraceRandElements, ConstraintCompositionCoefficients,
+    EvaluationFrame, FieldExtension, StarkProof,
 };
-use winter_verifier::verify as verify_proof;
 
 // EXPORTS
 // ================================================================================================
@@ -22,7 +34,7 @@ pub use winter_verifier::{AcceptableOptions, VerifierError};
 pub mod math {
     pub use vm_core::{Felt, FieldElement, StarkField};
 }
-pub use air::ExecutionProof;
+pub use air::{ExecutionProof, PublicInputs};
 
 // VERIFIER
 // ================================================================================================
@@ -54,33 +66,72 @@ pub use air::ExecutionProof;
 /// - The provided proof does not prove a correct execution of the program.
 /// - The the protocol parameters used to generate the proof is not in the set of acceptable
 ///   parameters.
-#[tracing::instrument("verify_program", skip_all)]
 pub fn verify(
     program_info: ProgramInfo,
     stack_inputs: StackInputs,
     stack_outputs: StackOutputs,
     proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    verify_public_inputs(pub_inputs, proof)
+}
+
+/// Returns the security level of the proof if the specified program was executed correctly against
+/// the specified inputs and outputs, and the proof provides at least `min_security_level` bits of
+/// security.
+///
+/// This is the same as [verify()], except that proofs which are valid but were generated using
+/// parameters providing less than the required level of security are rejected as well. Since the
+/// security level of a proof depends only on its parameters, it is checked before the proof is
+/// verified.
+///
+/// # Errors
+/// Returns an error if:
+/// - The security level of the proof is smaller than `min_security_level`.
+/// - Verification of the proof via [verify()] fails.
+#[tracing::instrument("verify_program_with_min_security", skip_all)]
+pub fn verify_with_min_security(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+    min_security_level: u32,
+) -> Result<u32, VerificationError> {
+    let security_level = proof.security_level();
+    if security_level < min_security_level {
+        return Err(VerificationError::InsufficientSecurityLevel {
+            actual: security_level,
+            required: min_security_level,
+        });
+    }
+
+    verify(program_info, stack_inputs, stack_outputs, proof)
+}
+
+/// Returns the security level of the proof if the program was executed correctly against the
+/// provided public inputs.
+///
+/// This is the same as [verify()], except that the program info, stack inputs, and stack outputs
+/// are passed bundled together as [PublicInputs].
+#[tracing::instrument("verify_program", skip_all)]
+pub fn verify_public_inputs(
+    pub_inputs: PublicInputs,
+    proof: ExecutionProof,
 ) -> Result<u32, VerificationError> {
     // get security level of the proof
     let security_level = proof.security_level();
 
-    // build public inputs and try to verify the proof
-    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    // try to verify the proof against the public inputs
     let (hash_fn, proof) = proof.into_parts();
+    let opts = acceptable_options(hash_fn);
     match hash_fn {
         HashFunction::Blake3_192 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_96_BITS]);
             verify_proof::<ProcessorAir, Blake3_192, WinterRandomCoin<_>>(proof, pub_inputs, &opts)
         }
         HashFunction::Blake3_256 => {
-            let opts = AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_128_BITS]);
             verify_proof::<ProcessorAir, Blake3_256, WinterRandomCoin<_>>(proof, pub_inputs, &opts)
         }
         HashFunction::Rpo256 => {
-            let opts = AcceptableOptions::OptionSet(vec![
-                ProvingOptions::RECURSIVE_96_BITS,
-                ProvingOptions::RECURSIVE_128_BITS,
-            ]);
             verify_proof::<ProcessorAir, Rpo256, RpoRandomCoin>(proof, pub_inputs, &opts)
         }
     }
@@ -89,6 +140,353 @@ pub fn verify(
     Ok(security_level)
 }
 
+// BATCH VERIFIER
+// ================================================================================================
+/// Returns the security levels of the provided proofs if all of them attest to correct executions
+/// of the specified program against their respective inputs and outputs.
+///
+/// Every item is a tuple of stack inputs, stack outputs, and a proof, which is verified in the
+/// same way as by [verify()]. All items are verified even if some of them fail, and the security
+/// levels are returned in the order of the provided items. When the `concurrent` feature is
+/// enabled, the items are verified in parallel.
+///
+/// # Errors
+/// Returns an error listing the indexes of all items which failed to verify together with the
+/// reasons of their failures.
+#[tracing::instrument("verify_program_batch", skip_all)]
+pub fn verify_batch(
+    program_info: ProgramInfo,
+    items: Vec<(StackInputs, StackOutputs, ExecutionProof)>,
+) -> Result<Vec<u32>, BatchVerificationError> {
+    let verify_item = |(stack_inputs, stack_outputs, proof)| {
+        verify(program_info.clone(), stack_inputs, stack_outputs, proof)
+    };
+
+    #[cfg(not(feature = "concurrent"))]
+    let results = items.into_iter().map(verify_item).collect::<Vec<_>>();
+
+    #[cfg(feature = "concurrent")]
+    let results = {
+        use rayon::prelude::*;
+        items.into_par_iter().map(verify_item).collect::<Vec<_>>()
+    };
+
+    let mut security_levels = Vec::with_capacity(results.len());
+    let mut failures = Vec::new();
+    for (index, result) in results.into_iter().enumerate() {
+        match result {
+            Ok(security_level) => security_levels.push(security_level),
+            Err(err) => failures.push((index, err)),
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(security_levels)
+    } else {
+        Err(BatchVerificationError { failures })
+    }
+}
+
+// PARTIAL VERIFIER
+// ================================================================================================
+/// Returns the security level of the proof if the proof is well-formed for the specified program
+/// and inputs, and is consistent with them at the out-of-domain point, **without** verifying the
+/// low-degree proof.
+///
+/// THIS FUNCTION IS NOT CRYPTOGRAPHICALLY SOUND. It is meant only for quick smoke testing in
+/// pipelines where the soundness of proofs is guaranteed by other means (e.g., the prover is
+/// trusted). A proof accepted by this function may still be rejected by [verify()].
+///
+/// Specifically, this function checks that:
+/// - The proof was generated using one of the parameter sets accepted by [verify()].
+/// - The proof is defined over the same base field as the Miden VM.
+/// - The commitments, trace and constraint queries, FRI proof, and out-of-domain frame contained
+///   in the proof can be parsed into the shapes expected by the Miden VM AIR instantiated with the
+///   provided public inputs.
+/// - The evaluations of the constraints over the out-of-domain frame are consistent with the
+///   out-of-domain evaluations of the constraint composition polynomial. Since the out-of-domain
+///   point is drawn from a public coin seeded with the public inputs, and the boundary constraints
+///   are defined by the program hash, stack inputs, and stack outputs, this binds the proof to the
+///   provided public inputs.
+///
+/// Neither the proof-of-work, nor the Merkle authentication paths of the queried values, nor the
+/// FRI layers are verified, and thus the out-of-domain frame is not checked to be consistent with
+/// the committed trace.
+///
+/// # Errors
+/// Returns an error if:
+/// - The protocol parameters used to generate the proof are not in the set of acceptable
+///   parameters.
+/// - The proof is structurally malformed.
+/// - The out-of-domain frame of the proof is inconsistent with the provided public inputs.
+#[tracing::instrument("verify_program_completeness_only", skip_all)]
+pub fn verify_completeness_only(
+    program_info: ProgramInfo,
+    stack_inputs: StackInputs,
+    stack_outputs: StackOutputs,
+    proof: ExecutionProof,
+) -> Result<u32, VerificationError> {
+    // get security level of the proof
+    let security_level = proof.security_level();
+
+    // build public inputs and check the proof against them
+    let pub_inputs = PublicInputs::new(program_info, stack_inputs, stack_outputs);
+    let (hash_fn, proof) = proof.into_parts();
+    let opts = acceptable_options(hash_fn);
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            check_proof::<Blake3_192, WinterRandomCoin<_>>(proof, pub_inputs, &opts)
+        }
+        HashFunction::Blake3_256 => {
+            check_proof::<Blake3_256, WinterRandomCoin<_>>(proof, pub_inputs, &opts)
+        }
+        HashFunction::Rpo256 => check_proof::<Rpo256, RpoRandomCoin>(proof, pub_inputs, &opts),
+    }
+    .map_err(VerificationError::VerifierError)?;
+
+    Ok(security_level)
+}
+
+/// Returns the sets of protocol parameters which are accepted for proofs generated using the
+/// specified hash function.
+fn acceptable_options(hash_fn: HashFunction) -> AcceptableOptions {
+    match hash_fn {
+        HashFunction::Blake3_192 => {
+            AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_96_BITS])
+        }
+        HashFunction::Blake3_256 => {
+            AcceptableOptions::OptionSet(vec![ProvingOptions::REGULAR_128_BITS])
+        }
+        HashFunction::Rpo256 => AcceptableOptions::OptionSet(vec![
+            ProvingOptions::RECURSIVE_96_BITS,
+            ProvingOptions::RECURSIVE_128_BITS,
+        ]),
+    }
+}
+
+/// Checks that the proof was generated with acceptable options, that it is well-formed in the
+/// context of the Miden VM AIR instantiated with the provided public inputs, and that its
+/// out-of-domain frame is consistent with the public inputs.
+fn check_proof<H, R>(
+    proof: StarkProof,
+    pub_inputs: PublicInputs,
+    acceptable_options: &AcceptableOptions,
+) -> Result<(), VerifierError>
+where
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    acceptable_options.validate::<H>(&proof)?;
+
+    // make sure AIR and proof base fields are the same
+    if Felt::get_modulus_le_bytes() != proof.context.field_modulus_bytes() {
+        return Err(VerifierError::InconsistentBaseField);
+    }
+
+    // the public coin is seeded in the same way as by the full verifier
+    let mut public_coin_seed = proof.context.to_elements();
+    public_coin_seed.append(&mut pub_inputs.to_elements());
+    let public_coin = R::new(&public_coin_seed);
+
+    let air = ProcessorAir::new(proof.get_trace_info(), pub_inputs, proof.options().clone());
+    match air.options().field_extension() {
+        FieldExtension::None => check_proof_over::<Felt, H, R>(&air, proof, public_coin),
+        FieldExtension::Quadratic => {
+            check_proof_over::<QuadExtension<Felt>, H, R>(&air, proof, public_coin)
+        }
+        FieldExtension::Cubic => {
+            check_proof_over::<CubeExtension<Felt>, H, R>(&air, proof, public_coin)
+        }
+    }
+}
+
+/// Parses all parts of the proof over the extension field `E`, and checks the out-of-domain
+/// consistency of the constraint evaluations.
+fn check_proof_over<E, H, R>(
+    air: &ProcessorAir,
+    proof: StarkProof,
+    mut public_coin: R,
+) -> Result<(), VerifierError>
+where
+    E: FieldElement<BaseField = Felt>,
+    H: ElementHasher<BaseField = Felt>,
+    R: RandomCoin<BaseField = Felt, Hasher = H>,
+{
+    let StarkProof {
+        num_unique_queries,
+        commitments,
+        trace_queries,
+        constraint_queries,
+        ood_frame,
+        fri_proof,
+        ..
+    } = proof;
+
+    let trace_layout = air.trace_layout();
+    let lde_domain_size = air.lde_domain_size();
+    let fri_options = air.options().to_fri_options();
+    let num_queries = num_unique_queries as usize;
+    if num_queries == 0 {
+        return Err(malformed_proof("proof does not contain any queries"));
+    }
+
+    // --- commitments ----------------------------------------------------------------------------
+    let (trace_roots, constraint_root, _) = commitments
+        .parse::<H>(trace_layout.num_segments(), fri_options.num_fri_layers(lde_domain_size))
+        .map_err(deserialization_error)?;
+
+    // --- trace and constraint queries -----------------------------------------------------------
+    if trace_queries.len() != trace_layout.num_segments() {
+        return Err(malformed_proof(&format!(
+            "expected {} trace segment queries, but received {}",
+            trace_layout.num_segments(),
+            trace_queries.len()
+        )));
+    }
+    for (i, segment_queries) in trace_queries.into_iter().enumerate() {
+        if i == 0 {
+            let width = trace_layout.main_trace_width();
+            segment_queries
+                .parse::<H, Felt>(lde_domain_size, num_queries, width)
+                .map(|_| ())
+        } else {
+            let width = trace_layout.get_aux_segment_width(i - 1);
+            segment_queries.parse::<H, E>(lde_domain_size, num_queries, width).map(|_| ())
+        }
+        .map_err(deserialization_error)?;
+    }
+    let constraint_frame_width = air.context().num_constraint_composition_columns();
+    constraint_queries
+        .parse::<H, E>(lde_domain_size, num_queries, constraint_frame_width)
+        .map_err(deserialization_error)?;
+
+    // --- FRI proof ------------------------------------------------------------------------------
+    fri_proof.parse_remainder::<E>().map_err(deserialization_error)?;
+    fri_proof
+        .parse_layers::<H, E>(lde_domain_size, fri_options.folding_factor())
+        .map_err(deserialization_error)?;
+
+    // --- out-of-domain frame --------------------------------------------------------------------
+    let main_trace_width = trace_layout.main_trace_width();
+    let aux_trace_width = trace_layout.aux_trace_width();
+    let (ood_trace_states, ood_constraint_evaluations) = ood_frame
+        .parse::<E>(main_trace_width, aux_trace_width, constraint_frame_width)
+        .map_err(deserialization_error)?;
+
+    // --- out-of-domain consistency --------------------------------------------------------------
+    // replay the transcript up to the drawing of the out-of-domain point z
+    public_coin.reseed(trace_roots[0]);
+    let mut aux_rand_elements = AuxTraceRandElements::<E>::new();
+    for (i, commitment) in trace_roots.iter().skip(1).enumerate() {
+        let rand_elements = air
+            .get_aux_trace_segment_random_elements(i, &mut public_coin)
+            .map_err(|_| VerifierError::RandomCoinError)?;
+        aux_rand_elements.add_segment_elements(rand_elements);
+        public_coin.reseed(*commitment);
+    }
+    let composition_coefficients = air
+        .get_constraint_composition_coefficients(&mut public_coin)
+        .map_err(|_| VerifierError::RandomCoinError)?;
+    public_coin.reseed(constraint_root);
+    let z = public_coin.draw::<E>().map_err(|_| VerifierError::RandomCoinError)?;
+
+    // the trace states are stored as pairs of values of the current and the next row of every
+    // column, with the columns of the main trace segment followed by the auxiliary ones
+    let (main_states, aux_states) = ood_trace_states.split_at(main_trace_width * 2);
+    let main_frame = ood_evaluation_frame(main_states);
+    let aux_frame = (aux_trace_width != 0).then(|| ood_evaluation_frame(aux_states));
+
+    // the constraints evaluated over the out-of-domain frame must match the evaluation of the
+    // composition polynomial at z, which is reduced from its column evaluations sent by the prover
+    let constraint_evaluation = evaluate_ood_constraints(
+        air,
+        composition_coefficients,
+        &main_frame,
+        &aux_frame,
+        aux_rand_elements,
+        z,
+    );
+    let composition_evaluation =
+        ood_constraint_evaluations
+            .iter()
+            .enumerate()
+            .fold(E::ZERO, |result, (i, &value)| {
+                result + z.exp_vartime(((i * air.trace_length()) as u32).into()) * value
+            });
+    if constraint_evaluation != composition_evaluation {
+        return Err(VerifierError::InconsistentOodConstraintEvaluations);
+    }
+
+    Ok(())
+}
+
+/// Builds an evaluation frame from interleaved values of the current and the next row.
+fn ood_evaluation_frame<E: FieldElement>(states: &[E]) -> EvaluationFrame<E> {
+    let (current, next) = states.chunks(2).map(|pair| (pair[0], pair[1])).unzip();
+    EvaluationFrame::from_rows(current, next)
+}
+
+/// Evaluates the transition and boundary constraints of the AIR at the point `x`, and merges the
+/// results into a single value using the provided composition coefficients.
+fn evaluate_ood_constraints<E>(
+    air: &ProcessorAir,
+    composition_coefficients: ConstraintCompositionCoefficients<E>,
+    main_frame: &EvaluationFrame<E>,
+    aux_frame: &Option<EvaluationFrame<E>>,
+    aux_rand_elements: AuxTraceRandElements<E>,
+    x: E,
+) -> E
+where
+    E: FieldElement<BaseField = Felt>,
+{
+    // --- transition constraints -----------------------------------------------------------------
+    let t_constraints = air.get_transition_constraints(&composition_coefficients.transition);
+    let periodic_values = air
+        .get_periodic_column_polys()
+        .iter()
+        .map(|poly| {
+            let num_cycles = air.trace_length() / poly.len();
+            polynom::eval(poly, x.exp_vartime((num_cycles as u32).into()))
+        })
+        .collect::<Vec<_>>();
+
+    let mut main_evaluations = E::zeroed_vector(t_constraints.num_main_constraints());
+    air.evaluate_transition(main_frame, &periodic_values, &mut main_evaluations);
+    let mut aux_evaluations = E::zeroed_vector(t_constraints.num_aux_constraints());
+    if let Some(aux_frame) = aux_frame {
+        air.evaluate_aux_transition(
+            main_frame,
+            aux_frame,
+            &periodic_values,
+            &aux_rand_elements,
+            &mut aux_evaluations,
+        );
+    }
+    let mut result = t_constraints.combine_evaluations::<E>(&main_evaluations, &aux_evaluations, x);
+
+    // --- boundary constraints -------------------------------------------------------------------
+    let b_constraints =
+        air.get_boundary_constraints(&aux_rand_elements, &composition_coefficients.boundary);
+    for group in b_constraints.main_constraints() {
+        result += group.evaluate_at(main_frame.current(), x);
+    }
+    if let Some(aux_frame) = aux_frame {
+        for group in b_constraints.aux_constraints() {
+            result += group.evaluate_at(aux_frame.current(), x);
+        }
+    }
+
+    result
+}
+
+fn deserialization_error(err: DeserializationError) -> VerifierError {
+    VerifierError::ProofDeserializationError(err.to_string())
+}
+
+fn malformed_proof(reason: &str) -> VerifierError {
+    VerifierError::ProofDeserializationError(reason.into())
+}
+
 // ERRORS
 // ================================================================================================
 
@@ -98,6 +496,7 @@ pub enum VerificationError {
     VerifierError(VerifierError),
     InputNotFieldElement(u64),
     OutputNotFieldElement(u64),
+    InsufficientSecurityLevel { actual: u32, required: u32 },
 }
 
 impl fmt::Display for VerificationError {
@@ -107,9 +506,44 @@ impl fmt::Display for VerificationError {
             VerifierError(e) => write!(f, "{e}"),
             InputNotFieldElement(i) => write!(f, "the input {i} is not a valid field element!"),
             OutputNotFieldElement(o) => write!(f, "the output {o} is not a valid field element!"),
+            InsufficientSecurityLevel { actual, required } => {
+                write!(f, "the proof security level of {actual} bits is below {required} bits!")
+            }
         }
     }
 }
 
 #[cfg(feature = "std")]
 impl std::error::Error for VerificationError {}
+
+/// An error returned by [verify_batch()] when at least one of the proofs fails to verify.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatchVerificationError {
+    failures: Vec<(usize, VerificationError)>,
+}
+
+impl BatchVerificationError {
+    /// Returns the indexes of all items which failed to verify together with the reasons of their
+    /// failures, ordered by index.
+    pub fn failures(&self) -> &[(usize, VerificationError)] {
+        &self.failures
+    }
+
+    /// Returns the index of the first item which failed to verify together with the reason of its
+    /// failure.
+    pub fn first_failure(&self) -> (usize, &VerificationError) {
+        let (index, err) = &self.failures[0];
+        (*index, err)
+    }
+}
+
+impl fmt::Display for BatchVerificationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (index, err) = self.first_failure();
+        let num_failures = self.failures.len();
+        write!(f, "{num_failures} proof(s) failed to verify, the first at index {index}: {err}")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BatchVerificationError {}